@@ -16,7 +16,10 @@ use fuel_core::{
         default_consensus_dev_key,
         ChainConfig,
     },
-    producer::Config as ProducerConfig,
+    producer::{
+        CoinbaseOverflowPolicy,
+        Config as ProducerConfig,
+    },
     service::{
         config::Trigger,
         Config,
@@ -28,7 +31,10 @@ use fuel_core::{
     txpool::Config as TxPoolConfig,
     types::{
         blockchain::primitives::SecretKeyWrapper,
-        fuel_tx::ContractId,
+        fuel_tx::{
+            AssetId,
+            ContractId,
+        },
         fuel_vm::SecretKey,
         secrecy::Secret,
     },
@@ -153,6 +159,32 @@ pub struct Command {
     #[arg(long = "coinbase-recipient", env)]
     pub coinbase_recipient: Option<String>,
 
+    /// The assets in which the fees are credited to the coinbase recipient.
+    ///
+    /// The fees paid in other assets are burned. If not set, all fees are credited.
+    #[arg(long = "coinbase-asset-allowlist", value_delimiter = ',', env)]
+    pub coinbase_asset_allowlist: Vec<String>,
+
+    /// Fail the block production if the coinbase recipient is not a deployed contract.
+    ///
+    /// If not set, the fee of such blocks is not minted and a warning is logged.
+    #[arg(long = "fail-on-missing-coinbase-recipient", env)]
+    pub fail_on_missing_coinbase_recipient: bool,
+
+    /// Fail the block production if the mint transaction fails to credit the coinbase
+    /// to the coinbase recipient. The case when the recipient is not a deployed contract
+    /// is controlled only by the `--fail-on-missing-coinbase-recipient`.
+    ///
+    /// If not set, the failed mint transaction is skipped and the fee of the block is lost.
+    #[arg(long = "require-coinbase-success", env)]
+    pub require_coinbase_success: bool,
+
+    /// What to do if crediting the fee of the block would overflow the balance
+    /// of the coinbase recipient: `skip` the failed mint transaction, `burn` the fee
+    /// of the block, or `halt` the production.
+    #[arg(long = "coinbase-overflow-policy", default_value = "skip", env)]
+    pub coinbase_overflow_policy: CoinbaseOverflowPolicy,
+
     #[cfg_attr(feature = "relayer", clap(flatten))]
     #[cfg(feature = "relayer")]
     pub relayer_args: relayer::RelayerArgs,
@@ -190,6 +222,29 @@ pub struct Command {
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
 
+    /// Evict the transactions from the `TxPool` if their inputs were spent by the committed block.
+    #[arg(long = "tx-evict-spent-inputs", default_value = "true", env)]
+    pub tx_evict_spent_inputs: bool,
+
+    /// The maximum number of distinct assets that a single transaction can reference.
+    #[clap(long = "tx-max-assets-per-transaction", default_value = "255", env)]
+    pub tx_max_assets_per_transaction: usize,
+
+    /// The minimum fee that a transaction must pay to the coinbase recipient to be
+    /// accepted by the `TxPool`. The fee is estimated as the max fee of the transaction.
+    #[clap(long = "tx-min-coinbase-fee", default_value = "0", env)]
+    pub tx_min_coinbase_fee: u64,
+
+    /// Accept the transactions with zero gas price regardless of the `min-gas-price`
+    /// and the `tx-min-coinbase-fee`. Intended for the test and private networks.
+    #[arg(long = "tx-allow-zero-gas-price", default_value = "false", env)]
+    pub tx_allow_zero_gas_price: bool,
+
+    /// The maximum gas that the dry run of a single transaction may consume.
+    /// If not set, equals the block gas limit of the chain.
+    #[clap(long = "max-dry-run-gas", env)]
+    pub max_dry_run_gas: Option<u64>,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min-connected-reserved-peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -198,6 +253,17 @@ pub struct Command {
     #[clap(long = "time-until-synced", default_value = "0s", env)]
     pub time_until_synced: humantime::Duration,
 
+    /// The number of the imported blocks waiting for the subscribers of the importer
+    /// above which the block production backs off. If not set, the block production
+    /// is not throttled.
+    #[clap(long = "max-importer-queue-depth", env)]
+    pub max_importer_queue_depth: Option<usize>,
+
+    /// The delay of the next block for each block in the importer queue above
+    /// the `max-importer-queue-depth`.
+    #[clap(long = "importer-backoff", default_value = "100ms", env)]
+    pub importer_backoff: humantime::Duration,
+
     /// Time to wait after submitting a query before debug info will be logged about query.
     #[clap(long = "query-log-threshold-time", default_value = "2s", env)]
     pub query_log_threshold_time: humantime::Duration,
@@ -206,6 +272,31 @@ pub struct Command {
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The interval between removals of the unreachable nodes of the contracts'
+    /// state Merkle trees. If not set, the compaction is disabled.
+    #[clap(long = "state-compaction-interval", env)]
+    pub state_compaction_interval: Option<humantime::Duration>,
+
+    /// The number of consecutive failed writes of the blocks after which the node
+    /// switches into the read-only mode. If not set, the node never switches.
+    #[clap(long = "max-consecutive-write-failures", env)]
+    pub max_consecutive_write_failures: Option<usize>,
+
+    /// Runs the node as a read replica. The node imports blocks from the network and
+    /// serves the GraphQL queries, but doesn't produce blocks and rejects transactions.
+    #[clap(long = "read-only", env)]
+    pub read_only: bool,
+
+    /// The time during which the node remembers the idempotency key of the submitted
+    /// transaction and rejects other submissions with the same key.
+    #[clap(long = "idempotency-key-ttl", default_value = "10m", env)]
+    pub idempotency_key_ttl: humantime::Duration,
+
+    /// The number of blocks on top of the block before its balances are considered
+    /// final and can't be reverted by a reorg.
+    #[clap(long = "finalization-depth", default_value = "0", env)]
+    pub finalization_depth: u32,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
 }
@@ -228,6 +319,10 @@ impl Command {
             poa_trigger,
             consensus_dev_key,
             coinbase_recipient,
+            coinbase_asset_allowlist,
+            fail_on_missing_coinbase_recipient,
+            require_coinbase_success,
+            coinbase_overflow_policy,
             #[cfg(feature = "relayer")]
             relayer_args,
             #[cfg(feature = "p2p")]
@@ -241,10 +336,22 @@ impl Command {
             tx_max_number,
             tx_max_depth,
             tx_number_active_subscriptions,
+            tx_evict_spent_inputs,
+            tx_max_assets_per_transaction,
+            tx_min_coinbase_fee,
+            tx_allow_zero_gas_price,
+            max_dry_run_gas,
             min_connected_reserved_peers,
             time_until_synced,
+            max_importer_queue_depth,
+            importer_backoff,
             query_log_threshold_time,
             api_request_timeout,
+            state_compaction_interval,
+            max_consecutive_write_failures,
+            read_only,
+            idempotency_key_ttl,
+            finalization_depth,
             profiling: _,
         } = self;
 
@@ -295,6 +402,21 @@ impl Command {
             None
         };
 
+        let coinbase_asset_allowlist = if coinbase_asset_allowlist.is_empty() {
+            None
+        } else {
+            Some(
+                coinbase_asset_allowlist
+                    .iter()
+                    .map(|asset_id| {
+                        AssetId::from_str(asset_id.as_str()).map_err(|err| anyhow!(err))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?,
+            )
+        };
+
+        let max_dry_run_gas = max_dry_run_gas.unwrap_or(chain_conf.block_gas_limit);
+
         let verifier = RelayerVerifierConfig {
             max_da_lag: max_da_lag.into(),
             max_wait_time: max_wait_time.into(),
@@ -322,14 +444,26 @@ impl Command {
                 metrics,
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
+                tx_evict_spent_inputs,
+                tx_max_assets_per_transaction,
+                tx_min_coinbase_fee,
+                tx_allow_zero_gas_price,
             ),
             block_producer: ProducerConfig {
                 utxo_validation,
                 coinbase_recipient,
+                coinbase_asset_allowlist,
+                fail_on_missing_coinbase_recipient,
+                require_coinbase_success,
+                coinbase_overflow_policy,
                 metrics,
+                max_dry_run_gas: Some(max_dry_run_gas),
             },
             block_executor: Default::default(),
-            block_importer: Default::default(),
+            block_importer: fuel_core::importer::Config {
+                max_consecutive_write_failures,
+                ..Default::default()
+            },
             #[cfg(feature = "relayer")]
             relayer: relayer_cfg,
             #[cfg(feature = "p2p")]
@@ -341,7 +475,14 @@ impl Command {
             verifier,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
+            max_importer_queue_depth,
+            importer_backoff: importer_backoff.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            deterministic_time: None,
+            state_compaction_interval: state_compaction_interval.map(Into::into),
+            read_only,
+            idempotency_key_ttl: idempotency_key_ttl.into(),
+            finalization_depth,
         };
         Ok(config)
     }
@@ -181,6 +181,17 @@ pub struct P2PArgs {
     /// For peer reputations, the maximum time since last heartbeat before penalty
     #[clap(long = "heartbeat-max-time-since-last", default_value = "40", env)]
     pub heartbeat_max_time_since_last: u64,
+
+    /// Set the interval in milliseconds at which the outbound transactions are gossiped.
+    /// Transactions received during the interval are coalesced and gossiped together.
+    /// If it's not set, each transaction is gossiped immediately.
+    #[clap(long = "tx-gossip-interval", default_value = "0", env)]
+    pub tx_gossip_interval: u64,
+
+    /// Max number of transactions gossiped per `tx-gossip-interval`.
+    /// Extra transactions are postponed to the next interval.
+    #[clap(long = "max-txs-per-gossip-interval", default_value = "100", env)]
+    pub max_txs_per_gossip_interval: usize,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -273,6 +284,12 @@ impl P2PArgs {
             Some(Duration::from_secs(self.random_walk))
         };
 
+        let tx_gossip_interval = if self.tx_gossip_interval == 0 {
+            None
+        } else {
+            Some(Duration::from_millis(self.tx_gossip_interval))
+        };
+
         let heartbeat_config = {
             let send_duration = Duration::from_secs(self.heartbeat_send_duration);
             let idle_duration = Duration::from_secs(self.heartbeat_idle_duration);
@@ -318,6 +335,8 @@ impl P2PArgs {
             ),
             info_interval: Some(Duration::from_secs(self.info_interval)),
             identify_interval: Some(Duration::from_secs(self.identify_interval)),
+            tx_gossip_interval,
+            max_txs_per_gossip_interval: self.max_txs_per_gossip_interval,
             metrics,
             state: NotInitialized,
         };
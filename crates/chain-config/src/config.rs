@@ -1,3 +1,4 @@
+mod asset;
 mod chain;
 mod coin;
 mod consensus;
@@ -5,6 +6,7 @@ mod contract;
 mod message;
 mod state;
 
+pub use asset::*;
 pub use chain::*;
 pub use coin::*;
 pub use consensus::*;
@@ -18,12 +20,14 @@ mod tests {
         blockchain::primitives::DaBlockHeight,
         fuel_asm::op,
         fuel_tx::{
+            StorageSlot,
             TxPointer,
             UtxoId,
         },
         fuel_types::{
             AssetId,
             Bytes32,
+            Salt,
         },
         fuel_vm::Contract,
     };
@@ -41,6 +45,7 @@ mod tests {
     };
 
     use super::{
+        asset::AssetConfig,
         chain::ChainConfig,
         coin::CoinConfig,
         contract::ContractConfig,
@@ -221,6 +226,53 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn can_roundtrip_assets_config() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let asset_id: AssetId = rng.gen();
+        let config = ChainConfig {
+            assets: vec![AssetConfig {
+                asset_id,
+                name: "Test Token".to_string(),
+                symbol: "TST".to_string(),
+                decimals: 9,
+            }],
+            ..ChainConfig::local_testnet()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+        assert_eq!(deserialized_config.asset(&asset_id), config.assets.first());
+        assert_eq!(deserialized_config.asset(&AssetId::BASE), None);
+    }
+
+    #[test]
+    fn with_contract_adds_contract_with_create_transaction_id() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let salt: Salt = rng.gen();
+        let slot = StorageSlot::new(rng.gen(), rng.gen());
+        let bytecode = op::ret(0x10).to_bytes().to_vec();
+
+        let config = ChainConfig::local_testnet().with_contract(
+            bytecode.clone(),
+            salt,
+            vec![slot.clone()],
+        );
+
+        let contract = Contract::from(bytecode.clone());
+        let state_root = Contract::initial_state_root(core::iter::once(&slot));
+        let expected_id = contract.id(&salt, &contract.root(), &state_root);
+        let state = config.initial_state.as_ref().unwrap();
+        let contracts = state.contracts.as_ref().unwrap();
+        assert_eq!(contracts.len(), 1);
+        assert_eq!(contracts[0].contract_id, expected_id);
+        assert_eq!(contracts[0].code, bytecode);
+        assert_eq!(contracts[0].state, Some(vec![(*slot.key(), *slot.value())]));
+        // The initial coins are preserved.
+        assert!(state.coins.is_some());
+    }
+
     fn test_config_contract(
         state: bool,
         balances: bool,
@@ -0,0 +1,28 @@
+use fuel_core_types::fuel_types::AssetId;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+
+use crate::serialization::HexType;
+
+/// The human-readable description of the asset used on the network.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct AssetConfig {
+    #[serde_as(as = "HexType")]
+    pub asset_id: AssetId,
+    pub name: String,
+    pub symbol: String,
+    /// The number of decimals used to display the amounts of the asset.
+    pub decimals: u8,
+}
+
+/// Returns the metadata of the asset with the `asset_id` among the `assets`.
+pub fn find_asset<'a>(
+    assets: &'a [AssetConfig],
+    asset_id: &AssetId,
+) -> Option<&'a AssetConfig> {
+    assets.iter().find(|asset| &asset.asset_id == asset_id)
+}
@@ -9,6 +9,8 @@ use fuel_core_types::{
     fuel_tx::{
         ConsensusParameters,
         GasCosts,
+        Input,
+        StorageSlot,
         TxParameters,
         UtxoId,
     },
@@ -16,6 +18,7 @@ use fuel_core_types::{
         Address,
         AssetId,
         Bytes32,
+        Salt,
     },
     fuel_vm::SecretKey,
 };
@@ -36,7 +39,12 @@ use std::{
 
 use crate::{
     config::{
+        asset::{
+            find_asset,
+            AssetConfig,
+        },
         coin::CoinConfig,
+        contract::ContractConfig,
         state::StateConfig,
     },
     genesis::GenesisCommitment,
@@ -60,6 +68,19 @@ pub struct ChainConfig {
     pub initial_state: Option<StateConfig>,
     pub consensus_parameters: ConsensusParameters,
     pub consensus: ConsensusConfig,
+    /// The code roots of the predicates that transactions are allowed to use.
+    /// The empty list allows all predicates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_predicate_roots: Vec<Bytes32>,
+    /// The metadata of the assets used on the network.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assets: Vec<AssetConfig>,
+    /// The maximum amount of the base asset that a single `Coin` or `Variable` output
+    /// can hold. Transactions creating bigger outputs are reverted. The `Change` outputs
+    /// are not limited, because the revert returns the spent inputs to them anyway.
+    /// `None` means unlimited.
+    #[serde(default)]
+    pub max_coin_output_amount: Option<u64>,
 }
 
 impl Default for ChainConfig {
@@ -70,6 +91,9 @@ impl Default for ChainConfig {
             consensus_parameters: ConsensusParameters::default(),
             initial_state: None,
             consensus: ConsensusConfig::default_poa(),
+            allowed_predicate_roots: vec![],
+            assets: vec![],
+            max_coin_output_amount: None,
         }
     }
 }
@@ -77,6 +101,21 @@ impl Default for ChainConfig {
 impl ChainConfig {
     pub const BASE_ASSET: AssetId = AssetId::zeroed();
 
+    /// Returns `true` if transactions are allowed to use the predicate with the `code`.
+    pub fn is_predicate_allowed(&self, code: &[u8]) -> bool {
+        if self.allowed_predicate_roots.is_empty() {
+            return true
+        }
+
+        let root = Bytes32::new(*Input::predicate_owner(code));
+        self.allowed_predicate_roots.contains(&root)
+    }
+
+    /// Returns the metadata of the asset with the `asset_id`, if it is registered.
+    pub fn asset(&self, asset_id: &AssetId) -> Option<&AssetConfig> {
+        find_asset(&self.assets, asset_id)
+    }
+
     pub fn local_testnet() -> Self {
         // endow some preset accounts with an initial balance
         tracing::info!("Initial Accounts");
@@ -148,6 +187,42 @@ impl ChainConfig {
         }
     }
 
+    /// Adds the contract with the `bytecode`, the `salt` and the `storage_slots` to the
+    /// initial state, so it is deployed at the genesis. The id of the contract is the same
+    /// as the id of the contract deployed by the `Create` transaction with the same inputs.
+    pub fn with_contract(
+        mut self,
+        bytecode: Vec<u8>,
+        salt: Salt,
+        storage_slots: Vec<StorageSlot>,
+    ) -> Self {
+        let state = (!storage_slots.is_empty()).then(|| {
+            storage_slots
+                .into_iter()
+                .map(|slot| (*slot.key(), *slot.value()))
+                .collect()
+        });
+        let mut contract = ContractConfig {
+            contract_id: Default::default(),
+            code: bytecode,
+            salt,
+            state,
+            balances: None,
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+        };
+        contract.calculate_contract_id();
+
+        self.initial_state
+            .get_or_insert_with(StateConfig::default)
+            .contracts
+            .get_or_insert_with(Vec::new)
+            .push(contract);
+        self
+    }
+
     pub fn initial_coin(
         secret: SecretKey,
         amount: u64,
@@ -204,15 +279,25 @@ impl GenesisCommitment for ChainConfig {
             initial_state: _,
             consensus_parameters,
             consensus,
+            // Skip the `allowed_predicate_roots` because it is the admission policy
+            // of the `TxPool` and doesn't affect the validity of blocks.
+            allowed_predicate_roots: _,
+            // Skip the `assets` because it is only the description for the users.
+            assets: _,
+            max_coin_output_amount,
         } = self;
 
         // TODO: Hash settlement configuration when it will be available.
-        let config_hash = *Hasher::default()
+        let mut hasher = Hasher::default()
             .chain(chain_name.as_bytes())
             .chain(block_gas_limit.to_be_bytes())
             .chain(consensus_parameters.root()?)
-            .chain(consensus.root()?)
-            .finalize();
+            .chain(consensus.root()?);
+        // Hash the limit only if it is set to keep the hash of the existing configs.
+        if let Some(max_coin_output_amount) = max_coin_output_amount {
+            hasher.input(max_coin_output_amount.to_be_bytes());
+        }
+        let config_hash = *hasher.finalize();
 
         Ok(config_hash)
     }
@@ -1,30 +1,99 @@
 use fuel_core_types::{
     fuel_asm::{
+        self,
         op,
         GTFArgs,
         Instruction,
         RegId,
     },
     fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+            Script as ScriptField,
+            ScriptData,
+        },
         Address,
         AssetId,
+        Cacheable,
+        Contract,
+        ContractId,
+        Create,
+        Finalizable,
+        Input,
+        Output,
+        Receipt,
+        Salt,
+        Script,
+        ScriptParameters,
+        StorageSlot,
+        TransactionBuilder,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+        Bytes32,
+        ChainId,
+        Word,
+    },
+    fuel_vm::{
+        Call,
+        CallFrame,
     },
 };
+use std::collections::HashMap;
+
+/// The first instruction of the contract from the [`generate`]. It jumps over
+/// the embedded address, which is placed immediately after the jump.
+fn recipient_jump() -> Instruction {
+    op::ji((1 + (Address::LEN / Instruction::SIZE)).try_into().unwrap())
+}
 
 /// Generates the bytecode for the fee collection contract.
 /// The contract expects `AssetId` and `output_index` as a first elements in `script_data`.
 pub fn generate(address: Address) -> Vec<u8> {
-    let start_jump = vec![
-        // Jump over the embedded address, which is placed immediately after the jump
-        op::ji((1 + (Address::LEN / Instruction::SIZE)).try_into().unwrap()),
-    ];
+    generate_collector(address, false, None)
+}
+
+/// Generates the bytecode for the fee collection contract that works like the one from
+/// the [`generate`], but also emits the `LogData` receipt after the transfer. The `ra`
+/// of the receipt is the withdrawn amount, and the data is the withdrawn `AssetId`,
+/// see [`withdrawals_from_receipts`]. No receipt is emitted if the balance is zero.
+pub fn generate_with_log(address: Address) -> Vec<u8> {
+    generate_collector(address, true, None)
+}
+
+/// Generates the bytecode for the fee collection contract that works like the one from
+/// the [`generate`], but doesn't withdraw the balance below the `min_amount`, so dust
+/// withdrawals don't cost more gas than they are worth. The `min_amount` is embedded
+/// as a big-endian word immediately after the address.
+///
+/// The contract starts with another jump than the one from the [`generate`], so it is
+/// not recognized by the [`extract_fee_collection_recipient`].
+pub fn generate_with_min(address: Address, min_amount: Word) -> Vec<u8> {
+    generate_collector(address, false, Some(min_amount))
+}
+
+fn generate_collector(address: Address, log: bool, min_amount: Option<Word>) -> Vec<u8> {
+    let start_jump = match min_amount {
+        Some(_) => {
+            const EMBEDDED_LEN: usize = Address::LEN + core::mem::size_of::<Word>();
+            // Jump over the embedded address and the minimum amount,
+            // which are placed immediately after the jump
+            op::ji((1 + (EMBEDDED_LEN / Instruction::SIZE)).try_into().unwrap())
+        }
+        None => recipient_jump(),
+    };
 
     let asset_id_register = 0x10;
     let balance_register = 0x11;
     let contract_id_register = 0x12;
     let output_index_register = 0x13;
     let recipient_id_register = 0x14;
-    let body = vec![
+    let len_register = 0x15;
+    let min_amount_register = 0x16;
+    let flag_register = 0x17;
+    let mut body = vec![
         // Load pointer to AssetId
         op::gtf_args(asset_id_register, 0x00, GTFArgs::ScriptData),
         // Load output index
@@ -41,6 +110,24 @@ pub fn generate(address: Address) -> Vec<u8> {
         // If balance == 0, return early
         op::jnzf(balance_register, RegId::ZERO, 1),
         op::ret(RegId::ONE),
+    ];
+    if min_amount.is_some() {
+        body.extend([
+            // Load the embedded minimum amount
+            op::addi(
+                min_amount_register,
+                RegId::IS,
+                (Instruction::SIZE + Address::LEN).try_into().unwrap(),
+            ),
+            op::lw(min_amount_register, min_amount_register, 0),
+            // If balance < min_amount, return early
+            op::lt(flag_register, balance_register, min_amount_register),
+            op::eq(flag_register, flag_register, RegId::ZERO),
+            op::jnzf(flag_register, RegId::ZERO, 1),
+            op::ret(RegId::ONE),
+        ]);
+    }
+    body.extend([
         // Pointer to the recipient address
         op::addi(
             recipient_id_register,
@@ -54,18 +141,607 @@ pub fn generate(address: Address) -> Vec<u8> {
             balance_register,
             asset_id_register,
         ),
+    ]);
+    if log {
+        body.extend([
+            // Log the withdrawn amount and the asset id
+            op::movi(len_register, AssetId::LEN.try_into().unwrap()),
+            op::logd(
+                balance_register,
+                RegId::ZERO,
+                asset_id_register,
+                len_register,
+            ),
+        ]);
+    }
+    // Return
+    body.push(op::ret(RegId::ONE));
+
+    let mut asm_bytes: Vec<u8> = vec![start_jump].into_iter().collect();
+    asm_bytes.extend_from_slice(address.as_slice()); // Embed the address
+    if let Some(min_amount) = min_amount {
+        asm_bytes.extend_from_slice(&min_amount.to_be_bytes()); // Embed the minimum amount
+    }
+    let body: Vec<u8> = body.into_iter().collect();
+    asm_bytes.extend(body.as_slice());
+
+    asm_bytes
+}
+
+/// Recognizes the bytecode produced by the [`generate`] or the [`generate_with_log`]
+/// and returns the embedded recipient address. Returns `None` if the `code` is not
+/// a fee collection contract.
+pub fn is_fee_collection_contract(code: &[u8]) -> Option<Address> {
+    let address = extract_fee_collection_recipient(code)?;
+    (generate(address) == code || generate_with_log(address) == code).then_some(address)
+}
+
+/// Returns the `AssetId` and the amount of each withdrawal logged by the fee collection
+/// contract from the [`generate_with_log`] with the `contract_id` in the `receipts`.
+pub fn withdrawals_from_receipts(
+    contract_id: &ContractId,
+    receipts: &[Receipt],
+) -> Vec<(AssetId, Word)> {
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::LogData {
+                id,
+                ra,
+                data: Some(data),
+                ..
+            } if id == contract_id => {
+                let asset_id: [u8; AssetId::LEN] = data.as_slice().try_into().ok()?;
+                Some((AssetId::new(asset_id), *ra))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Computes the balance of the fee collection contract from the block data alone:
+/// the `coinbase_amounts` credited by the blocks and the `withdrawals` from
+/// the [`withdrawals_from_receipts`], both tagged with the height of their block.
+///
+/// The coinbase is minted by the last transaction of the block, so the withdrawals
+/// of the block are applied before its coinbase. A withdrawal exceeding the balance
+/// and a balance overflowing `u64` can't happen on-chain, so both are reported as
+/// an error about the inconsistent block data.
+pub fn expected_fee_collection_balance(
+    coinbase_amounts: &[(BlockHeight, AssetId, u64)],
+    withdrawals: &[(BlockHeight, AssetId, u64)],
+) -> anyhow::Result<HashMap<AssetId, u64>> {
+    let mut changes: Vec<_> = withdrawals
+        .iter()
+        .map(|change| (false, change))
+        .chain(coinbase_amounts.iter().map(|change| (true, change)))
+        .collect();
+    changes.sort_by_key(|(is_credit, (height, _, _))| (*height, *is_credit));
+
+    let mut balances = HashMap::new();
+    for (is_credit, (height, asset_id, amount)) in changes {
+        let balance = balances.entry(*asset_id).or_insert(0u64);
+        *balance = if is_credit {
+            balance.checked_add(*amount).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The coinbase of the block {height} overflows \
+                    the balance of the asset {asset_id:#x}"
+                )
+            })?
+        } else {
+            balance.checked_sub(*amount).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The withdrawal of {amount} at the block {height} exceeds \
+                    the balance {balance} of the asset {asset_id:#x}"
+                )
+            })?
+        };
+    }
+    Ok(balances)
+}
+
+/// Extracts the recipient address embedded into the `bytecode` by the [`generate`].
+///
+/// Only the layout is checked: the jump over the address, the address itself, and
+/// at least one instruction after it. Unlike [`is_fee_collection_contract`], the body
+/// of the contract is not compared, so the address is extracted from the contracts
+/// generated by other versions of the [`generate`] as well. Returns `None` if the
+/// `bytecode` doesn't start with the jump or is too short to embed the address.
+pub fn extract_fee_collection_recipient(bytecode: &[u8]) -> Option<Address> {
+    let jump = bytecode.get(..Instruction::SIZE)?;
+    if jump != recipient_jump().to_bytes() {
+        return None
+    }
+    let rest = bytecode.get(Instruction::SIZE..)?;
+    let address: [u8; Address::LEN] = rest.get(..Address::LEN)?.try_into().ok()?;
+    let body = rest.get(Address::LEN..)?;
+    if body.is_empty() || body.len() % Instruction::SIZE != 0 {
+        return None
+    }
+    Some(Address::new(address))
+}
+
+/// The storage slot of the contract generated by [`generate_with_mutable_recipient`]
+/// that holds the recipient address.
+pub const RECIPIENT_SLOT: Bytes32 = Bytes32::new([0; 32]);
+
+/// The storage slot of the contract generated by [`generate_with_mutable_recipient`]
+/// that holds the address of the admin allowed to update the recipient.
+pub const ADMIN_SLOT: Bytes32 = Bytes32::new([1; 32]);
+
+/// Generates the bytecode for the fee collection contract that reads the recipient
+/// address from the [`RECIPIENT_SLOT`] instead of embedding it into the bytecode.
+///
+/// The contract behaves like the one from the [`generate`] if the first parameter
+/// of the call is zero. Otherwise, it replaces the recipient with the address placed
+/// at the beginning of the `script_data`, see [`update_recipient_script_data`].
+/// Only the transaction whose first input is a coin owned by the address from
+/// the [`ADMIN_SLOT`] can update the recipient.
+///
+/// The collection costs one more `SRWQ` instruction than the contract from the
+/// [`generate`], and the `Create` transaction must initialize the storage slots
+/// returned by the [`mutable_recipient_storage_slots`].
+pub fn generate_with_mutable_recipient() -> Vec<u8> {
+    let start_jump = vec![
+        // Jump over the embedded storage keys, which are placed immediately after the jump
+        op::ji(
+            (1 + (2 * Bytes32::LEN / Instruction::SIZE))
+                .try_into()
+                .unwrap(),
+        ),
+    ];
+
+    let asset_id_register = 0x10;
+    let balance_register = 0x11;
+    let contract_id_register = 0x12;
+    let output_index_register = 0x13;
+    let recipient_id_register = 0x14;
+    let key_register = 0x15;
+    let flag_register = 0x16;
+    let param_register = 0x17;
+    let admin_register = 0x18;
+    let owner_register = 0x19;
+    let len_register = 0x1a;
+    let collect = vec![
+        // Load pointer to AssetId
+        op::gtf_args(asset_id_register, 0x00, GTFArgs::ScriptData),
+        // Load output index
+        op::addi(
+            output_index_register,
+            asset_id_register,
+            u16::try_from(AssetId::LEN).expect("The size is 32"),
+        ),
+        op::lw(output_index_register, output_index_register, 0),
+        // Gets pointer to the contract id
+        op::move_(contract_id_register, RegId::FP),
+        // Get the balance of asset ID in the contract
+        op::bal(balance_register, asset_id_register, contract_id_register),
+        // If balance == 0, return early
+        op::jnzf(balance_register, RegId::ZERO, 1),
+        op::ret(RegId::ONE),
+        // Read the recipient address from the storage into the stack
+        op::addi(
+            key_register,
+            RegId::IS,
+            Instruction::SIZE.try_into().unwrap(),
+        ),
+        op::move_(recipient_id_register, RegId::SP),
+        op::cfei(Address::LEN.try_into().unwrap()),
+        op::srwq(
+            recipient_id_register,
+            flag_register,
+            key_register,
+            RegId::ONE,
+        ),
+        // Perform the transfer
+        op::tro(
+            recipient_id_register,
+            output_index_register,
+            balance_register,
+            asset_id_register,
+        ),
+        // Return
+        op::ret(RegId::ONE),
+    ];
+    let update = vec![
+        // Read the admin address from the storage into the stack
+        op::addi(
+            key_register,
+            RegId::IS,
+            (Instruction::SIZE + Bytes32::LEN).try_into().unwrap(),
+        ),
+        op::move_(admin_register, RegId::SP),
+        op::cfei(Address::LEN.try_into().unwrap()),
+        op::srwq(admin_register, flag_register, key_register, RegId::ONE),
+        // Revert unless the first input is a coin owned by the admin
+        op::gtf_args(owner_register, RegId::ZERO, GTFArgs::InputCoinOwner),
+        op::movi(len_register, Address::LEN.try_into().unwrap()),
+        op::meq(flag_register, owner_register, admin_register, len_register),
+        op::jnzf(flag_register, RegId::ZERO, 1),
+        op::rvrt(RegId::ONE),
+        // Store the new recipient address from the `script_data`
+        op::gtf_args(recipient_id_register, 0x00, GTFArgs::ScriptData),
+        op::addi(
+            key_register,
+            RegId::IS,
+            Instruction::SIZE.try_into().unwrap(),
+        ),
+        op::swwq(
+            key_register,
+            flag_register,
+            recipient_id_register,
+            RegId::ONE,
+        ),
         // Return
         op::ret(RegId::ONE),
     ];
+    let dispatch = vec![
+        // Load the first parameter of the call
+        op::addi(
+            param_register,
+            RegId::FP,
+            CallFrame::a_offset().try_into().unwrap(),
+        ),
+        op::lw(param_register, param_register, 0),
+        // Jump to the update of the recipient if the parameter is not zero
+        op::jnzf(
+            param_register,
+            RegId::ZERO,
+            collect.len().try_into().unwrap(),
+        ),
+    ];
 
     let mut asm_bytes: Vec<u8> = start_jump.into_iter().collect();
-    asm_bytes.extend_from_slice(address.as_slice()); // Embed the address
-    let body: Vec<u8> = body.into_iter().collect();
+    // Embed the storage keys
+    asm_bytes.extend_from_slice(RECIPIENT_SLOT.as_slice());
+    asm_bytes.extend_from_slice(ADMIN_SLOT.as_slice());
+    let body: Vec<u8> = dispatch.into_iter().chain(collect).chain(update).collect();
     asm_bytes.extend(body.as_slice());
 
     asm_bytes
 }
 
+/// The storage slots that the `Create` transaction of the contract from the
+/// [`generate_with_mutable_recipient`] should initialize.
+pub fn mutable_recipient_storage_slots(
+    recipient: Address,
+    admin: Address,
+) -> Vec<StorageSlot> {
+    // The slots are sorted by the key, as required by the `Create` transaction.
+    vec![
+        StorageSlot::new(RECIPIENT_SLOT, Bytes32::new(*recipient)),
+        StorageSlot::new(ADMIN_SLOT, Bytes32::new(*admin)),
+    ]
+}
+
+/// The initial storage slots of the contract from the [`generate_with_mutable_recipient`]
+/// where the `recipient` is also the admin. The [`RECIPIENT_SLOT`] holds the address
+/// receiving the fees, and the [`ADMIN_SLOT`] holds the address allowed to update it.
+///
+/// Use the [`mutable_recipient_storage_slots`] if the recipient should be updated
+/// by another address.
+pub fn fee_collection_storage_slots(recipient: Address) -> Vec<StorageSlot> {
+    mutable_recipient_storage_slots(recipient, recipient)
+}
+
+/// The script that calls the contract from the [`generate_with_mutable_recipient`]
+/// with the `script_data` built by the [`update_recipient_script_data`].
+pub fn update_recipient_script() -> Vec<u8> {
+    let call_struct_register = 0x10;
+    vec![
+        // Point to the call structure
+        op::gtf_args(call_struct_register, 0x00, GTFArgs::ScriptData),
+        op::addi(
+            call_struct_register,
+            call_struct_register,
+            u16::try_from(Address::LEN).expect("The size is 32"),
+        ),
+        op::call(call_struct_register, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// Builds the `script_data` for the [`update_recipient_script`]: the new recipient
+/// address, followed by the call structure with the non-zero first parameter.
+pub fn update_recipient_script_data(
+    new_recipient: &Address,
+    contract_id: &ContractId,
+) -> Vec<u8> {
+    let call = CallStructure::new(*contract_id, 1, 0);
+    new_recipient
+        .iter()
+        .copied()
+        .chain(call.to_bytes())
+        .collect()
+}
+
+/// The call structure that the `CALL` instruction reads from the memory: the id of
+/// the called contract followed by two parameters passed to the contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallStructure {
+    /// The contract to call.
+    pub contract_id: ContractId,
+    /// The first parameter of the call, usually the amount of the coins.
+    pub amount: Word,
+    /// The second parameter of the call, usually the offset of the `AssetId` in the memory.
+    pub asset_offset: Word,
+}
+
+impl CallStructure {
+    /// The size of the serialized call structure.
+    pub const LEN: usize = ContractId::LEN + 2 * core::mem::size_of::<Word>();
+
+    pub fn new(contract_id: ContractId, amount: Word, asset_offset: Word) -> Self {
+        Self {
+            contract_id,
+            amount,
+            asset_offset,
+        }
+    }
+
+    /// Serializes the call structure in the layout expected by the VM.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        Call::new(self.contract_id, self.amount, self.asset_offset).to_bytes()
+    }
+
+    /// Deserializes the call structure, failing if the size of `bytes` is not [`Self::LEN`].
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        if bytes.len() != Self::LEN {
+            anyhow::bail!(
+                "The call structure should be {} bytes, but got {} bytes",
+                Self::LEN,
+                bytes.len()
+            );
+        }
+        let (contract_id, params) = bytes.split_at(ContractId::LEN);
+        let (amount, asset_offset) = params.split_at(core::mem::size_of::<Word>());
+        let word = |bytes: &[u8]| {
+            Word::from_be_bytes(bytes.try_into().expect("The size is checked above"))
+        };
+
+        Ok(Self {
+            contract_id: ContractId::new(
+                contract_id.try_into().expect("The size is checked above"),
+            ),
+            amount: word(amount),
+            asset_offset: word(asset_offset),
+        })
+    }
+}
+
+/// Builds the `script_data` for the script that calls the fee collection contract:
+/// `AssetId` and `output_index` of the variable output, followed by the call structure.
+pub fn script_data(
+    asset_id: &AssetId,
+    output_index: Word,
+    contract_id: &ContractId,
+) -> Vec<u8> {
+    // The fee collection contract doesn't use the parameters of the call.
+    let call = CallStructure::new(*contract_id, 0, 0);
+    asset_id
+        .iter()
+        .copied()
+        .chain(output_index.to_be_bytes())
+        .chain(call.to_bytes())
+        .collect()
+}
+
+/// The script that calls the fee collection contract with the `script_data` built
+/// by the [`script_data`]. The contract transfers the balance of the `AssetId` from
+/// the `script_data` to the variable output at the `output_index`.
+pub fn withdrawal_script() -> Vec<u8> {
+    let call_struct_register = 0x10;
+    let call_struct_offset = AssetId::LEN.saturating_add(core::mem::size_of::<Word>());
+    vec![
+        // Point to the call structure
+        op::gtf_args(call_struct_register, 0x00, GTFArgs::ScriptData),
+        op::addi(
+            call_struct_register,
+            call_struct_register,
+            u16::try_from(call_struct_offset).expect("The size is 40"),
+        ),
+        op::call(call_struct_register, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// The layout of the outputs of the transaction withdrawing the fees.
+///
+/// The output of the fee collection contract is always the first one, at index `0`.
+/// It is followed by one variable output per withdrawn asset, in the order of the
+/// assets, so the variable output of the asset `i` is at index `1 + i`. The contract
+/// transfers the fees to the output index from the `script_data`, so the index from
+/// [`WithdrawalOutputs::variable_output_index_for`] should be used there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawalOutputs {
+    contract_input_index: u8,
+    assets: Vec<AssetId>,
+}
+
+impl WithdrawalOutputs {
+    /// Creates the layout for the `assets`, where the input of the fee collection
+    /// contract is at `contract_input_index`.
+    pub fn new(contract_input_index: u8, assets: Vec<AssetId>) -> Self {
+        Self {
+            contract_input_index,
+            assets,
+        }
+    }
+
+    /// Returns the index of the variable output receiving the `asset_id`,
+    /// or `None` if the asset is not withdrawn by the transaction.
+    pub fn variable_output_index_for(&self, asset_id: &AssetId) -> Option<Word> {
+        let position = self.assets.iter().position(|asset| asset == asset_id)?;
+        Some((position as Word).saturating_add(1))
+    }
+
+    /// Returns the outputs in the order of the layout.
+    pub fn outputs(&self) -> Vec<Output> {
+        core::iter::once(Output::contract(
+            self.contract_input_index,
+            Default::default(),
+            Default::default(),
+        ))
+        .chain(self.assets.iter().map(|_| {
+            Output::variable(Default::default(), Default::default(), Default::default())
+        }))
+        .collect()
+    }
+}
+
+/// The reason why the transaction can't withdraw the fees, see [`validate_fee_collection_tx`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FeeCollectionError {
+    #[error("The script doesn't call the contract")]
+    MissingCall,
+    #[error(
+        "The `script_data` should be {expected} bytes of `[asset_id, output_index, \
+        call_struct]`, but got {actual} bytes"
+    )]
+    BadScriptData { expected: usize, actual: usize },
+    #[error("The transaction doesn't have the input of the called contract {0:#x}")]
+    MissingContractInput(ContractId),
+    #[error("The transaction doesn't have the output of the called contract {0:#x}")]
+    MissingContractOutput(ContractId),
+    #[error(
+        "The output at the index {0} from the `script_data` is not a variable output"
+    )]
+    MissingVariableOutput(Word),
+}
+
+/// Checks that the `tx` can withdraw the fees from the fee collection contract before
+/// the submission: the script calls the contract, the `script_data` follows the layout
+/// of the [`script_data`], the transaction has the input and the output of the called
+/// contract, and the output index from the `script_data` points to a variable output.
+pub fn validate_fee_collection_tx(tx: &Script) -> Result<(), FeeCollectionError> {
+    let calls_contract = fuel_asm::from_bytes(tx.script().iter().copied())
+        .filter_map(Result::ok)
+        .any(|instruction| matches!(instruction, Instruction::CALL(_)));
+    if !calls_contract {
+        return Err(FeeCollectionError::MissingCall)
+    }
+
+    let script_data = tx.script_data();
+    let expected = AssetId::LEN
+        .saturating_add(core::mem::size_of::<Word>())
+        .saturating_add(CallStructure::LEN);
+    if script_data.len() != expected {
+        return Err(FeeCollectionError::BadScriptData {
+            expected,
+            actual: script_data.len(),
+        })
+    }
+    let (_, rest) = script_data.split_at(AssetId::LEN);
+    let (output_index, call) = rest.split_at(core::mem::size_of::<Word>());
+    let output_index =
+        Word::from_be_bytes(output_index.try_into().expect("The size is checked above"));
+    let call = CallStructure::from_bytes(call).expect("The size is checked above");
+
+    let contract_input_index = tx
+        .inputs()
+        .iter()
+        .position(|input| input.contract_id() == Some(&call.contract_id))
+        .ok_or(FeeCollectionError::MissingContractInput(call.contract_id))?;
+    let has_contract_output = tx.outputs().iter().any(|output| {
+        matches!(
+            output,
+            Output::Contract(contract) if contract.input_index as usize == contract_input_index
+        )
+    });
+    if !has_contract_output {
+        return Err(FeeCollectionError::MissingContractOutput(call.contract_id))
+    }
+
+    let variable_output = usize::try_from(output_index)
+        .ok()
+        .and_then(|index| tx.outputs().get(index));
+    if !matches!(variable_output, Some(Output::Variable { .. })) {
+        return Err(FeeCollectionError::MissingVariableOutput(output_index))
+    }
+
+    Ok(())
+}
+
+/// Checks that the `script_data` fits into the `max_script_data_length` of the network,
+/// so the transaction is not rejected later during the submission.
+pub fn check_script_data_length(
+    script_data: &[u8],
+    params: &ScriptParameters,
+) -> anyhow::Result<()> {
+    let limit = params.max_script_data_length;
+    let size = script_data.len() as u64;
+    if size > limit {
+        anyhow::bail!(
+            "The size of the `script_data` is {size} bytes, \
+            but `max_script_data_length` of the network is {limit} bytes"
+        );
+    }
+    Ok(())
+}
+
+/// Builds the `Create` transaction that deploys the contract from the [`generate`]
+/// with the `salt`, the empty storage, and the `fee_input` paying for the deployment.
+/// The metadata, including the id of the contract, is precomputed for the `chain_id`.
+///
+/// The bytecode is the only witness of the transaction, so the `fee_input` should
+/// be a predicate or a message coin. A signed coin requires adding its witness and
+/// precomputing the metadata again.
+pub fn fee_collection_create_tx(
+    address: Address,
+    salt: Salt,
+    fee_input: Input,
+    chain_id: &ChainId,
+) -> Create {
+    let mut tx = TransactionBuilder::create(generate(address).into(), salt, vec![])
+        .add_input(fee_input)
+        .finalize();
+    tx.precompute(chain_id)
+        .expect("The bytecode witness is referenced by the transaction");
+    tx
+}
+
+/// The fee collection contracts withdrawing to the same address. The instances share
+/// the bytecode from the [`generate`] and differ only by the salt, so each salt deploys
+/// a separate contract with its own id and balance. The [`is_fee_collection_contract`]
+/// recognizes all instances by their bytecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeCollectionFactory {
+    address: Address,
+}
+
+/// Creates the [`FeeCollectionFactory`] of the contracts withdrawing to the `address`.
+pub fn generate_factory(address: Address) -> FeeCollectionFactory {
+    FeeCollectionFactory { address }
+}
+
+impl FeeCollectionFactory {
+    /// The address receiving the fees withdrawn from any instance.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// The bytecode shared by all instances.
+    pub fn bytecode(&self) -> Vec<u8> {
+        generate(self.address)
+    }
+
+    /// Returns the id of the instance deployed with the `salt`.
+    pub fn contract_id(&self, salt: &Salt) -> ContractId {
+        let contract = Contract::from(self.bytecode());
+        contract.id(salt, &contract.root(), &Contract::default_state_root())
+    }
+
+    /// Builds the `Create` transaction deploying the instance with the `salt`,
+    /// see the [`fee_collection_create_tx`].
+    pub fn create_tx(&self, salt: Salt, fee_input: Input, chain_id: &ChainId) -> Create {
+        fee_collection_create_tx(self.address, salt, fee_input, chain_id)
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::arithmetic_side_effects)]
@@ -79,23 +755,32 @@ mod tests {
         SeedableRng,
     };
 
-    use fuel_core::service::{
-        Config,
-        FuelService,
+    use fuel_core::{
+        fee_collection::testkit::{
+            withdrawal_builder,
+            FeeCollectionHarness,
+        },
+        service::{
+            Config,
+            FuelService,
+        },
     };
     use fuel_core_client::client::{
-        types::TransactionStatus,
+        types::{
+            LintKind,
+            ScriptResult,
+            TransactionStatus,
+        },
         FuelClient,
     };
     use fuel_core_types::{
         fuel_asm::GTFArgs,
         fuel_tx::{
-            Cacheable,
             Finalizable,
             Input,
             Output,
+            Transaction,
             TransactionBuilder,
-            Witness,
         },
         fuel_types::{
             canonical::Serialize,
@@ -103,201 +788,228 @@ mod tests {
             BlockHeight,
             ChainId,
             ContractId,
-            Salt,
         },
     };
 
-    struct TestContext {
-        address: Address,
-        contract_id: ContractId,
-        _node: FuelService,
-        client: FuelClient,
+    #[test]
+    fn fee_collection_storage_slots_make_recipient_the_admin() {
+        let recipient = Address::new([7; 32]);
+
+        let slots = fee_collection_storage_slots(recipient);
+
+        assert_eq!(
+            slots,
+            vec![
+                StorageSlot::new(RECIPIENT_SLOT, Bytes32::new(*recipient)),
+                StorageSlot::new(ADMIN_SLOT, Bytes32::new(*recipient)),
+            ]
+        );
+        assert_eq!(slots, mutable_recipient_storage_slots(recipient, recipient));
     }
 
-    async fn setup(rng: &mut StdRng) -> TestContext {
-        // Make contract that coinbase fees are collected into
-        let address: Address = rng.gen();
-        let salt: Salt = rng.gen();
-        let contract = generate(address);
-        let witness: Witness = contract.into();
-        let mut create_tx = TransactionBuilder::create(witness.clone(), salt, vec![])
-            .add_random_fee_input()
-            .finalize();
-        create_tx
-            .precompute(&ChainId::default())
-            .expect("tx should be valid");
-        let contract_id = create_tx.metadata().as_ref().unwrap().contract_id;
+    #[test]
+    fn call_structure_roundtrips_through_bytes() {
+        let call = CallStructure::new(ContractId::new([7; 32]), 100, 64);
 
-        // Start up a node
-        let mut config = Config::local_node();
-        config.debug = true;
-        config.block_producer.coinbase_recipient = Some(contract_id);
-        let node = FuelService::new_node(config).await.unwrap();
-        let client = FuelClient::from(node.bound_address);
+        let bytes = call.to_bytes();
 
-        // Submit contract creation tx
-        let tx_status = client
-            .submit_and_await_commit(&create_tx.into())
-            .await
-            .unwrap();
-        assert!(matches!(tx_status, TransactionStatus::Success { .. }));
-        let bh = client.produce_blocks(1, None).await.unwrap();
-        assert_eq!(bh, BlockHeight::new(2));
+        assert_eq!(bytes.len(), CallStructure::LEN);
+        assert_eq!(&bytes[..ContractId::LEN], call.contract_id.as_ref());
+        assert_eq!(
+            &bytes[ContractId::LEN..ContractId::LEN + 8],
+            &100u64.to_be_bytes()
+        );
+        assert_eq!(CallStructure::from_bytes(&bytes).unwrap(), call);
+    }
 
-        // No fees should have been collected yet
-        let contract_balance =
-            client.contract_balance(&(contract_id), None).await.unwrap();
-        assert_eq!(contract_balance, 0);
+    #[test]
+    fn call_structure_from_bytes_rejects_wrong_size() {
+        let bytes = CallStructure::new(Default::default(), 0, 0).to_bytes();
 
-        TestContext {
-            address,
-            contract_id,
-            _node: node,
-            client,
-        }
+        assert!(CallStructure::from_bytes(&bytes[1..]).is_err());
+        assert!(CallStructure::from_bytes(&[bytes, vec![0]].concat()).is_err());
     }
 
-    /// This makes a block with a single transaction that has a fee,
-    /// so that the coinbase fee is collected into the contract
-    async fn make_block_with_fee(rng: &mut StdRng, ctx: &TestContext) {
-        let old_balance = ctx
+    #[tokio::test]
+    async fn happy_path() {
+        let mut ctx = FeeCollectionHarness::new(0).await;
+
+        // Given
+        let credited_amount = ctx.accrue_fee().await;
+
+        // Before withdrawal, the recipient's balance should be zero,
+        // and the contract balance should be exactly the credited amount.
+        let contract_balance_before_collect = ctx
             .client
             .contract_balance(&ctx.contract_id, None)
             .await
             .unwrap();
+        assert_eq!(contract_balance_before_collect, credited_amount);
+        assert_eq!(ctx.client.balance(&ctx.address, None).await.unwrap(), 0);
+        let simulated_amount = ctx
+            .client
+            .simulate_fee_collection(&ctx.contract_id, &AssetId::BASE)
+            .await
+            .unwrap();
+        assert_eq!(simulated_amount, credited_amount);
 
-        // Run a script that does nothing, but will cause fee collection
-        let tx = TransactionBuilder::script(
-            [op::ret(RegId::ONE)].into_iter().collect(),
-            vec![],
-        )
-        .add_unsigned_coin_input(
-            SecretKey::random(rng),
-            rng.gen(),
-            1000,
-            Default::default(),
-            Default::default(),
-            Default::default(),
-        )
-        .gas_price(1)
-        .script_gas_limit(1_000_000)
-        .finalize_as_transaction();
-        let tx_status = ctx.client.submit_and_await_commit(&tx).await.unwrap();
-        assert!(matches!(tx_status, TransactionStatus::Success { .. }));
+        // When
+        let tx_id = ctx.collect().await;
+
+        // Then
 
-        // Now the coinbase fee should be reflected in the contract balance
-        let new_balance = ctx
+        // Make sure that the full balance was been withdrawn
+        let contract_balance_after_collect = ctx
             .client
             .contract_balance(&ctx.contract_id, None)
             .await
             .unwrap();
-        assert!(new_balance > old_balance);
-    }
-
-    async fn collect_fees(ctx: &TestContext) {
-        let TestContext {
-            client,
-            contract_id,
-            ..
-        } = ctx;
+        assert_eq!(contract_balance_after_collect, 0);
 
-        let asset_id = AssetId::BASE;
-        let output_index = 1u64;
-        let call_struct_register = 0x10;
-        // Now call the fee collection contract to withdraw the fees
-        let script = vec![
-            // Point to the call structure
-            op::gtf_args(call_struct_register, 0x00, GTFArgs::ScriptData),
-            op::addi(
-                call_struct_register,
-                call_struct_register,
-                (asset_id.size() + output_index.size()) as u16,
+        // The recipient received exactly the simulated amount
+        assert_eq!(
+            ctx.client.balance(&ctx.address, None).await.unwrap(),
+            simulated_amount
+        );
+        let status = ctx.client.transaction_status(&tx_id).await.unwrap();
+        assert!(
+            matches!(
+                status,
+                TransactionStatus::Success { script_result, .. }
+                    if script_result.transferred && !script_result.reverted
             ),
-            op::call(call_struct_register, RegId::ZERO, RegId::ZERO, RegId::CGAS),
-            op::ret(RegId::ONE),
-        ];
-
-        let tx = TransactionBuilder::script(
-            script.into_iter().collect(),asset_id.to_bytes().into_iter()
-                .chain(output_index.to_bytes().into_iter())
-                .chain(contract_id
-                    .to_bytes().into_iter())
-                .chain(0u64.to_bytes().into_iter())
-                .chain(0u64.to_bytes().into_iter())
-                .collect(),
-        )
-        .add_random_fee_input() // No coinbase fee for this block
-        .gas_price(0)
-        .script_gas_limit(1_000_000)
-        .add_input(Input::contract(
-            Default::default(),
-            Default::default(),
-            Default::default(),
-            Default::default(),
-            *contract_id,
-        ))
-        .add_output(Output::contract(1, Default::default(), Default::default()))
-        .add_output(Output::variable(
-            Default::default(),
-            Default::default(),
-            Default::default(),
-        ))
-        .finalize_as_transaction();
+            "{status:?}"
+        );
 
-        let tx_status = client.submit_and_await_commit(&tx).await.unwrap();
+        // The withdrawn coin can be spent right away
+        let coins = ctx.client.outputs_as_coins(&tx_id).await.unwrap();
         assert!(
-            matches!(tx_status, TransactionStatus::Success { .. }),
-            "{tx_status:?}"
+            coins
+                .iter()
+                .any(|coin| coin.asset_id == AssetId::BASE
+                    && coin.amount == simulated_amount),
+            "{coins:?}"
+        );
+
+        // Everything minted by the coinbase is either in the contract or withdrawn
+        assert_eq!(
+            ctx.client
+                .total_coinbase_minted(AssetId::BASE)
+                .await
+                .unwrap(),
+            u128::from(contract_balance_after_collect)
+                .saturating_add(u128::from(simulated_amount))
         );
     }
 
     #[tokio::test]
-    async fn happy_path() {
+    async fn contract_with_log_reports_withdrawn_amount() {
         let rng = &mut StdRng::seed_from_u64(0);
+        let address: Address = rng.gen();
+        let ctx = FeeCollectionHarness::with_contract(
+            0,
+            generate_with_log(address),
+            vec![],
+            address,
+        )
+        .await;
 
-        let ctx = setup(rng).await;
-
-        for _ in 0..10 {
-            make_block_with_fee(rng, &ctx).await;
-        }
+        // Given
+        let credited_amount = 12345;
+        ctx.client
+            .credit_coinbase(ctx.contract_id, AssetId::BASE, credited_amount)
+            .await
+            .unwrap();
 
         // When
-        // Before withdrawal, the recipient's balance should be zero,
-        // and the contract balance should be non-zero.
-        let contract_balance_before_collect = ctx
+        let tx_id = ctx.collect().await;
+
+        // Then
+        let receipts = ctx
             .client
-            .contract_balance(&ctx.contract_id, None)
+            .receipts(&tx_id)
+            .await
+            .unwrap()
+            .expect("The transaction should be executed");
+        assert_eq!(
+            withdrawals_from_receipts(&ctx.contract_id, &receipts),
+            vec![(AssetId::BASE, credited_amount)]
+        );
+        assert_eq!(
+            ctx.client.balance(&ctx.address, None).await.unwrap(),
+            credited_amount
+        );
+    }
+
+    #[tokio::test]
+    async fn contract_with_min_withdraws_only_above_min_amount() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let address: Address = rng.gen();
+        let min_amount = 1000;
+        let ctx = FeeCollectionHarness::with_contract(
+            0,
+            generate_with_min(address, min_amount),
+            vec![],
+            address,
+        )
+        .await;
+        let transfers = |receipts: Vec<Receipt>| {
+            receipts
+                .into_iter()
+                .filter(|receipt| matches!(receipt, Receipt::TransferOut { .. }))
+                .count()
+        };
+
+        // Given
+        ctx.client
+            .credit_coinbase(ctx.contract_id, AssetId::BASE, min_amount - 1)
             .await
             .unwrap();
-        assert_ne!(contract_balance_before_collect, 0);
-        assert_eq!(ctx.client.balance(&ctx.address, None).await.unwrap(), 0);
 
         // When
-        collect_fees(&ctx).await;
+        let tx_id = ctx.collect().await;
 
         // Then
+        let receipts = ctx.client.receipts(&tx_id).await.unwrap().unwrap();
+        assert_eq!(transfers(receipts), 0);
+        assert_eq!(
+            ctx.client
+                .contract_balance(&ctx.contract_id, None)
+                .await
+                .unwrap(),
+            min_amount - 1
+        );
+        assert_eq!(ctx.client.balance(&ctx.address, None).await.unwrap(), 0);
 
-        // Make sure that the full balance was been withdrawn
-        let contract_balance_after_collect = ctx
-            .client
-            .contract_balance(&ctx.contract_id, None)
+        // Given
+        ctx.client
+            .credit_coinbase(ctx.contract_id, AssetId::BASE, 1)
             .await
             .unwrap();
-        assert_eq!(contract_balance_after_collect, 0);
 
-        // Make sure that the full balance was been withdrawn
+        // When
+        let tx_id = ctx.collect().await;
+
+        // Then
+        let receipts = ctx.client.receipts(&tx_id).await.unwrap().unwrap();
+        assert_eq!(transfers(receipts), 1);
+        assert_eq!(
+            ctx.client
+                .contract_balance(&ctx.contract_id, None)
+                .await
+                .unwrap(),
+            0
+        );
         assert_eq!(
             ctx.client.balance(&ctx.address, None).await.unwrap(),
-            contract_balance_before_collect
+            min_amount
         );
     }
 
     /// Attempts fee collection when no balance has accumulated yet
     #[tokio::test]
     async fn no_fees_collected_yet() {
-        let rng = &mut StdRng::seed_from_u64(0);
-
-        let ctx = setup(rng).await;
+        let ctx = FeeCollectionHarness::new(0).await;
 
         // Given
         let contract_balance_before_collect = ctx
@@ -309,10 +1021,24 @@ mod tests {
         assert_eq!(ctx.client.balance(&ctx.address, None).await.unwrap(), 0);
 
         // When
-        collect_fees(&ctx).await;
+        let tx_id = ctx.collect().await;
 
         // Then
 
+        // The contract returned early without the transfer
+        let status = ctx.client.transaction_status(&tx_id).await.unwrap();
+        match status {
+            TransactionStatus::Success { script_result, .. } => assert_eq!(
+                script_result,
+                ScriptResult {
+                    return_value: Some(1),
+                    reverted: false,
+                    transferred: false,
+                }
+            ),
+            status => panic!("Unexpected status {status:?}"),
+        }
+
         // Make sure that the balance is still zero
         let contract_balance = ctx
             .client
@@ -327,10 +1053,8 @@ mod tests {
 
     #[tokio::test]
     async fn missing_variable_output() {
-        let rng = &mut StdRng::seed_from_u64(0);
-
-        let ctx = setup(rng).await;
-        make_block_with_fee(rng, &ctx).await;
+        let mut ctx = FeeCollectionHarness::new(0).await;
+        ctx.accrue_fee().await;
 
         let asset_id = AssetId::BASE;
         let output_index = 1u64;
@@ -351,13 +1075,7 @@ mod tests {
         ];
         let tx = TransactionBuilder::script(
             script.into_iter().collect(),
-            asset_id.to_bytes().into_iter()
-                .chain(output_index.to_bytes().into_iter())
-                .chain(ctx.contract_id
-                    .to_bytes().into_iter())
-                .chain(0u64.to_bytes().into_iter())
-                .chain(0u64.to_bytes().into_iter())
-                .collect(),
+            script_data(&asset_id, output_index, &ctx.contract_id),
         )
         .add_random_fee_input() // No coinbase fee for this block
         .gas_price(0)
@@ -370,7 +1088,22 @@ mod tests {
             ctx.contract_id,
         ))
         .add_output(Output::contract(1, Default::default(), Default::default()))
-        .finalize_as_transaction();
+        .finalize();
+        assert_eq!(
+            validate_fee_collection_tx(&tx),
+            Err(FeeCollectionError::MissingVariableOutput(output_index))
+        );
+        let tx: Transaction = tx.into();
+
+        // The transfer of the contract is found before the submission.
+        let lints = ctx.client.lint_transaction(&tx).await.unwrap();
+        assert!(
+            lints
+                .iter()
+                .any(|lint| lint.contract_id == Some(ctx.contract_id)
+                    && lint.kind == LintKind::MissingVariableOutput),
+            "{lints:?}"
+        );
 
         let tx_status = ctx.client.submit_and_await_commit(&tx).await.unwrap();
         let TransactionStatus::Failure { reason, .. } = tx_status else {
@@ -388,4 +1121,428 @@ mod tests {
         let asset_balance = ctx.client.balance(&ctx.address, None).await.unwrap();
         assert_eq!(asset_balance, 0);
     }
+
+    #[tokio::test]
+    async fn deployed_contract_is_recognized_as_fee_collection_contract() {
+        let ctx = FeeCollectionHarness::new(0).await;
+
+        let code = ctx
+            .client
+            .contract_code(&ctx.contract_id)
+            .await
+            .unwrap()
+            .expect("The contract should be deployed");
+        assert_eq!(is_fee_collection_contract(&code), Some(ctx.address));
+    }
+
+    #[test]
+    fn contract_with_log_is_recognized_as_fee_collection_contract() {
+        let address = Address::new([3; 32]);
+
+        let code = generate_with_log(address);
+
+        assert_ne!(code, generate(address));
+        assert_eq!(is_fee_collection_contract(&code), Some(address));
+        assert_eq!(extract_fee_collection_recipient(&code), Some(address));
+    }
+
+    #[tokio::test]
+    async fn fee_collection_create_tx_deploys_contract() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let address: Address = rng.gen();
+        let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+        let fee_input = Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&predicate),
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        );
+
+        let create_tx =
+            fee_collection_create_tx(address, rng.gen(), fee_input, &ChainId::default());
+        let contract_id = create_tx.metadata().as_ref().unwrap().contract_id;
+
+        let node = FuelService::new_node(Config::local_node_in_memory())
+            .await
+            .unwrap();
+        let client = FuelClient::from(node.bound_address);
+        let tx_status = client
+            .submit_and_await_commit(&create_tx.into())
+            .await
+            .unwrap();
+        assert!(matches!(tx_status, TransactionStatus::Success { .. }));
+
+        let code = client
+            .contract_code(&contract_id)
+            .await
+            .unwrap()
+            .expect("The contract should be deployed");
+        assert_eq!(is_fee_collection_contract(&code), Some(address));
+    }
+
+    #[test]
+    fn factory_instances_share_bytecode_but_differ_by_salt() {
+        let address = Address::new([3; 32]);
+        let factory = generate_factory(address);
+        let first_salt = Salt::new([1; 32]);
+        let second_salt = Salt::new([2; 32]);
+        let fee_input = Input::coin_predicate(
+            Default::default(),
+            Default::default(),
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            op::ret(RegId::ONE).to_bytes().to_vec(),
+            vec![],
+        );
+
+        let first = factory.create_tx(first_salt, fee_input.clone(), &ChainId::default());
+        let second = factory.create_tx(second_salt, fee_input, &ChainId::default());
+
+        assert_eq!(factory.address(), address);
+        assert_eq!(
+            is_fee_collection_contract(&factory.bytecode()),
+            Some(address)
+        );
+        assert_ne!(
+            factory.contract_id(&first_salt),
+            factory.contract_id(&second_salt)
+        );
+        assert_eq!(
+            first.metadata().as_ref().unwrap().contract_id,
+            factory.contract_id(&first_salt)
+        );
+        assert_eq!(
+            second.metadata().as_ref().unwrap().contract_id,
+            factory.contract_id(&second_salt)
+        );
+    }
+
+    #[test]
+    fn extract_fee_collection_recipient_returns_embedded_address() {
+        let address = Address::new([3; 32]);
+        let mut code = generate(address);
+        assert_eq!(extract_fee_collection_recipient(&code), Some(address));
+
+        // The body is not compared, only the layout.
+        code.extend(op::noop().to_bytes());
+        assert_eq!(extract_fee_collection_recipient(&code), Some(address));
+        assert_eq!(is_fee_collection_contract(&code), None);
+    }
+
+    #[test]
+    fn extract_fee_collection_recipient_rejects_other_layouts() {
+        let code = generate(Address::new([3; 32]));
+
+        assert_eq!(extract_fee_collection_recipient(&[]), None);
+        assert_eq!(
+            extract_fee_collection_recipient(&code[..Instruction::SIZE + Address::LEN]),
+            None
+        );
+        assert_eq!(
+            extract_fee_collection_recipient(&generate_with_mutable_recipient()),
+            None
+        );
+    }
+
+    #[test]
+    fn is_fee_collection_contract_rejects_other_code() {
+        let address = Address::new([1; 32]);
+        let mut code = generate(address);
+        assert_eq!(is_fee_collection_contract(&code), Some(address));
+
+        // Any change of the body makes it a different contract.
+        code.push(0);
+        assert_eq!(is_fee_collection_contract(&code), None);
+        assert_eq!(is_fee_collection_contract(&[]), None);
+        assert_eq!(is_fee_collection_contract(&code[..10]), None);
+    }
+
+    #[test]
+    fn script_data_matches_the_call_layout() {
+        let asset_id = AssetId::new([1; 32]);
+        let contract_id = ContractId::new([2; 32]);
+        let output_index = 1u64;
+
+        let data = script_data(&asset_id, output_index, &contract_id);
+
+        let expected: Vec<u8> = asset_id
+            .to_bytes()
+            .into_iter()
+            .chain(output_index.to_bytes())
+            .chain(contract_id.to_bytes())
+            .chain(0u64.to_bytes())
+            .chain(0u64.to_bytes())
+            .collect();
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn withdrawal_outputs_place_variable_outputs_after_the_contract_output() {
+        let first = AssetId::new([1; 32]);
+        let second = AssetId::new([2; 32]);
+        let layout = WithdrawalOutputs::new(1, vec![first, second]);
+
+        assert_eq!(layout.variable_output_index_for(&first), Some(1));
+        assert_eq!(layout.variable_output_index_for(&second), Some(2));
+        assert_eq!(layout.variable_output_index_for(&AssetId::BASE), None);
+
+        let outputs = layout.outputs();
+        assert_eq!(outputs.len(), 3);
+        assert!(
+            matches!(outputs[0], Output::Contract(contract) if contract.input_index == 1)
+        );
+        assert!(outputs[1..].iter().all(|output| output.is_variable()));
+    }
+
+    #[test]
+    fn validate_fee_collection_tx_rejects_script_without_call() {
+        let mut tx = withdrawal_builder(&ContractId::new([1; 32])).finalize();
+        *tx.script_mut() = [op::ret(RegId::ONE)].into_iter().collect();
+
+        assert_eq!(
+            validate_fee_collection_tx(&tx),
+            Err(FeeCollectionError::MissingCall)
+        );
+    }
+
+    #[test]
+    fn validate_fee_collection_tx_rejects_bad_script_data() {
+        let contract_id = ContractId::new([1; 32]);
+        let mut tx = withdrawal_builder(&contract_id).finalize();
+        let expected = tx.script_data().len();
+        tx.script_data_mut().truncate(AssetId::LEN);
+
+        assert_eq!(
+            validate_fee_collection_tx(&tx),
+            Err(FeeCollectionError::BadScriptData {
+                expected,
+                actual: AssetId::LEN
+            })
+        );
+    }
+
+    #[test]
+    fn validate_fee_collection_tx_rejects_misordered_script_data() {
+        let contract_id = ContractId::new([1; 32]);
+        let mut tx = withdrawal_builder(&contract_id).finalize();
+        // The call structure is placed before the `AssetId` and the output index.
+        *tx.script_data_mut() = CallStructure::new(contract_id, 0, 0)
+            .to_bytes()
+            .into_iter()
+            .chain(AssetId::BASE.iter().copied())
+            .chain(1u64.to_be_bytes())
+            .collect();
+
+        assert!(matches!(
+            validate_fee_collection_tx(&tx),
+            Err(FeeCollectionError::MissingContractInput(_))
+        ));
+    }
+
+    #[test]
+    fn validate_fee_collection_tx_rejects_missing_contract_output() {
+        let contract_id = ContractId::new([1; 32]);
+        let mut tx = withdrawal_builder(&contract_id).finalize();
+        tx.outputs_mut()
+            .retain(|output| !matches!(output, Output::Contract(_)));
+
+        assert_eq!(
+            validate_fee_collection_tx(&tx),
+            Err(FeeCollectionError::MissingContractOutput(contract_id))
+        );
+    }
+
+    #[test]
+    fn check_script_data_length_rejects_too_big_script_data() {
+        let data = script_data(&AssetId::BASE, 1, &ContractId::zeroed());
+        let limit = data.len() as u64 - 1;
+        let params = ScriptParameters {
+            max_script_data_length: limit,
+            ..Default::default()
+        };
+
+        let err = check_script_data_length(&data, &params)
+            .expect_err("Should fail because of the limit");
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "The size of the `script_data` is {} bytes, \
+                but `max_script_data_length` of the network is {limit} bytes",
+                data.len()
+            )
+        );
+        check_script_data_length(&data, &ScriptParameters::default())
+            .expect("Should fit into the default limit");
+    }
+
+    async fn update_recipient(
+        ctx: &FeeCollectionHarness,
+        signer: &SecretKey,
+        new_recipient: &Address,
+    ) -> TransactionStatus {
+        let tx = TransactionBuilder::script(
+            update_recipient_script(),
+            update_recipient_script_data(new_recipient, &ctx.contract_id),
+        )
+        .add_unsigned_coin_input(
+            *signer,
+            Default::default(),
+            1000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .gas_price(0)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            ctx.contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+        ctx.client.submit_and_await_commit(&tx).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn mutable_recipient_can_be_updated_by_admin() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let admin = SecretKey::random(rng);
+        let first_recipient: Address = rng.gen();
+        let second_recipient: Address = rng.gen();
+        let ctx = FeeCollectionHarness::with_contract(
+            0,
+            generate_with_mutable_recipient(),
+            mutable_recipient_storage_slots(
+                first_recipient,
+                Input::owner(&admin.public_key()),
+            ),
+            first_recipient,
+        )
+        .await;
+
+        // Given
+        ctx.client
+            .credit_coinbase(ctx.contract_id, AssetId::BASE, 100)
+            .await
+            .unwrap();
+
+        // When
+        let status = update_recipient(&ctx, &admin, &second_recipient).await;
+        assert!(
+            matches!(status, TransactionStatus::Success { .. }),
+            "{status:?}"
+        );
+        ctx.collect().await;
+
+        // Then
+        assert_eq!(ctx.client.balance(&first_recipient, None).await.unwrap(), 0);
+        assert_eq!(
+            ctx.client.balance(&second_recipient, None).await.unwrap(),
+            100
+        );
+    }
+
+    #[tokio::test]
+    async fn mutable_recipient_cannot_be_updated_by_others() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let admin = SecretKey::random(rng);
+        let recipient: Address = rng.gen();
+        let ctx = FeeCollectionHarness::with_contract(
+            0,
+            generate_with_mutable_recipient(),
+            mutable_recipient_storage_slots(recipient, Input::owner(&admin.public_key())),
+            recipient,
+        )
+        .await;
+
+        // When
+        let status = update_recipient(&ctx, &SecretKey::random(rng), &rng.gen()).await;
+
+        // Then
+        assert!(
+            matches!(status, TransactionStatus::Failure { .. }),
+            "{status:?}"
+        );
+        ctx.client
+            .credit_coinbase(ctx.contract_id, AssetId::BASE, 100)
+            .await
+            .unwrap();
+        ctx.collect().await;
+        assert_eq!(ctx.client.balance(&recipient, None).await.unwrap(), 100);
+    }
+
+    #[test]
+    fn expected_fee_collection_balance_sums_credits_minus_withdrawals_per_asset() {
+        let base = AssetId::BASE;
+        let other = AssetId::new([1; 32]);
+        let coinbase_amounts: &[(BlockHeight, AssetId, u64)] = &[
+            (1.into(), base, 100),
+            (2.into(), other, 30),
+            (3.into(), base, 50),
+            (4.into(), other, 20),
+        ];
+        let withdrawals: &[(BlockHeight, AssetId, u64)] =
+            &[(2.into(), base, 100), (4.into(), other, 30)];
+
+        let balances =
+            expected_fee_collection_balance(coinbase_amounts, withdrawals).unwrap();
+
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[&base], 50);
+        assert_eq!(balances[&other], 20);
+    }
+
+    #[test]
+    fn expected_fee_collection_balance_applies_withdrawals_before_coinbase_of_block() {
+        let base = AssetId::BASE;
+        let coinbase_amounts: &[(BlockHeight, AssetId, u64)] =
+            &[(1.into(), base, 10), (2.into(), base, 100)];
+        let withdrawals: &[(BlockHeight, AssetId, u64)] =
+            &[(2.into(), base, 10), (3.into(), base, 100)];
+
+        let balances =
+            expected_fee_collection_balance(coinbase_amounts, withdrawals).unwrap();
+
+        assert_eq!(balances[&base], 0);
+        assert!(expected_fee_collection_balance(&[], &[])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn expected_fee_collection_balance_fails_if_withdrawal_spends_coinbase_of_its_block()
+    {
+        let base = AssetId::BASE;
+        // The withdrawal at the height 2 can't spend the coinbase of the same block.
+        let coinbase_amounts: &[(BlockHeight, AssetId, u64)] =
+            &[(1.into(), base, 10), (2.into(), base, 100)];
+        let withdrawals: &[(BlockHeight, AssetId, u64)] = &[(2.into(), base, 100)];
+
+        let result = expected_fee_collection_balance(coinbase_amounts, withdrawals);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn mutable_recipient_storage_slots_are_sorted() {
+        let slots =
+            mutable_recipient_storage_slots(Address::new([2; 32]), Address::new([1; 32]));
+
+        assert_eq!(slots.len(), 2);
+        assert!(slots[0].key() < slots[1].key());
+        assert_eq!(slots[0].value(), &Bytes32::new([2; 32]));
+    }
 }
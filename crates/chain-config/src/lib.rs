@@ -9,6 +9,7 @@ mod genesis;
 mod serialization;
 
 pub use config::*;
+pub use fee_collection_contract::expected_fee_collection_balance;
 use fuel_core_types::fuel_vm::SecretKey;
 pub use genesis::GenesisCommitment;
 
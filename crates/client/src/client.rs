@@ -5,7 +5,11 @@ use crate::client::{
             ExcludeInput,
             SpendQueryElementInput,
         },
-        contract::ContractBalanceQueryArgs,
+        contract::{
+            ContractBalanceQueryArgs,
+            ContractBalanceSeriesArgs,
+            FeeCollectionContractsArgs,
+        },
         message::MessageStatusArgs,
         tx::DryRunArg,
         Tai64Timestamp,
@@ -17,6 +21,7 @@ use crate::client::{
             Address,
             AssetId,
             BlockId,
+            Bytes32,
             ContractId,
             UtxoId,
         },
@@ -33,15 +38,38 @@ use cynic::{
     Operation,
     QueryBuilder,
 };
+use fuel_core_chain_config::fee_collection_contract::{
+    script_data,
+    validate_fee_collection_tx,
+    withdrawal_script,
+    WithdrawalOutputs,
+};
 use fuel_core_types::{
     fuel_asm::{
         Instruction,
         Word,
     },
     fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+            Policies as _,
+            Script as _,
+            ScriptGasLimit,
+        },
+        policies::PolicyType,
+        Chargeable,
+        ConsensusParameters,
+        Contract,
+        Finalizable,
+        Input,
+        Output,
         Receipt,
+        ScriptExecutionResult,
         Transaction,
+        TransactionBuilder,
         TxId,
+        Witness,
     },
     fuel_types,
     fuel_types::{
@@ -61,8 +89,19 @@ use pagination::{
 use schema::{
     balance::BalanceArgs,
     block::BlockByIdArgs,
-    coins::CoinByIdArgs,
-    contract::ContractByIdArgs,
+    chain::{
+        AssetInfoArgs,
+        AssetSupplyHistoryArgs,
+        TotalCoinbaseMintedArgs,
+    },
+    coins::{
+        CoinByIdArgs,
+        InputsRequiredArgs,
+    },
+    contract::{
+        ContractByIdArgs,
+        ContractStorageSlotArgs,
+    },
     tx::{
         TxArg,
         TxIdArgs,
@@ -94,10 +133,12 @@ use std::{
         ErrorKind,
     },
     net,
+    ops::Range,
     str::{
         self,
         FromStr,
     },
+    time::Duration,
 };
 use tai64::Tai64;
 use tracing as _;
@@ -107,7 +148,15 @@ use types::{
 };
 
 use self::schema::{
-    block::ProduceBlockArgs,
+    block::{
+        AccruedCoinbaseArgs,
+        BlocksCreditingArgs,
+        CoinbaseArgs,
+        CreditCoinbaseArgs,
+        ProduceBlockArgs,
+        ProduceBlockWithTxsArgs,
+        RecentCoinbaseCreditsArgs,
+    },
     message::MessageProofArgs,
 };
 
@@ -122,6 +171,8 @@ pub struct FuelClient {
     client: reqwest::Client,
     #[cfg(feature = "subscriptions")]
     cookie: std::sync::Arc<reqwest::cookie::Jar>,
+    #[cfg(feature = "subscriptions")]
+    keepalive: Option<Duration>,
     url: reqwest::Url,
 }
 
@@ -149,6 +200,7 @@ impl FromStr for FuelClient {
             Ok(Self {
                 client,
                 cookie,
+                keepalive: None,
                 url,
             })
         }
@@ -184,11 +236,30 @@ pub fn from_strings_errors_to_std_error(errors: Vec<String>) -> io::Error {
     io::Error::new(io::ErrorKind::Other, e)
 }
 
+/// The extensions of the GraphQL errors returned by the node.
+#[derive(Debug, Default, serde::Deserialize)]
+struct ErrorExtensions {
+    /// The machine-readable code of the error, if the node sets one.
+    code: Option<String>,
+}
+
 impl FuelClient {
     pub fn new(url: impl AsRef<str>) -> anyhow::Result<Self> {
         Self::from_str(url.as_ref())
     }
 
+    #[cfg(feature = "subscriptions")]
+    /// Reconnects the subscriptions if nothing arrives from the node within the `timeout`.
+    /// The node sends the keep-alive messages every 15 seconds, so only the connections
+    /// dropped silently, for example, by a proxy, are idle for longer.
+    ///
+    /// The reconnection repeats the subscription request. The stream yields an error
+    /// before each reconnection, so the consumer can refetch the state it may have missed.
+    pub fn with_keepalive(mut self, timeout: Duration) -> Self {
+        self.keepalive = Some(timeout);
+        self
+    }
+
     /// Send the GraphQL query to the client.
     pub async fn query<ResponseData, Vars>(
         &self,
@@ -202,21 +273,37 @@ impl FuelClient {
             .client
             .post(self.url.clone())
             .run_graphql(q)
+            .retain_extensions::<ErrorExtensions>()
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
 
         Self::decode_response(response)
     }
 
-    fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
+    fn decode_response<R>(response: GraphQlResponse<R, ErrorExtensions>) -> io::Result<R>
     where
         R: serde::de::DeserializeOwned + 'static,
     {
         match (response.data, response.errors) {
             (Some(d), _) => Ok(d),
-            (_, Some(e)) => Err(from_strings_errors_to_std_error(
-                e.into_iter().map(|e| e.message).collect(),
-            )),
+            (_, Some(e)) => {
+                let exhausted = e.iter().any(|e| {
+                    e.extensions.as_ref().and_then(|ext| ext.code.as_deref())
+                        == Some(types::ResourcesExhausted::CODE)
+                });
+                let error = from_strings_errors_to_std_error(
+                    e.into_iter().map(|e| e.message).collect(),
+                );
+                if exhausted {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        types::ResourcesExhausted {
+                            reason: error.to_string(),
+                        },
+                    ))
+                }
+                Err(error)
+            }
             _ => Err(io::Error::new(io::ErrorKind::Other, "Invalid response")),
         }
     }
@@ -272,6 +359,16 @@ impl FuelClient {
                 })?;
         }
 
+        if let Some(timeout) = self.keepalive {
+            client_builder = client_builder.read_timeout(timeout).reconnect(
+                es::ReconnectOptions::reconnect(true)
+                    .retry_initial(false)
+                    .delay(Duration::from_secs(1))
+                    .delay_max(timeout)
+                    .build(),
+            );
+        }
+
         let client = client_builder.build_with_conn(
             hyper_rustls::HttpsConnectorBuilder::new()
                 .with_webpki_roots()
@@ -290,7 +387,9 @@ impl FuelClient {
                 tracing::debug!("Got result: {result:?}");
                 let r = match result {
                     Ok(es::SSE::Event(es::Event { data, .. })) => {
-                        match serde_json::from_str::<GraphQlResponse<ResponseData>>(&data)
+                        match serde_json::from_str::<
+                            GraphQlResponse<ResponseData, ErrorExtensions>,
+                        >(&data)
                         {
                             Ok(resp) => {
                                 match Self::decode_response(resp) {
@@ -341,11 +440,101 @@ impl FuelClient {
         self.query(query).await.map(|r| r.node_info.into())
     }
 
+    pub async fn node_health(&self) -> io::Result<types::NodeHealth> {
+        let query = schema::node_info::QueryNodeHealth::build(());
+        self.query(query).await.map(|r| r.node_health.into())
+    }
+
+    pub async fn service_config(&self) -> io::Result<types::ServiceConfig> {
+        let query = schema::node_info::QueryServiceConfig::build(());
+        self.query(query).await.map(|r| r.service_config.into())
+    }
+
+    /// Returns whether the node produces the blocks instantly, periodically with
+    /// the returned block time, or only on `produce_blocks`.
+    pub async fn block_production_config(&self) -> io::Result<types::ProductionConfig> {
+        let query = schema::node_info::QueryBlockProductionConfig::build(());
+        let config = self
+            .query(query)
+            .await?
+            .block_production_config
+            .try_into()?;
+        Ok(config)
+    }
+
+    /// Returns the number of blocks produced by the node, the average coinbase
+    /// of these blocks, and the time of the latest production since the start of the node.
+    ///
+    /// Returns `None` if the metrics are disabled on the node.
+    pub async fn producer_metrics(&self) -> io::Result<Option<types::ProducerMetrics>> {
+        let query = schema::node_info::QueryProducerMetrics::build(());
+        self.query(query)
+            .await
+            .map(|r| r.producer_metrics.map(Into::into))
+    }
+
     pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.map(|r| r.chain.into())
     }
 
+    /// Returns the metadata of the asset from the chain configuration.
+    pub async fn asset_info(
+        &self,
+        asset_id: &AssetId,
+    ) -> io::Result<Option<types::AssetInfo>> {
+        let query = schema::chain::AssetInfoQuery::build(AssetInfoArgs {
+            asset_id: (*asset_id).into(),
+        });
+        let asset_info = self.query(query).await?.asset_info.map(Into::into);
+        Ok(asset_info)
+    }
+
+    /// Returns the amounts of the asset minted and burned per block
+    /// in the `[from_height, to_height]` range.
+    pub async fn asset_supply_history(
+        &self,
+        asset_id: &AssetId,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+    ) -> io::Result<Vec<types::AssetSupplyDelta>> {
+        let query =
+            schema::chain::AssetSupplyHistoryQuery::build(AssetSupplyHistoryArgs {
+                asset_id: (*asset_id).into(),
+                from_height: from_height.into(),
+                to_height: to_height.into(),
+            });
+        let history = self
+            .query(query)
+            .await?
+            .asset_supply_history
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(history)
+    }
+
+    /// Returns the total amount of the asset minted by the coinbase of all blocks.
+    ///
+    /// The total is accumulated while the blocks are executed, so the blocks executed
+    /// by the node before it started tracking the total are not counted.
+    pub async fn total_coinbase_minted(&self, asset_id: AssetId) -> io::Result<u128> {
+        let query =
+            schema::chain::TotalCoinbaseMintedQuery::build(TotalCoinbaseMintedArgs {
+                asset_id: asset_id.into(),
+            });
+        let total = self.query(query).await?.total_coinbase_minted.into();
+        Ok(total)
+    }
+
+    /// Returns the consensus-relevant part of the chain configuration the node was
+    /// started with.
+    pub async fn chain_config(&self) -> io::Result<types::ChainConfig> {
+        let query = schema::chain::ChainConfigQuery::build(());
+        let chain_config = self.query(query).await?.chain_config.into();
+        Ok(chain_config)
+    }
+
     /// Default dry run, matching the exact configuration as the node
     pub async fn dry_run(&self, tx: &Transaction) -> io::Result<Vec<Receipt>> {
         self.dry_run_opt(tx, None).await
@@ -370,6 +559,237 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run the transaction and calculate the fee it would pay, based on the
+    /// current consensus parameters of the node
+    pub async fn dry_run_with_fees(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<(Vec<Receipt>, types::FeeBreakdown)> {
+        let receipts = self.dry_run(tx).await?;
+        let params = self.chain_info().await?.consensus_parameters;
+        let fees = types::FeeBreakdown::new(tx, &receipts, &params).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                "Failed to calculate the fee of the transaction",
+            )
+        })?;
+
+        Ok((receipts, fees))
+    }
+
+    /// Dry run the transaction and calculate the amount its fee would credit to the
+    /// coinbase recipient of the block, based on the current consensus parameters of
+    /// the node. The fee is paid in the base asset, which is returned with the amount.
+    ///
+    /// The recipient is credited only if the block producer has it configured,
+    /// otherwise the fee is burned.
+    pub async fn dry_run_coinbase_effect(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<(AssetId, u64)> {
+        let receipts = self.dry_run(tx).await?;
+        let params = self.chain_info().await?.consensus_parameters;
+        let fees = types::FeeBreakdown::new(tx, &receipts, &params).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                "Failed to calculate the fee of the transaction",
+            )
+        })?;
+
+        Ok((params.base_asset_id, fees.total_fee))
+    }
+
+    /// Dry run the transaction and report the panics that will fail it after
+    /// the submission, like the `tro` without the variable output.
+    pub async fn dry_run_with_diagnostics(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<(Vec<Receipt>, Vec<types::DryRunDiagnostic>)> {
+        let receipts = self.dry_run(tx).await?;
+        let diagnostics = types::DryRunDiagnostic::from_receipts(&receipts);
+
+        Ok((receipts, diagnostics))
+    }
+
+    /// Dry run the transaction and estimate its total cost: the gas used by the script,
+    /// the max gas, and the min and max fees, based on the current consensus parameters
+    /// of the node.
+    pub async fn estimate_transaction_cost(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::TransactionCost> {
+        let receipts = self.dry_run(tx).await?;
+        let params = self.chain_info().await?.consensus_parameters;
+        types::TransactionCost::new(tx, &receipts, &params).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                "Failed to calculate the cost of the transaction",
+            )
+        })
+    }
+
+    /// Dry runs the script transaction with the maximum gas limit allowed by the node,
+    /// including the cap of the dry run gas, and returns the gas consumed by the script. The result can be used as a tight
+    /// `script_gas_limit` of the transaction.
+    ///
+    /// The gas price is zeroed and the UTXO validation is disabled for the estimation,
+    /// so the inputs don't need to cover the fee of the raised gas limit.
+    pub async fn estimate_gas_limit(&self, tx: &Transaction) -> io::Result<u64> {
+        let mut script = tx.as_script().cloned().ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidInput,
+                "Only script transactions have a gas limit",
+            )
+        })?;
+        let params = self.chain_info().await?.consensus_parameters;
+
+        script.policies_mut().set(PolicyType::GasPrice, Some(0));
+        let min_gas = script.min_gas(params.gas_costs(), params.fee_params());
+        *script.script_gas_limit_mut() =
+            self.max_script_gas_limit(&params, min_gas).await?;
+
+        let receipts = self.dry_run_opt(&script.into(), Some(false)).await?;
+        receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::Other,
+                    "The dry run of the transaction didn't return the script result",
+                )
+            })
+    }
+
+    /// The highest `script_gas_limit` of the script with the `min_gas` allowed by
+    /// the consensus parameters and accepted by the dry run of the node.
+    async fn max_script_gas_limit(
+        &self,
+        params: &ConsensusParameters,
+        min_gas: u64,
+    ) -> io::Result<u64> {
+        let gas_limit = params.tx_params().max_gas_per_tx.saturating_sub(min_gas);
+        let max_dry_run_gas = self.node_info().await?.max_dry_run_gas;
+        Ok(max_dry_run_gas.map_or(gas_limit, |max| gas_limit.min(max)))
+    }
+
+    /// Simulates the withdrawal of the `asset_id` from the fee collection contract
+    /// `contract_id` by the dry run and returns the amount that the recipient would
+    /// receive right now.
+    ///
+    /// The withdrawal transaction is validated with the same rules as the real one,
+    /// including the variable output receiving the asset, and its fee is zeroed.
+    pub async fn simulate_fee_collection(
+        &self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+    ) -> io::Result<u64> {
+        let layout = WithdrawalOutputs::new(1, vec![*asset_id]);
+        let output_index = layout
+            .variable_output_index_for(asset_id)
+            .expect("The asset is withdrawn");
+        let params = self.chain_info().await?.consensus_parameters;
+
+        let mut builder = TransactionBuilder::script(
+            withdrawal_script(),
+            script_data(asset_id, output_index, contract_id),
+        );
+        // The UTXO validation is disabled for the dry run, so the coin paying
+        // the zero fee doesn't need to exist.
+        builder
+            .gas_price(0)
+            .add_input(Input::coin_signed(
+                Default::default(),
+                Default::default(),
+                0,
+                params.base_asset_id,
+                Default::default(),
+                0,
+                Default::default(),
+            ))
+            .add_input(Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                *contract_id,
+            ))
+            .add_witness(Witness::default());
+        for output in layout.outputs() {
+            builder.add_output(output);
+        }
+        let mut script = builder.finalize();
+        validate_fee_collection_tx(&script)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidInput, e))?;
+        let min_gas = script.min_gas(params.gas_costs(), params.fee_params());
+        *script.script_gas_limit_mut() =
+            self.max_script_gas_limit(&params, min_gas).await?;
+
+        let receipts = self.dry_run_opt(&script.into(), Some(false)).await?;
+        let succeeded = receipts.iter().any(|receipt| {
+            matches!(
+                receipt,
+                Receipt::ScriptResult {
+                    result: ScriptExecutionResult::Success,
+                    ..
+                }
+            )
+        });
+        if !succeeded {
+            return Err(io::Error::new(
+                ErrorKind::Other,
+                format!("The withdrawal from the contract {contract_id} reverted"),
+            ))
+        }
+
+        let amount = receipts
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::TransferOut {
+                    id,
+                    amount,
+                    asset_id: transferred,
+                    ..
+                } if id == contract_id && transferred == asset_id => Some(*amount),
+                _ => None,
+            })
+            .fold(0u64, |total, amount| total.saturating_add(amount));
+        Ok(amount)
+    }
+
+    /// Statically checks the script of the transaction and the code of its contract
+    /// inputs for the instructions that will panic because of the missing inputs or
+    /// outputs, so the mistake is found before the submission. Only the script
+    /// transactions execute the code, so the other transactions have no lints.
+    pub async fn lint_transaction(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<Vec<types::Lint>> {
+        let script = match tx {
+            Transaction::Script(script) => script,
+            Transaction::Create(_) | Transaction::Mint(_) => return Ok(vec![]),
+        };
+
+        let mut lints = types::Lint::check(script, script.script(), None);
+        let contract_ids = script
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Contract(contract) => Some(contract.contract_id),
+                _ => None,
+            })
+            .unique();
+        for contract_id in contract_ids {
+            if let Some(code) = self.contract_code(&contract_id).await? {
+                lints.extend(types::Lint::check(script, &code, Some(contract_id)));
+            }
+        }
+
+        Ok(lints)
+    }
+
     /// Estimate predicates for the transaction
     pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
         let serialized_tx = tx.to_bytes();
@@ -395,6 +815,44 @@ impl FuelClient {
         Ok(id)
     }
 
+    /// Submits the transaction with the idempotency `key`. The node rejects other
+    /// submissions with the same `key` during the TTL of the keys and returns
+    /// the id of the transaction submitted first, so the retries of the same logical
+    /// submission don't create duplicates. The `key` can be reused if the submission failed.
+    pub async fn submit_with_idempotency(
+        &self,
+        tx: &Transaction,
+        key: [u8; 32],
+    ) -> io::Result<types::IdempotentSubmission> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitWithIdempotencyMutation::build(
+            schema::tx::SubmitWithIdempotencyArgs {
+                tx: HexString(Bytes(tx)),
+                idempotency_key: Bytes32::from(key).into(),
+            },
+        );
+
+        let submission: types::IdempotentSubmission = self
+            .query(query)
+            .await?
+            .submit_with_idempotency
+            .try_into()?;
+        Ok(submission)
+    }
+
+    /// Removes the pending transaction from the `TxPool`, along with the transactions
+    /// depending on it, so it can be resubmitted, for example, with a higher gas price.
+    /// Returns `false` if the transaction is not in the `TxPool`, e.g., it is already
+    /// included in a block. Requires the `debug` mode on the node.
+    pub async fn remove_from_txpool(&self, tx_id: &TxId) -> io::Result<bool> {
+        let query = schema::tx::RemoveFromTxpoolMutation::build(TxIdArgs {
+            id: (*tx_id).into(),
+        });
+
+        let removed = self.query(query).await?.remove_from_txpool;
+        Ok(removed)
+    }
+
     /// Submit the transaction and wait for it either to be included in
     /// a block or removed from `TxPool`.
     ///
@@ -426,6 +884,137 @@ impl FuelClient {
         Ok(status)
     }
 
+    #[cfg(feature = "subscriptions")]
+    /// Submits the transaction built by the `build` for the `gas_price` and awaits
+    /// its commit like the `submit_and_await_commit`. If the transaction is evicted
+    /// from the full `TxPool` by the transactions paying the higher gas price, it is
+    /// built again for the gas price bumped according to the `policy` and resubmitted.
+    /// The `build` should sign the transaction, because the bumped gas price changes
+    /// its id.
+    ///
+    /// Returns the [`TransactionStatus::SqueezedOut`] if the transaction was squeezed
+    /// out for another reason, or the `policy` doesn't allow another resubmission.
+    pub async fn submit_and_await_commit_with_resubmit<F>(
+        &self,
+        mut build: F,
+        mut gas_price: Word,
+        policy: &types::ResubmitPolicy,
+    ) -> io::Result<TransactionStatus>
+    where
+        F: FnMut(Word) -> Transaction,
+    {
+        loop {
+            let status = self.submit_and_await_commit(&build(gas_price)).await?;
+            match (&status, policy.next_gas_price(gas_price)) {
+                (
+                    TransactionStatus::SqueezedOut {
+                        kind: types::SqueezedOutKind::Evicted,
+                        ..
+                    },
+                    Some(next),
+                ) => gas_price = next,
+                _ => return Ok(status),
+            }
+        }
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Builds the script transaction, funds its fee from the `funding` coins owned by
+    /// the `signer`, returns the change to the `signer`, signs the transaction, submits
+    /// it, and awaits its commit like the `submit_and_await_commit`.
+    ///
+    /// The gas limit is estimated by the dry run, and the gas price is the minimal one
+    /// accepted by the node. The scripts calling the contracts require the contract
+    /// inputs and outputs, so such transactions should be built with the
+    /// `TransactionBuilder`, funded by the `FundWith::fund_with_witness`, and signed
+    /// by the `FinalizeSigned`.
+    pub async fn send_script<S>(
+        &self,
+        script: Vec<u8>,
+        script_data: Vec<u8>,
+        signer: &S,
+        funding: &[types::CoinInfo],
+    ) -> io::Result<TransactionStatus>
+    where
+        S: types::Signer + ?Sized,
+    {
+        use fuel_core_types::fuel_crypto::Signature;
+        use types::{
+            FinalizeSigned,
+            FundWith,
+        };
+        // The signature of the `signer` is placed into the first witness.
+        const WITNESS_INDEX: u8 = 0;
+
+        let params = self.chain_info().await?.consensus_parameters;
+        let gas_price = self.node_info().await?.min_gas_price;
+        let owner = Input::owner(&signer.public_key());
+
+        let mut builder = TransactionBuilder::script(script, script_data);
+        builder
+            .gas_price(gas_price)
+            .add_witness(vec![0; Signature::LEN].into());
+
+        // The UTXO validation is disabled for the estimation,
+        // so the coin paying the fee doesn't need to exist.
+        let mut estimation = builder.clone();
+        estimation.add_input(Input::coin_signed(
+            Default::default(),
+            owner,
+            0,
+            params.base_asset_id,
+            Default::default(),
+            WITNESS_INDEX,
+            Default::default(),
+        ));
+        let gas_limit = self
+            .estimate_gas_limit(&estimation.finalize_as_transaction())
+            .await?;
+        builder.script_gas_limit(gas_limit);
+
+        builder
+            .fund_with_witness(funding, &owner, WITNESS_INDEX, 0, &params)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidInput, e))?;
+        builder.add_output(Output::change(owner, 0, params.base_asset_id));
+        let tx = builder.finalize_signed(signer, &params.chain_id).await;
+
+        self.submit_and_await_commit(&tx.into()).await
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Submits transaction and awaits its commit like the `submit_and_await_commit`,
+    /// but the node drops the transaction if it is not included in a block by
+    /// the `expire_at` height. In this case, the [`TransactionStatus::Expired`] is returned.
+    pub async fn submit_with_expiry(
+        &self,
+        tx: &Transaction,
+        expire_at: BlockHeight,
+    ) -> io::Result<TransactionStatus> {
+        use cynic::SubscriptionBuilder;
+        let tx = tx.clone().to_bytes();
+        let s = schema::tx::SubmitAndAwaitWithExpirySubscription::build(
+            schema::tx::SubmitAndAwaitWithExpiryArgs {
+                tx: HexString(Bytes(tx)),
+                expire_at: (*expire_at).into(),
+            },
+        );
+
+        let mut stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::tx::SubmitAndAwaitWithExpirySubscription>| {
+                let status: TransactionStatus =
+                    r?.submit_and_await_with_expiry.try_into()?;
+                Result::<_, io::Error>::Ok(status)
+            },
+        );
+
+        let status = stream.next().await.ok_or(io::Error::new(
+            io::ErrorKind::Other,
+            "Failed to get status from the submission",
+        ))??;
+
+        Ok(status)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Submits transaction, await confirmation and return receipts.
     pub async fn submit_and_await_commit_with_receipts(
@@ -554,6 +1143,29 @@ impl FuelClient {
         Ok(transaction.map(|tx| tx.try_into()).transpose()?)
     }
 
+    /// Returns the outputs of the committed transaction, with the variable outputs
+    /// filled by the execution: the recipient, the amount, and the asset id.
+    /// The `Mint` transaction doesn't have outputs, so the list is empty for it.
+    pub async fn transaction_outputs(&self, tx_id: &TxId) -> io::Result<Vec<Output>> {
+        let transaction = self
+            .transaction(tx_id)
+            .await?
+            .ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::NotFound,
+                    format!("transaction {tx_id} not found"),
+                )
+            })?
+            .transaction;
+
+        let outputs = match transaction {
+            Transaction::Script(script) => script.outputs().clone(),
+            Transaction::Create(create) => create.outputs().clone(),
+            Transaction::Mint(_) => vec![],
+        };
+        Ok(outputs)
+    }
+
     /// Get the status of a transaction
     pub async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
@@ -577,6 +1189,45 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns the height of the block that included the transaction and the index
+    /// of the transaction within this block. Returns `None` if the transaction is
+    /// unknown or is not included into a block yet, like the submitted one.
+    pub async fn transaction_location(
+        &self,
+        tx_id: &TxId,
+    ) -> io::Result<Option<(BlockHeight, u32)>> {
+        let status = match self.transaction_status(tx_id).await {
+            Ok(status) => status,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let block_id = match status {
+            TransactionStatus::Success { block_id, .. }
+            | TransactionStatus::Failure { block_id, .. } => block_id,
+            _ => return Ok(None),
+        };
+        let block_id = BlockId::from_str(&block_id)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+        let block = self.block(&block_id).await?.ok_or_else(|| {
+            io::Error::new(ErrorKind::NotFound, format!("block {block_id} not found"))
+        })?;
+        let index = block
+            .transactions
+            .iter()
+            .position(|id| id == tx_id)
+            .ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("transaction {tx_id} is not in the block {block_id}"),
+                )
+            })?;
+        let index = u32::try_from(index)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+        Ok(Some((block.header.height.into(), index)))
+    }
+
     #[tracing::instrument(skip(self), level = "debug")]
     #[cfg(feature = "subscriptions")]
     /// Subscribe to the status of a transaction
@@ -629,6 +1280,30 @@ impl FuelClient {
         }
     }
 
+    #[cfg(feature = "subscriptions")]
+    /// Awaits for all the transactions to be committed into blocks concurrently
+    /// and returns their statuses in the order of `tx_ids`. Returns an error
+    /// if any of the transactions is not committed within the `timeout`.
+    pub async fn await_commits(
+        &self,
+        tx_ids: &[TxId],
+        timeout: Duration,
+    ) -> io::Result<Vec<TransactionStatus>> {
+        let commits = tx_ids.iter().map(|id| self.await_transaction_commit(id));
+
+        tokio::time::timeout(timeout, futures::future::try_join_all(commits))
+            .await
+            .map_err(|_| {
+                io::Error::new(
+                    ErrorKind::TimedOut,
+                    format!(
+                        "{} transactions were not committed within {timeout:?}",
+                        tx_ids.len()
+                    ),
+                )
+            })?
+    }
+
     /// returns a paginated set of transactions sorted by block height
     pub async fn transactions(
         &self,
@@ -652,44 +1327,233 @@ impl FuelClient {
         Ok(transactions)
     }
 
-    pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
-        let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
+    /// Returns the page of the `owner`'s transactions that follow the `cursor`.
+    ///
+    /// The transactions are ordered by the block height and the index within the block, so
+    /// new blocks only append transactions to the end, and walking the pages with the returned
+    /// cursor doesn't skip or duplicate transactions. If the page is empty, the returned cursor
+    /// is the requested one, so the walk can be resumed after new blocks arrive.
+    pub async fn transactions_by_owner_paged(
+        &self,
+        owner: &Address,
+        cursor: Option<String>,
+        page_size: usize,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let request = PaginationRequest {
+            cursor: cursor.clone(),
+            results: i32::try_from(page_size).unwrap_or(i32::MAX),
+            direction: PageDirection::Forward,
+        };
+        let mut page = self.transactions_by_owner(owner, request).await?;
+        if page.cursor.is_none() {
+            page.cursor = cursor;
+        }
+        Ok(page)
+    }
 
-        let tx = self.query(query).await?.transaction.ok_or_else(|| {
-            io::Error::new(ErrorKind::NotFound, format!("transaction {id} not found"))
-        })?;
+    /// Returns the pending transactions that the transaction depends on, directly
+    /// or transitively. Returns an empty list if the transaction is unknown to the `TxPool`.
+    pub async fn transaction_dependencies(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Vec<Transaction>> {
+        let query = schema::tx::TransactionDependenciesQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
 
-        let receipts = tx
-            .receipts
-            .map(|vec| {
-                let vec: Result<Vec<Receipt>, ConversionError> =
-                    vec.into_iter().map(TryInto::<Receipt>::try_into).collect();
-                vec
-            })
-            .transpose()?;
+        let dependencies = self
+            .query(query)
+            .await?
+            .transaction_dependencies
+            .into_iter()
+            .map(TryInto::<Transaction>::try_into)
+            .collect::<Result<Vec<_>, ConversionError>>()?;
 
-        Ok(receipts)
+        Ok(dependencies)
     }
 
-    #[cfg(feature = "test-helpers")]
-    pub async fn all_receipts(&self) -> io::Result<Vec<Receipt>> {
-        let query = schema::tx::AllReceipts::build(());
-        let receipts = self.query(query).await?.all_receipts;
+    /// Returns the origin of the pending transaction: the hex-encoded id of the peer
+    /// that gossiped it, or `local` if it was submitted to the node directly.
+    /// Returns `None` if the transaction is unknown to the `TxPool`.
+    pub async fn transaction_source(&self, id: &TxId) -> io::Result<Option<String>> {
+        let query =
+            schema::tx::TransactionSourceQuery::build(TxIdArgs { id: (*id).into() });
 
-        let vec: Result<Vec<Receipt>, ConversionError> = receipts
-            .into_iter()
-            .map(TryInto::<Receipt>::try_into)
-            .collect();
+        let source = self.query(query).await?.transaction_source;
 
-        Ok(vec?)
+        Ok(source)
     }
 
-    pub async fn produce_blocks(
+    /// Returns the pending transactions that use the coin with `utxo_id` as an input.
+    pub async fn pending_spenders_of(
         &self,
-        blocks_to_produce: u32,
-        start_timestamp: Option<u64>,
-    ) -> io::Result<BlockHeight> {
-        let query = schema::block::BlockMutation::build(ProduceBlockArgs {
+        utxo_id: &UtxoId,
+    ) -> io::Result<Vec<Transaction>> {
+        let query = schema::tx::PendingSpendersOfQuery::build(
+            schema::tx::PendingSpendersOfArgs {
+                utxo_id: (*utxo_id).into(),
+            },
+        );
+
+        let spenders = self
+            .query(query)
+            .await?
+            .pending_spenders_of
+            .into_iter()
+            .map(TryInto::<Transaction>::try_into)
+            .collect::<Result<Vec<_>, ConversionError>>()?;
+
+        Ok(spenders)
+    }
+
+    /// Retrieve the number and the total gas of the transactions in the `TxPool`,
+    /// together with the coinbase that the next block would credit if it was produced now.
+    ///
+    /// The coinbase is only estimated if the node is in the `debug` mode.
+    pub async fn txpool_stats(&self) -> io::Result<types::TxpoolStats> {
+        let query = schema::tx::TxPoolStatsQuery::build(());
+
+        let stats = self.query(query).await?.tx_pool_stats.into();
+
+        Ok(stats)
+    }
+
+    /// Returns the proof that the transaction is included in its block, or `None`
+    /// if the transaction is not included in any block.
+    pub async fn transaction_inclusion_proof(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<types::TransactionProof>> {
+        let query = schema::tx::TransactionInclusionProofQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
+
+        let proof = self
+            .query(query)
+            .await?
+            .transaction_inclusion_proof
+            .map(Into::into);
+
+        Ok(proof)
+    }
+
+    /// Returns the coins created by the committed transaction with their UTXO ids.
+    pub async fn transaction_created_utxos(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<Vec<types::CreatedUtxo>>> {
+        let query = schema::tx::TransactionCreatedUtxosQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
+
+        let utxos = self
+            .query(query)
+            .await?
+            .transaction_created_utxos
+            .map(|utxos| utxos.into_iter().map(Into::into).collect());
+        Ok(utxos)
+    }
+
+    /// Returns the unspent coins created by the committed transaction, for example, by
+    /// the variable outputs of the withdrawal, so they can be spent right away without
+    /// querying all coins of the owner. The spent and the zero amount outputs are skipped.
+    pub async fn outputs_as_coins(&self, id: &TxId) -> io::Result<Vec<types::CoinInfo>> {
+        let query = schema::tx::TransactionUnspentCoinsQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
+
+        let coins = self
+            .query(query)
+            .await?
+            .transaction_unspent_coins
+            .ok_or_else(|| {
+                io::Error::new(ErrorKind::NotFound, format!("transaction {id} not found"))
+            })?
+            .into_iter()
+            .map(|coin| types::CoinInfo::from(&types::Coin::from(coin)))
+            .collect();
+        Ok(coins)
+    }
+
+    /// Returns the address that paid the fee of the transaction: the owner of its
+    /// first base asset input. Returns `None` if the transaction is not found
+    /// or doesn't pay the fee, like `Mint`.
+    pub async fn transaction_fee_payer(&self, id: &TxId) -> io::Result<Option<Address>> {
+        let query =
+            schema::tx::TransactionFeePayerQuery::build(TxIdArgs { id: (*id).into() });
+
+        let fee_payer = self
+            .query(query)
+            .await?
+            .transaction
+            .and_then(|tx| tx.fee_payer)
+            .map(Into::into);
+        Ok(fee_payer)
+    }
+
+    pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
+        let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
+
+        let tx = self.query(query).await?.transaction.ok_or_else(|| {
+            io::Error::new(ErrorKind::NotFound, format!("transaction {id} not found"))
+        })?;
+
+        let receipts = tx
+            .receipts
+            .map(|vec| {
+                let vec: Result<Vec<Receipt>, ConversionError> =
+                    vec.into_iter().map(TryInto::<Receipt>::try_into).collect();
+                vec
+            })
+            .transpose()?;
+
+        Ok(receipts)
+    }
+
+    /// Returns the receipts of the executed transaction. Unlike [`Self::receipts`],
+    /// returns `None` instead of the error if the `tx_id` is unknown.
+    pub async fn transaction_receipts(
+        &self,
+        tx_id: &TxId,
+    ) -> io::Result<Option<Vec<Receipt>>> {
+        let query = schema::tx::TransactionQuery::build(TxIdArgs {
+            id: (*tx_id).into(),
+        });
+
+        let receipts = self
+            .query(query)
+            .await?
+            .transaction
+            .and_then(|tx| tx.receipts)
+            .map(|vec| {
+                vec.into_iter()
+                    .map(TryInto::<Receipt>::try_into)
+                    .collect::<Result<Vec<_>, ConversionError>>()
+            })
+            .transpose()?;
+
+        Ok(receipts)
+    }
+
+    #[cfg(feature = "test-helpers")]
+    pub async fn all_receipts(&self) -> io::Result<Vec<Receipt>> {
+        let query = schema::tx::AllReceipts::build(());
+        let receipts = self.query(query).await?.all_receipts;
+
+        let vec: Result<Vec<Receipt>, ConversionError> = receipts
+            .into_iter()
+            .map(TryInto::<Receipt>::try_into)
+            .collect();
+
+        Ok(vec?)
+    }
+
+    pub async fn produce_blocks(
+        &self,
+        blocks_to_produce: u32,
+        start_timestamp: Option<u64>,
+    ) -> io::Result<BlockHeight> {
+        let query = schema::block::BlockMutation::build(ProduceBlockArgs {
             blocks_to_produce: blocks_to_produce.into(),
             start_timestamp: start_timestamp
                 .map(|timestamp| Tai64Timestamp::from(Tai64(timestamp))),
@@ -700,6 +1564,183 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Produces blocks in the same way as [`Self::produce_blocks`] and returns
+    /// the summary of each produced block.
+    pub async fn produce_blocks_detailed(
+        &self,
+        blocks_to_produce: u32,
+        start_timestamp: Option<u64>,
+    ) -> io::Result<Vec<types::BlockSummary>> {
+        let new_height = *self
+            .produce_blocks(blocks_to_produce, start_timestamp)
+            .await?;
+        let first_height = new_height
+            .saturating_add(1)
+            .saturating_sub(blocks_to_produce);
+
+        let mut summaries = vec![];
+        for height in first_height..=new_height {
+            let block = self.block_by_height(height).await?.ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("The produced block at height {height} is not found"),
+                )
+            })?;
+            let coinbase = self
+                .block_coinbase(height.into())
+                .await?
+                .map(|coinbase| coinbase.amounts)
+                .unwrap_or_default();
+            summaries.push(types::BlockSummary {
+                height,
+                tx_count: block.header.transactions_count,
+                coinbase,
+                timestamp: block.header.time,
+            });
+        }
+
+        Ok(summaries)
+    }
+
+    /// Produces the blocks one by one until the `TxPool` has no transactions or
+    /// the `max_blocks` are produced, and returns the height of the latest block.
+    ///
+    /// The production stops earlier if the produced block doesn't include any
+    /// transaction, because the remaining ones can't be included right now.
+    /// Requires the `debug` mode on the node.
+    pub async fn produce_until_empty(&self, max_blocks: u32) -> io::Result<BlockHeight> {
+        let mut height: BlockHeight =
+            self.chain_info().await?.latest_block.header.height.into();
+        for _ in 0..max_blocks {
+            if self.txpool_stats().await?.tx_count == 0 {
+                break
+            }
+            let summary = self
+                .produce_blocks_detailed(1, None)
+                .await?
+                .pop()
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::Other, "The block is not produced")
+                })?;
+            height = summary.height.into();
+            // Only the mint transaction is included.
+            if summary.tx_count <= 1 {
+                break
+            }
+        }
+        Ok(height)
+    }
+
+    /// Produces the next block with the `txs` in the specified order. The transactions
+    /// bypass the `TxPool`, so the block contains them even if they are not submitted.
+    ///
+    /// Requires the `debug` mode on the node.
+    pub async fn produce_block_with_txs(
+        &self,
+        txs: Vec<Transaction>,
+    ) -> io::Result<BlockHeight> {
+        let query =
+            schema::block::ProduceBlockWithTxsMutation::build(ProduceBlockWithTxsArgs {
+                txs: txs
+                    .into_iter()
+                    .map(|tx| HexString(Bytes(tx.to_bytes())))
+                    .collect(),
+                start_timestamp: None,
+            });
+
+        let new_height = self.query(query).await?.produce_block_with_txs;
+
+        Ok(new_height.into())
+    }
+
+    /// Produces the next block only if its coinbase exceeds the `threshold` of the base
+    /// asset, so the fees are accrued into the meaningful blocks. Returns the height of
+    /// the produced block, or `None` if the block is not produced.
+    ///
+    /// Requires the `debug` mode on the node.
+    pub async fn produce_block_if_coinbase_over(
+        &self,
+        threshold: u64,
+    ) -> io::Result<Option<BlockHeight>> {
+        let query = schema::block::ProduceBlockIfCoinbaseOverMutation::build(
+            schema::block::ProduceBlockIfCoinbaseOverArgs {
+                threshold: threshold.into(),
+            },
+        );
+
+        let height = self
+            .query(query)
+            .await?
+            .produce_block_if_coinbase_over
+            .map(Into::into);
+        Ok(height)
+    }
+
+    /// Re-executes the transactions of the block at the `height` on top of the current
+    /// state without committing the result, and returns the receipts of each transaction.
+    /// The last receipts are of the mint transaction crediting the coinbase. The replay
+    /// uses the timestamp and the gas limit of the original block.
+    ///
+    /// Requires the `debug` mode on the node. The inputs spent by the block are already
+    /// spent, so the replay of the blocks with coins requires the disabled UTXO validation.
+    pub async fn replay_block(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Vec<Vec<Receipt>>> {
+        let query =
+            schema::block::ReplayBlockMutation::build(schema::block::ReplayBlockArgs {
+                height: (*height).into(),
+            });
+
+        let replayed = self.query(query).await?.replay_block;
+        replayed
+            .into_iter()
+            .map(|receipts| {
+                receipts
+                    .into_iter()
+                    .map(|receipt| receipt.try_into().map_err(Into::into))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Replaces the gas limit of the blocks produced by the node after the call.
+    /// The `limit` can't exceed the block gas limit of the chain.
+    ///
+    /// Requires the `debug` mode on the node.
+    pub async fn set_block_gas_limit(&self, limit: u64) -> io::Result<()> {
+        let query = schema::block::SetBlockGasLimitMutation::build(
+            schema::block::SetBlockGasLimitArgs {
+                limit: limit.into(),
+            },
+        );
+
+        self.query(query).await?;
+
+        Ok(())
+    }
+
+    /// Credits the `amount` of the `asset_id` to the `contract_id` and produces a block.
+    /// Allows to set up the exact balance of the coinbase recipient without paying fees.
+    ///
+    /// Requires the `debug` mode and the disabled UTXO validation on the node.
+    pub async fn credit_coinbase(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> io::Result<BlockHeight> {
+        let query = schema::block::CreditCoinbaseMutation::build(CreditCoinbaseArgs {
+            contract_id: contract_id.into(),
+            asset_id: asset_id.into(),
+            amount: amount.into(),
+        });
+
+        let new_height = self.query(query).await?.credit_coinbase;
+
+        Ok(new_height.into())
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -720,6 +1761,206 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Retrieve the block at the `height` together with the amounts credited by its mint
+    /// transaction. It distinguishes the blocks without the mint transaction from
+    /// the blocks that didn't collect any fees.
+    pub async fn block_with_coinbase_by_height(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::BlockWithCoinbase>> {
+        let query =
+            schema::block::BlockWithCoinbaseByHeightQuery::build(BlockByHeightArgs {
+                height: Some((*height).into()),
+            });
+
+        let block = self.query(query).await?.block.map(Into::into);
+
+        Ok(block)
+    }
+
+    /// Retrieve the amounts credited to the coinbase recipient by the mint transaction
+    /// of the block at the `height`. Returns `None` if the block doesn't exist
+    /// or doesn't have transactions, like the genesis block.
+    pub async fn block_coinbase(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::Coinbase>> {
+        let query = schema::block::CoinbaseQuery::build(CoinbaseArgs {
+            height: (*height).into(),
+        });
+
+        let coinbase = self.query(query).await?.coinbase.map(Into::into);
+
+        Ok(coinbase)
+    }
+
+    /// Retrieve the header of the genesis block and the hash of the chain config
+    /// committed by it. Two nodes with the same hash are on the same network.
+    pub async fn genesis_info(&self) -> io::Result<types::GenesisInfo> {
+        let block = self
+            .blocks(PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            })
+            .await?
+            .results
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "The genesis block is not found")
+            })?;
+
+        match block.consensus {
+            types::Consensus::Genesis(genesis) => Ok(types::GenesisInfo {
+                header: block.header,
+                chain_config_hash: genesis.chain_config_hash,
+            }),
+            _ => Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "The first block at the height {} is not the genesis block",
+                    block.header.height
+                ),
+            )),
+        }
+    }
+
+    /// Retrieve the amounts that the next block would credit to the coinbase recipient
+    /// if it was produced now from the transactions in the `TxPool`.
+    ///
+    /// Requires the `debug` mode on the node.
+    pub async fn pending_coinbase(&self) -> io::Result<Vec<(AssetId, u64)>> {
+        let query = schema::block::PendingCoinbaseQuery::build(());
+
+        let coinbase: types::Coinbase = self.query(query).await?.pending_coinbase.into();
+
+        Ok(coinbase.amounts)
+    }
+
+    /// Retrieve the sum of the coinbase amounts credited to the `contract_id` by the blocks
+    /// up to and including the `up_to` height.
+    ///
+    /// Requires the `debug` mode on the node.
+    pub async fn accrued_coinbase(
+        &self,
+        contract_id: &ContractId,
+        up_to: BlockHeight,
+    ) -> io::Result<u64> {
+        let query = schema::block::AccruedCoinbaseQuery::build(AccruedCoinbaseArgs {
+            contract_id: (*contract_id).into(),
+            up_to: (*up_to).into(),
+        });
+
+        let accrued = self.query(query).await?.accrued_coinbase.into();
+
+        Ok(accrued)
+    }
+
+    /// Retrieve the coinbase credited to the `recipient` by the blocks within
+    /// the `range` as `(height, asset id, amount)`, ordered by the height.
+    /// The blocks that didn't credit the `recipient` are skipped. The `range`
+    /// can't contain more than 10000 blocks.
+    pub async fn blocks_crediting(
+        &self,
+        recipient: ContractId,
+        range: Range<BlockHeight>,
+    ) -> io::Result<Vec<(BlockHeight, AssetId, u64)>> {
+        if range.start >= range.end {
+            return Ok(vec![])
+        }
+
+        let query = schema::block::BlocksCreditingQuery::build(BlocksCreditingArgs {
+            recipient: recipient.into(),
+            from: (*range.start).into(),
+            to: (*range.end).into(),
+        });
+
+        let credits = self
+            .query(query)
+            .await?
+            .blocks_crediting
+            .into_iter()
+            .map(|credit| {
+                let height: u32 = credit.block_height.into();
+                (height.into(), credit.asset_id.into(), credit.amount.into())
+            })
+            .collect();
+
+        Ok(credits)
+    }
+
+    /// Retrieve the latest `limit` coinbase credits of the `recipient`,
+    /// ordered from the newest to the oldest.
+    pub async fn recent_coinbase_credits(
+        &self,
+        recipient: ContractId,
+        limit: usize,
+    ) -> io::Result<Vec<types::CoinbaseCredit>> {
+        let limit = u32::try_from(limit).unwrap_or(u32::MAX);
+        let query =
+            schema::block::RecentCoinbaseCreditsQuery::build(RecentCoinbaseCreditsArgs {
+                recipient: recipient.into(),
+                limit: limit.into(),
+            });
+
+        let credits = self
+            .query(query)
+            .await?
+            .recent_coinbase_credits
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(credits)
+    }
+
+    /// Estimates the number of blocks after which the balance of the `asset_id`
+    /// of the `recipient` reaches the `target`, based on the average coinbase
+    /// credited to the `recipient` per block by the latest 100 blocks. The credits
+    /// of these blocks are fetched by one [`Self::blocks_crediting`] request.
+    ///
+    /// Returns `Some(0)` if the balance already reaches the `target`, and `None`
+    /// if the latest blocks didn't credit the `recipient`.
+    pub async fn blocks_until_coinbase(
+        &self,
+        recipient: ContractId,
+        asset_id: AssetId,
+        target: u64,
+    ) -> io::Result<Option<u64>> {
+        const RECENT_BLOCKS: u32 = 100;
+
+        let balance = self.contract_balance(&recipient, Some(&asset_id)).await?;
+        let missing = match target.checked_sub(balance) {
+            None | Some(0) => return Ok(Some(0)),
+            Some(missing) => missing,
+        };
+
+        let latest_height = self.chain_info().await?.latest_block.header.height;
+        let end = latest_height.saturating_add(1);
+        // The genesis block is not produced by the block producer.
+        let start = end.saturating_sub(RECENT_BLOCKS).max(1);
+        let blocks = end.saturating_sub(start);
+        let credited: u128 = self
+            .blocks_crediting(recipient, start.into()..end.into())
+            .await?
+            .into_iter()
+            .filter(|(_, asset, _)| *asset == asset_id)
+            .map(|(_, _, amount)| u128::from(amount))
+            .sum();
+        if credited == 0 {
+            return Ok(None)
+        }
+
+        // `ceil(missing / (credited / blocks))` without the rounding of the rate.
+        let scaled = u128::from(missing).saturating_mul(u128::from(blocks));
+        let estimate = scaled
+            .saturating_add(credited.saturating_sub(1))
+            .checked_div(credited)
+            .unwrap_or_default();
+        Ok(Some(u64::try_from(estimate).unwrap_or(u64::MAX)))
+    }
+
     /// Retrieve multiple blocks
     pub async fn blocks(
         &self,
@@ -732,6 +1973,199 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Estimates how long it takes to commit the transaction submitted now,
+    /// based on the block production configured on the node.
+    ///
+    /// The node producing the blocks instantly commits the transaction right away,
+    /// and the node producing the blocks periodically commits it at most in
+    /// the `block_time`. The node producing the blocks manually doesn't commit
+    /// the transaction on its own, so the estimation returns an error.
+    pub async fn estimate_commit_time(&self) -> io::Result<Duration> {
+        match self.block_production_config().await? {
+            types::ProductionConfig::Instant => Ok(Duration::ZERO),
+            types::ProductionConfig::Interval { block_time } => Ok(block_time),
+            types::ProductionConfig::Manual => Err(io::Error::new(
+                io::ErrorKind::Other,
+                "The node produces the blocks manually, so the commit time is unknown",
+            )),
+        }
+    }
+
+    /// Retrieve the blocks with the Tai64 timestamps within the `[from_time, to_time]` range
+    pub async fn blocks_by_time_range(
+        &self,
+        from_time: u64,
+        to_time: u64,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Block, String>> {
+        let query = schema::block::BlocksByTimeRangeQuery::build(
+            (
+                Tai64Timestamp::from(Tai64(from_time)),
+                Tai64Timestamp::from(Tai64(to_time)),
+                request,
+            )
+                .into(),
+        );
+
+        let blocks = self.query(query).await?.blocks_by_time_range.into();
+
+        Ok(blocks)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the headers of the newly imported blocks
+    ///
+    /// The stream never ends on its own, so consider wrapping it
+    /// with a `tokio::time::timeout`.
+    pub async fn subscribe_block_headers(
+        &self,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::block::Header>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::NewBlockHeadersSubscription::build(());
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|r| r.map(|header| header.new_block_headers.into()));
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the changes of the contracts' balances caused by the newly
+    /// imported blocks. If the `contract_id` is set, only the changes of its
+    /// balances are streamed.
+    ///
+    /// The stream never ends on its own, so consider wrapping it
+    /// with a `tokio::time::timeout`.
+    pub async fn subscribe_contract_balance_changes(
+        &self,
+        contract_id: Option<&ContractId>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::ContractBalanceChange>>>
+    {
+        use cynic::SubscriptionBuilder;
+        let s = schema::contract::ContractBalanceChangesSubscription::build(
+            schema::contract::ContractBalanceChangesArgs {
+                contract_id: contract_id.map(|id| (*id).into()),
+            },
+        );
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|r| r.map(|change| change.contract_balance_changes.into()));
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the transactions from the newly imported blocks that produced
+    /// at least one receipt matching the `filter`. The filtering happens on the node,
+    /// so other transactions are not streamed at all.
+    ///
+    /// The stream never ends on its own, so consider wrapping it
+    /// with a `tokio::time::timeout`.
+    pub async fn subscribe_transactions_with_receipt(
+        &self,
+        filter: types::ReceiptFilter,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::TransactionWithReceipts>>>
+    {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx::TransactionsWithReceiptSubscription::build(
+            schema::tx::TransactionsWithReceiptArgs {
+                receipt_type: filter.receipt_type.map(Into::into),
+                contract_id: filter.contract_id.map(Into::into),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(|r| {
+            let tx = r?.transactions_with_receipt.try_into()?;
+            Ok(tx)
+        });
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the balance of the `asset_id` owned by the `owner`. The stream
+    /// yields the new balance each time the newly imported block changes it.
+    ///
+    /// The stream never ends on its own, so consider wrapping it
+    /// with a `tokio::time::timeout`.
+    pub async fn subscribe_balance(
+        &self,
+        owner: Address,
+        asset_id: AssetId,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<u64>> + '_> {
+        let headers = Box::pin(self.subscribe_block_headers().await?);
+        let balance = self.balance(&owner, Some(&asset_id)).await?;
+
+        let stream = futures::stream::unfold(
+            (headers, balance),
+            move |(mut headers, last_balance)| async move {
+                loop {
+                    if let Err(err) = headers.next().await? {
+                        return Some((Err(err), (headers, last_balance)))
+                    }
+                    match self.balance(&owner, Some(&asset_id)).await {
+                        Ok(balance) if balance == last_balance => continue,
+                        Ok(balance) => return Some((Ok(balance), (headers, balance))),
+                        Err(err) => return Some((Err(err), (headers, last_balance))),
+                    }
+                }
+            },
+        );
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Awaits until the node imports the block at the `target` height,
+    /// or returns an error if it doesn't happen within the `timeout`.
+    pub async fn await_block_height(
+        &self,
+        target: BlockHeight,
+        timeout: Duration,
+    ) -> io::Result<()> {
+        // The subscription is established lazily on the first poll of the stream,
+        // so the blocks imported before it may be missed. Because of that, we re-check
+        // the existence of the target block each time the stream is idle for a while.
+        const RECHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+        let wait = async {
+            let mut headers = self.subscribe_block_headers().await?;
+            loop {
+                if self.block_by_height(*target).await?.is_some() {
+                    return Ok(())
+                }
+
+                match tokio::time::timeout(RECHECK_INTERVAL, headers.next()).await {
+                    Ok(Some(header)) => {
+                        if header?.height >= *target {
+                            return Ok(())
+                        }
+                    }
+                    Ok(None) => {
+                        return Err(io::Error::new(
+                            ErrorKind::Other,
+                            "The subscription to the block headers has ended",
+                        ))
+                    }
+                    Err(_) => {}
+                }
+            }
+        };
+
+        tokio::time::timeout(timeout, wait).await.map_err(|_| {
+            io::Error::new(
+                ErrorKind::TimedOut,
+                format!(
+                    "The block at height {target} was not imported within {timeout:?}"
+                ),
+            )
+        })?
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -801,6 +2235,59 @@ impl FuelClient {
         Ok(coins_per_asset)
     }
 
+    /// Selects the `owner`'s coins that cover the targets of the `query`. The `owner`
+    /// can be the root of the predicate, then the coins are converted into the inputs
+    /// with [`types::CoinInput::into_predicate_input`].
+    ///
+    /// Returns the [`types::ResourcesExhausted`] error if the coins are
+    /// not enough or the number of the inputs exceeds the limit.
+    pub async fn coin_inputs_to_spend(
+        &self,
+        owner: &Address,
+        query: types::SpendQuery,
+    ) -> io::Result<Vec<types::CoinInput>> {
+        let spend_query = query
+            .targets
+            .into_iter()
+            .map(|(asset_id, amount)| (asset_id, amount, query.max_inputs))
+            .collect();
+        // The node reports the exhausted resources with the code that the response
+        // decoding turns into the `ResourcesExhausted` error.
+        let coins_per_asset = self.coins_to_spend(owner, spend_query, None).await?;
+
+        let inputs = coins_per_asset
+            .into_iter()
+            .flatten()
+            .filter_map(|coin| match coin {
+                types::CoinType::Coin(coin) => Some(types::CoinInput::Coin(coin)),
+                types::CoinType::MessageCoin(message) => {
+                    Some(types::CoinInput::MessageCoin(message))
+                }
+                types::CoinType::Unknown => None,
+            })
+            .collect();
+        Ok(inputs)
+    }
+
+    /// Retrieve the number of the `owner`'s coins of the `asset_id` selected
+    /// by the node to cover the `amount`
+    pub async fn inputs_required(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u64,
+    ) -> io::Result<u32> {
+        let query = schema::coins::InputsRequiredQuery::build(InputsRequiredArgs {
+            owner: (*owner).into(),
+            asset_id: (*asset_id).into(),
+            amount: amount.into(),
+        });
+
+        let inputs_required = self.query(query).await?.inputs_required;
+
+        Ok(inputs_required.into())
+    }
+
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
@@ -809,6 +2296,34 @@ impl FuelClient {
         Ok(contract)
     }
 
+    /// Returns `true` if the code root of the deployed contract matches the root of
+    /// the `expected_bytecode`. Only the root is fetched, so it is cheaper than
+    /// downloading the code of large contracts. Returns `false` if the contract
+    /// doesn't exist.
+    pub async fn verify_contract_code(
+        &self,
+        contract_id: &ContractId,
+        expected_bytecode: &[u8],
+    ) -> io::Result<bool> {
+        let query = schema::contract::ContractCodeRootQuery::build(ContractByIdArgs {
+            id: (*contract_id).into(),
+        });
+        let expected_root = Contract::from(expected_bytecode.to_vec()).root();
+        let matches = self
+            .query(query)
+            .await?
+            .contract
+            .map(|contract| Bytes32::from(contract.code_root) == expected_root)
+            .unwrap_or(false);
+        Ok(matches)
+    }
+
+    /// Returns the raw bytecode of the contract, if it exists.
+    pub async fn contract_code(&self, id: &ContractId) -> io::Result<Option<Vec<u8>>> {
+        let code = self.contract(id).await?.map(|contract| contract.bytecode);
+        Ok(code)
+    }
+
     pub async fn contract_balance(
         &self,
         id: &ContractId,
@@ -830,6 +2345,42 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Returns the balance of the asset of the contract at the end of each block
+    /// in the `range`, computed by the node from the indexed balance changes.
+    /// The heights above the latest block are skipped. The node rejects the ranges
+    /// of more than 10000 blocks.
+    pub async fn contract_balance_series(
+        &self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+        range: Range<BlockHeight>,
+    ) -> io::Result<Vec<(BlockHeight, u64)>> {
+        if range.start >= range.end {
+            return Ok(vec![])
+        }
+        let to_height = range.end.saturating_sub(1);
+
+        let query = schema::contract::ContractBalanceSeriesQuery::build(
+            ContractBalanceSeriesArgs {
+                contract: (*contract_id).into(),
+                asset: (*asset_id).into(),
+                from_height: (*range.start).into(),
+                to_height: to_height.into(),
+            },
+        );
+        let series = self
+            .query(query)
+            .await?
+            .contract_balance_series
+            .into_iter()
+            .map(|balance| {
+                let height: u32 = balance.height.into();
+                (height.into(), balance.amount.into())
+            })
+            .collect();
+        Ok(series)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
@@ -845,6 +2396,97 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Retrieve the balances requested by the `queries` as of the block at the `height`,
+    /// in the order of the `queries`.
+    ///
+    /// The balances of the contracts are resolved from the history of their changes,
+    /// so any committed height is supported. The balances of the addresses are only
+    /// known for the latest block, so they are read between two checks that the latest
+    /// block is at the `height`. The error is returned if it is not the latest block,
+    /// or if another block was committed between the checks.
+    pub async fn balances_at_height(
+        &self,
+        queries: &[types::BalanceQuery],
+        height: BlockHeight,
+    ) -> io::Result<Vec<u64>> {
+        let has_addresses = queries
+            .iter()
+            .any(|query| matches!(query, types::BalanceQuery::Address { .. }));
+        if has_addresses {
+            self.ensure_latest_block_height(height).await?;
+        }
+
+        let mut balances = Vec::with_capacity(queries.len());
+        for query in queries {
+            let balance = match query {
+                types::BalanceQuery::Address { owner, asset_id } => {
+                    self.balance(owner, Some(asset_id)).await?
+                }
+                types::BalanceQuery::Contract {
+                    contract_id,
+                    asset_id,
+                } => {
+                    self.contract_balance_at_height(contract_id, asset_id, height)
+                        .await?
+                }
+            };
+            balances.push(balance);
+        }
+
+        if has_addresses {
+            self.ensure_latest_block_height(height).await?;
+        }
+        Ok(balances)
+    }
+
+    /// Returns the balance of the asset of the contract as of `tip - finalization_depth`,
+    /// where the finalization depth is configured by the node. Unlike the latest balance,
+    /// it can't be reverted by a reorg shallower than the finalization depth.
+    pub async fn finalized_contract_balance(
+        &self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+    ) -> io::Result<u64> {
+        let finalization_depth = self.node_info().await?.finalization_depth;
+        let tip = self.chain_info().await?.latest_block_height;
+        let height = tip.saturating_sub(finalization_depth);
+        self.contract_balance_at_height(contract_id, asset_id, height.into())
+            .await
+    }
+
+    async fn contract_balance_at_height(
+        &self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+        height: BlockHeight,
+    ) -> io::Result<u64> {
+        let next_height = height.succ().unwrap_or(height);
+        self.contract_balance_series(contract_id, asset_id, height..next_height)
+            .await?
+            .first()
+            .map(|(_, amount)| *amount)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("The block at the height {height} is not committed"),
+                )
+            })
+    }
+
+    async fn ensure_latest_block_height(&self, height: BlockHeight) -> io::Result<()> {
+        let latest_height = self.chain_info().await?.latest_block_height;
+        if latest_height != *height {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "The balances of the addresses are only available for the latest \
+                    block at the height {latest_height}, but requested {height}"
+                ),
+            ))
+        }
+        Ok(())
+    }
+
     // Retrieve a page of balances by their owner
     pub async fn balances(
         &self,
@@ -872,6 +2514,126 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Retrieve a page of the nonzero balances of all contracts, ordered by the contract
+    /// and the asset. The node keeps only the latest state, so it rejects the `height`
+    /// other than the latest one.
+    pub async fn all_contract_balances(
+        &self,
+        height: Option<BlockHeight>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractBalance, String>> {
+        let query = schema::contract::AllContractBalancesQuery::build(
+            (height.map(Into::into), request).into(),
+        );
+
+        let balances = self.query(query).await?.all_contract_balances.into();
+
+        Ok(balances)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Streams the nonzero balances of all contracts at the `height` as the triples
+    /// of the contract, the asset and the amount. The balances are fetched page
+    /// by page, so the `height` should stay the latest one until the stream ends.
+    pub fn export_contract_balances(
+        &self,
+        height: BlockHeight,
+    ) -> impl futures::Stream<Item = io::Result<(ContractId, AssetId, u64)>> + '_ {
+        use futures::TryStreamExt;
+        const PAGE_SIZE: i32 = 100;
+
+        // `None` means that the last page was already fetched.
+        futures::stream::try_unfold(Some(None::<String>), move |cursor| async move {
+            let cursor = match cursor {
+                Some(cursor) => cursor,
+                None => return Ok(None),
+            };
+            let page = self
+                .all_contract_balances(
+                    Some(height),
+                    PaginationRequest {
+                        cursor,
+                        results: PAGE_SIZE,
+                        direction: PageDirection::Forward,
+                    },
+                )
+                .await?;
+            let next = page.has_next_page.then_some(page.cursor);
+            let balances: Vec<_> = page
+                .results
+                .into_iter()
+                .map(|balance| Ok((balance.contract, balance.asset_id, balance.amount)))
+                .collect();
+
+            Ok::<_, io::Error>(Some((futures::stream::iter(balances), next)))
+        })
+        .try_flatten()
+    }
+
+    /// Returns the value of the storage slot at the `key` of the `contract`, or `None`
+    /// if the slot is not set. The node keeps only the latest state, so it rejects
+    /// the `height` other than the latest one.
+    pub async fn contract_storage_slot(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+        height: Option<BlockHeight>,
+    ) -> io::Result<Option<Bytes32>> {
+        let query =
+            schema::contract::ContractStorageSlotQuery::build(ContractStorageSlotArgs {
+                contract: (*contract).into(),
+                key: (*key).into(),
+                height: height.map(|height| (*height).into()),
+            });
+
+        let value = self
+            .query(query)
+            .await?
+            .contract_storage_slot
+            .map(Into::into);
+
+        Ok(value)
+    }
+
+    /// Returns the storage slots of the `contract` ordered by the key.
+    pub async fn contract_storage_slots(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractStorageSlot, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let query = schema::contract::ContractStorageSlotsQuery::build(
+            (contract_id, request).into(),
+        );
+
+        let slots = self.query(query).await?.contract_storage_slots.into();
+
+        Ok(slots)
+    }
+
+    /// Returns the ids of the deployed fee collection contracts withdrawing to
+    /// the `recipient`, including all instances of the same factory.
+    pub async fn fee_collection_contracts(
+        &self,
+        recipient: &Address,
+    ) -> io::Result<Vec<ContractId>> {
+        let query = schema::contract::FeeCollectionContractsQuery::build(
+            FeeCollectionContractsArgs {
+                recipient: (*recipient).into(),
+            },
+        );
+
+        let contracts = self
+            .query(query)
+            .await?
+            .fee_collection_contracts
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(contracts)
+    }
+
     pub async fn messages(
         &self,
         owner: Option<&Address>,
@@ -1,17 +1,27 @@
-use crate::client::schema::{
-    schema,
-    BlockId,
-    ConnectionArgs,
-    PageInfo,
-    Signature,
-    Tai64Timestamp,
-    U32,
-    U64,
+use crate::client::{
+    schema::{
+        schema,
+        AssetId,
+        BlockId,
+        ConnectionArgs,
+        ContractId,
+        HexString,
+        PageInfo,
+        Signature,
+        Tai64Timestamp,
+        U32,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
 };
 use fuel_core_types::fuel_crypto;
 
 use super::{
-    tx::TransactionIdFragment,
+    tx::{
+        transparent_receipt::Receipt,
+        TransactionIdFragment,
+    },
     Bytes32,
 };
 
@@ -58,6 +68,149 @@ pub struct BlocksQuery {
     pub blocks: BlockConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksByTimeRangeArgs {
+    /// The inclusive lower bound of the block timestamp
+    pub from_time: Tai64Timestamp,
+    /// The inclusive upper bound of the block timestamp
+    pub to_time: Tai64Timestamp,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n blocks in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n blocks in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Tai64Timestamp, Tai64Timestamp, PaginationRequest<String>)>
+    for BlocksByTimeRangeArgs
+{
+    fn from(r: (Tai64Timestamp, Tai64Timestamp, PaginationRequest<String>)) -> Self {
+        match r.2.direction {
+            PageDirection::Forward => BlocksByTimeRangeArgs {
+                from_time: r.0,
+                to_time: r.1,
+                after: r.2.cursor,
+                before: None,
+                first: Some(r.2.results),
+                last: None,
+            },
+            PageDirection::Backward => BlocksByTimeRangeArgs {
+                from_time: r.0,
+                to_time: r.1,
+                after: None,
+                before: r.2.cursor,
+                first: None,
+                last: Some(r.2.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlocksByTimeRangeArgs"
+)]
+pub struct BlocksByTimeRangeQuery {
+    #[arguments(fromTime: $from_time, toTime: $to_time, after: $after, before: $before, first: $first, last: $last)]
+    pub blocks_by_time_range: BlockConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinbaseArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinbaseArgs"
+)]
+pub struct CoinbaseQuery {
+    #[arguments(height: $height)]
+    pub coinbase: Option<Coinbase>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct PendingCoinbaseQuery {
+    pub pending_coinbase: Coinbase,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AccruedCoinbaseArgs {
+    pub contract_id: ContractId,
+    pub up_to: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AccruedCoinbaseArgs"
+)]
+pub struct AccruedCoinbaseQuery {
+    #[arguments(contractId: $contract_id, upTo: $up_to)]
+    pub accrued_coinbase: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Coinbase {
+    pub recipient: Option<ContractId>,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksCreditingArgs {
+    pub recipient: ContractId,
+    pub from: U32,
+    pub to: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlocksCreditingArgs"
+)]
+pub struct BlocksCreditingQuery {
+    #[arguments(recipient: $recipient, from: $from, to: $to)]
+    pub blocks_crediting: Vec<CoinbaseCredit>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct RecentCoinbaseCreditsArgs {
+    pub recipient: ContractId,
+    pub limit: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "RecentCoinbaseCreditsArgs"
+)]
+pub struct RecentCoinbaseCreditsQuery {
+    #[arguments(recipient: $recipient, limit: $limit)]
+    pub recent_coinbase_credits: Vec<CoinbaseCredit>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinbaseCredit {
+    pub block_height: U32,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BlockConnection {
@@ -81,6 +234,29 @@ pub struct Block {
     pub transactions: Vec<TransactionIdFragment>,
 }
 
+/// The [`Block`] together with its coinbase. The coinbase requires a lookup of the mint
+/// transaction, so it is only requested by the queries that need it.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
+pub struct BlockWithCoinbase {
+    pub id: BlockId,
+    pub header: Header,
+    pub consensus: Consensus,
+    pub transactions: Vec<TransactionIdFragment>,
+    pub coinbase: Option<Coinbase>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockByHeightArgs"
+)]
+pub struct BlockWithCoinbaseByHeightQuery {
+    #[arguments(height: $height)]
+    pub block: Option<BlockWithCoinbase>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
 pub struct BlockIdFragment {
@@ -104,6 +280,89 @@ pub struct BlockMutation {
     pub produce_blocks: U32,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProduceBlockWithTxsArgs {
+    pub txs: Vec<HexString>,
+    pub start_timestamp: Option<Tai64Timestamp>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "ProduceBlockWithTxsArgs",
+    graphql_type = "Mutation"
+)]
+pub struct ProduceBlockWithTxsMutation {
+    #[arguments(txs: $txs, startTimestamp: $start_timestamp)]
+    pub produce_block_with_txs: U32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProduceBlockIfCoinbaseOverArgs {
+    pub threshold: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "ProduceBlockIfCoinbaseOverArgs",
+    graphql_type = "Mutation"
+)]
+pub struct ProduceBlockIfCoinbaseOverMutation {
+    #[arguments(threshold: $threshold)]
+    pub produce_block_if_coinbase_over: Option<U32>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReplayBlockArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "ReplayBlockArgs",
+    graphql_type = "Mutation"
+)]
+pub struct ReplayBlockMutation {
+    #[arguments(height: $height)]
+    pub replay_block: Vec<Vec<Receipt>>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SetBlockGasLimitArgs {
+    pub limit: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "SetBlockGasLimitArgs",
+    graphql_type = "Mutation"
+)]
+pub struct SetBlockGasLimitMutation {
+    #[arguments(limit: $limit)]
+    pub set_block_gas_limit: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CreditCoinbaseArgs {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "CreditCoinbaseArgs",
+    graphql_type = "Mutation"
+)]
+pub struct CreditCoinbaseMutation {
+    #[arguments(contractId: $contract_id, assetId: $asset_id, amount: $amount)]
+    pub credit_coinbase: U32,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Header {
@@ -119,6 +378,12 @@ pub struct Header {
     pub application_hash: Bytes32,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct NewBlockHeadersSubscription {
+    pub new_block_headers: Header,
+}
+
 #[derive(cynic::InlineFragments, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub enum Consensus {
@@ -146,16 +411,30 @@ pub struct PoAConsensus {
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
-        let message = self.header.id.clone().into_message();
-        match &self.consensus {
-            Consensus::Genesis(_) => Some(Default::default()),
-            Consensus::PoAConsensus(poa) => {
-                let signature = poa.signature.clone().into_signature();
-                let producer_pub_key = signature.recover(&message);
-                producer_pub_key.ok()
-            }
-            Consensus::Unknown => None,
+        block_producer(&self.header, &self.consensus)
+    }
+}
+
+impl BlockWithCoinbase {
+    /// Returns the block producer public key, if any.
+    pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
+        block_producer(&self.header, &self.consensus)
+    }
+}
+
+fn block_producer(
+    header: &Header,
+    consensus: &Consensus,
+) -> Option<fuel_crypto::PublicKey> {
+    let message = header.id.clone().into_message();
+    match consensus {
+        Consensus::Genesis(_) => Some(Default::default()),
+        Consensus::PoAConsensus(poa) => {
+            let signature = poa.signature.clone().into_signature();
+            let producer_pub_key = signature.recover(&message);
+            producer_pub_key.ok()
         }
+        Consensus::Unknown => None,
     }
 }
 
@@ -181,6 +460,15 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn block_with_coinbase_by_height_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlockWithCoinbaseByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(0)),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
@@ -191,6 +479,59 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn produce_block_with_txs_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = ProduceBlockWithTxsMutation::build(ProduceBlockWithTxsArgs {
+            txs: vec![],
+            start_timestamp: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn produce_block_if_coinbase_over_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation =
+            ProduceBlockIfCoinbaseOverMutation::build(ProduceBlockIfCoinbaseOverArgs {
+                threshold: U64(0),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn replay_block_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = ReplayBlockMutation::build(ReplayBlockArgs { height: U32(0) });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn set_block_gas_limit_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation =
+            SetBlockGasLimitMutation::build(SetBlockGasLimitArgs { limit: U64(0) });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn credit_coinbase_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = CreditCoinbaseMutation::build(CreditCoinbaseArgs {
+            contract_id: ContractId::default(),
+            asset_id: AssetId::default(),
+            amount: U64(0),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn new_block_headers_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = NewBlockHeadersSubscription::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn blocks_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -202,4 +543,63 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn blocks_by_time_range_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlocksByTimeRangeQuery::build(BlocksByTimeRangeArgs {
+            from_time: Tai64Timestamp(tai64::Tai64(0)),
+            to_time: Tai64Timestamp(tai64::Tai64(0)),
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn coinbase_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = CoinbaseQuery::build(CoinbaseArgs { height: U32(0) });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn pending_coinbase_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = PendingCoinbaseQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn accrued_coinbase_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AccruedCoinbaseQuery::build(AccruedCoinbaseArgs {
+            contract_id: ContractId::default(),
+            up_to: U32(0),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn blocks_crediting_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlocksCreditingQuery::build(BlocksCreditingArgs {
+            recipient: ContractId::default(),
+            from: U32(0),
+            to: U32(0),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn recent_coinbase_credits_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = RecentCoinbaseCreditsQuery::build(RecentCoinbaseCreditsArgs {
+            recipient: ContractId::default(),
+            limit: U32(0),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
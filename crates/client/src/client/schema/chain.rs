@@ -2,6 +2,7 @@ use crate::client::schema::{
     block::Block,
     schema,
     AssetId,
+    U128,
     U32,
     U64,
     U8,
@@ -325,6 +326,88 @@ pub struct ChainInfo {
     pub peer_count: i32,
     pub latest_block: Block,
     pub consensus_parameters: ConsensusParameters,
+    pub block_gas_limit: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetInfoArgs {
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetInfoArgs"
+)]
+pub struct AssetInfoQuery {
+    #[arguments(assetId: $asset_id)]
+    pub asset_info: Option<AssetInfo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetInfo {
+    pub asset_id: AssetId,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: U8,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetSupplyHistoryArgs {
+    pub asset_id: AssetId,
+    pub from_height: U32,
+    pub to_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetSupplyHistoryArgs"
+)]
+pub struct AssetSupplyHistoryQuery {
+    #[arguments(assetId: $asset_id, fromHeight: $from_height, toHeight: $to_height)]
+    pub asset_supply_history: Vec<AssetSupplyDelta>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetSupplyDelta {
+    pub height: U32,
+    pub minted: U64,
+    pub burned: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TotalCoinbaseMintedArgs {
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TotalCoinbaseMintedArgs"
+)]
+pub struct TotalCoinbaseMintedQuery {
+    #[arguments(assetId: $asset_id)]
+    pub total_coinbase_minted: U128,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct ChainConfigQuery {
+    pub chain_config: ChainConfig,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainConfig {
+    pub chain_name: String,
+    pub block_gas_limit: U64,
+    pub consensus_parameters: ConsensusParameters,
 }
 
 #[cfg(test)]
@@ -337,4 +420,40 @@ mod tests {
         let operation = ChainQuery::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn asset_info_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetInfoQuery::build(AssetInfoArgs {
+            asset_id: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn asset_supply_history_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetSupplyHistoryQuery::build(AssetSupplyHistoryArgs {
+            asset_id: AssetId::default(),
+            from_height: 0.into(),
+            to_height: 10.into(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn total_coinbase_minted_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = TotalCoinbaseMintedQuery::build(TotalCoinbaseMintedArgs {
+            asset_id: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn chain_config_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = ChainConfigQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
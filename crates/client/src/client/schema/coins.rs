@@ -212,6 +212,27 @@ pub struct CoinsToSpendQuery {
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct InputsRequiredArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The asset of the coins.
+    pub asset_id: AssetId,
+    /// The amount that the coins should cover.
+    pub amount: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "InputsRequiredArgs"
+)]
+pub struct InputsRequiredQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, amount: $amount)]
+    pub inputs_required: U32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +261,15 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn inputs_required_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = InputsRequiredQuery::build(InputsRequiredArgs {
+            owner: Address::default(),
+            asset_id: AssetId::default(),
+            amount: U64(0),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
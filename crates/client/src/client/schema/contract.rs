@@ -1,11 +1,14 @@
 use crate::client::{
     schema::{
         schema,
+        Address,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        U32,
         U64,
     },
     PageDirection,
@@ -28,6 +31,23 @@ pub struct ContractByIdQuery {
     pub contract: Option<Contract>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractByIdArgs"
+)]
+pub struct ContractCodeRootQuery {
+    #[arguments(id: $id)]
+    pub contract: Option<ContractCodeRootFragment>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Contract")]
+pub struct ContractCodeRootFragment {
+    pub code_root: Bytes32,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct ContractBalance {
@@ -135,6 +155,209 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
     }
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AllContractBalancesConnectionArgs {
+    pub height: Option<U32>,
+    /// Skip until the contract and the asset (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the contract and the asset (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n balances in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n balances in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AllContractBalancesConnectionArgs"
+)]
+pub struct AllContractBalancesQuery {
+    #[arguments(height: $height, after: $after, before: $before, first: $first, last: $last)]
+    pub all_contract_balances: ContractBalanceConnection,
+}
+
+impl From<(Option<U32>, PaginationRequest<String>)>
+    for AllContractBalancesConnectionArgs
+{
+    fn from(r: (Option<U32>, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => AllContractBalancesConnectionArgs {
+                height: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => AllContractBalancesConnectionArgs {
+                height: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotArgs {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotArgs"
+)]
+pub struct ContractStorageSlotQuery {
+    #[arguments(contract: $contract, key: $key, height: $height)]
+    pub contract_storage_slot: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlot {
+    pub key: Bytes32,
+    pub value: Bytes32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotsConnectionArgs {
+    pub contract: ContractId,
+    /// Skip until the key (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the key (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n storage slots in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n storage slots in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotEdge {
+    pub cursor: String,
+    pub node: ContractStorageSlot,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotConnection {
+    pub edges: Vec<ContractStorageSlotEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotsConnectionArgs"
+)]
+pub struct ContractStorageSlotsQuery {
+    #[arguments(contract: $contract, after: $after, before: $before, first: $first, last: $last)]
+    pub contract_storage_slots: ContractStorageSlotConnection,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for ContractStorageSlotsConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => ContractStorageSlotsConnectionArgs {
+                contract: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractStorageSlotsConnectionArgs {
+                contract: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceChange {
+    pub block_height: U32,
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub old: U64,
+    pub new: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractBalanceChangesArgs {
+    pub contract_id: Option<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "ContractBalanceChangesArgs"
+)]
+pub struct ContractBalanceChangesSubscription {
+    #[arguments(contractId: $contract_id)]
+    pub contract_balance_changes: ContractBalanceChange,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractBalanceSeriesArgs {
+    pub contract: ContractId,
+    pub asset: AssetId,
+    pub from_height: U32,
+    pub to_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractBalanceSeriesArgs"
+)]
+pub struct ContractBalanceSeriesQuery {
+    #[arguments(contract: $contract, asset: $asset, fromHeight: $from_height, toHeight: $to_height)]
+    pub contract_balance_series: Vec<ContractBalanceAtHeight>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceAtHeight {
+    pub height: U32,
+    pub amount: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct FeeCollectionContractsArgs {
+    pub recipient: Address,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "FeeCollectionContractsArgs"
+)]
+pub struct FeeCollectionContractsQuery {
+    #[arguments(recipient: $recipient)]
+    pub fee_collection_contracts: Vec<ContractId>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +370,89 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_code_root_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractCodeRootQuery::build(ContractByIdArgs {
+            id: ContractId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn all_contract_balances_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AllContractBalancesQuery::build(
+            (
+                Some(U32(1)),
+                PaginationRequest {
+                    cursor: None,
+                    results: 10,
+                    direction: PageDirection::Forward,
+                },
+            )
+                .into(),
+        );
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_storage_slot_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageSlotQuery::build(ContractStorageSlotArgs {
+            contract: ContractId::default(),
+            key: Bytes32::default(),
+            height: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_storage_slots_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageSlotsQuery::build(
+            (
+                ContractId::default(),
+                PaginationRequest {
+                    cursor: None,
+                    results: 10,
+                    direction: PageDirection::Forward,
+                },
+            )
+                .into(),
+        );
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_balance_changes_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation =
+            ContractBalanceChangesSubscription::build(ContractBalanceChangesArgs {
+                contract_id: Some(ContractId::default()),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_balance_series_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractBalanceSeriesQuery::build(ContractBalanceSeriesArgs {
+            contract: ContractId::default(),
+            asset: AssetId::default(),
+            from_height: 0.into(),
+            to_height: 10.into(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn fee_collection_contracts_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = FeeCollectionContractsQuery::build(FeeCollectionContractsArgs {
+            recipient: Address::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
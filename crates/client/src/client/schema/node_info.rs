@@ -1,5 +1,8 @@
 use crate::client::schema::{
     schema,
+    ContractId,
+    Tai64Timestamp,
+    U32,
     U64,
 };
 
@@ -12,6 +15,8 @@ pub struct NodeInfo {
     pub max_tx: U64,
     pub max_depth: U64,
     pub node_version: String,
+    pub finalization_depth: U32,
+    pub max_dry_run_gas: Option<U64>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -20,6 +25,102 @@ pub struct QueryNodeInfo {
     pub node_info: NodeInfo,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NodeHealth {
+    pub up: bool,
+    pub txpool_accepting: bool,
+    pub producer_running: bool,
+    pub p2p_connected_peers: U32,
+    pub db_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryNodeHealth {
+    pub node_health: NodeHealth,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolServiceConfig {
+    pub max_tx: U64,
+    pub max_depth: U64,
+    pub min_gas_price: U64,
+    pub utxo_validation: bool,
+    pub metrics: bool,
+    pub transaction_ttl: U64,
+    pub number_of_active_subscription: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockProducerServiceConfig {
+    pub utxo_validation: bool,
+    pub coinbase_recipient: Option<ContractId>,
+    pub metrics: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RelayerServiceConfig {
+    pub da_deploy_height: U64,
+    pub eth_v2_listening_contracts: Vec<String>,
+    pub log_page_size: U64,
+    pub sync_minimum_duration: U64,
+    pub syncing_call_frequency: U64,
+    pub syncing_log_frequency: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ServiceConfig {
+    pub txpool: TxPoolServiceConfig,
+    pub block_producer: BlockProducerServiceConfig,
+    pub relayer: Option<RelayerServiceConfig>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryServiceConfig {
+    pub service_config: ServiceConfig,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockProductionConfig {
+    pub mode: BlockProductionMode,
+    pub block_time: Option<U64>,
+}
+
+#[derive(cynic::Enum, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum BlockProductionMode {
+    Instant,
+    Interval,
+    Manual,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryBlockProductionConfig {
+    pub block_production_config: BlockProductionConfig,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ProducerMetrics {
+    pub blocks_produced: U64,
+    pub average_coinbase: U64,
+    pub last_production_time: Option<Tai64Timestamp>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryProducerMetrics {
+    pub producer_metrics: Option<ProducerMetrics>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,4 +131,32 @@ mod tests {
         let operation = QueryNodeInfo::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn node_health_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryNodeHealth::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn service_config_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryServiceConfig::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn block_production_config_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryBlockProductionConfig::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn producer_metrics_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryProducerMetrics::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
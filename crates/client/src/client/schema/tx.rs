@@ -1,15 +1,34 @@
-use super::block::BlockIdFragment;
+use super::{
+    block::{
+        BlockIdFragment,
+        Coinbase,
+        Header,
+    },
+    coins::Coin,
+    message::MerkleProof,
+};
 use crate::client::{
     schema::{
         schema,
-        tx::transparent_receipt::Receipt,
+        tx::{
+            transparent_receipt::{
+                Receipt,
+                ReceiptType,
+            },
+            transparent_tx::VariableOutput,
+        },
         Address,
+        AssetId,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        UtxoId,
+        U32,
+        U64,
     },
     types::TransactionResponse,
     PageDirection,
@@ -161,6 +180,7 @@ pub enum TransactionStatus {
     SuccessStatus(SuccessStatus),
     SqueezedOutStatus(SqueezedOutStatus),
     FailureStatus(FailureStatus),
+    ExpiredStatus(ExpiredStatus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -177,6 +197,18 @@ pub struct SuccessStatus {
     pub block: BlockIdFragment,
     pub time: Tai64Timestamp,
     pub program_state: Option<ProgramState>,
+    pub gas_used: U64,
+    pub total_fee: U64,
+    pub script_result: ScriptResult,
+    pub variable_outputs: Vec<VariableOutput>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ScriptResult {
+    pub return_value: Option<U64>,
+    pub reverted: bool,
+    pub transferred: bool,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -186,12 +218,28 @@ pub struct FailureStatus {
     pub time: Tai64Timestamp,
     pub reason: String,
     pub program_state: Option<ProgramState>,
+    pub gas_used: U64,
+    pub total_fee: U64,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct SqueezedOutStatus {
     pub reason: String,
+    pub kind: SqueezedOutKind,
+}
+
+#[derive(cynic::Enum, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum SqueezedOutKind {
+    Evicted,
+    Removed,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ExpiredStatus {
+    pub expire_at: U32,
 }
 
 #[derive(cynic::QueryVariables, Debug)]
@@ -241,6 +289,133 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionDependenciesQuery {
+    #[arguments(id: $id)]
+    pub transaction_dependencies: Vec<OpaqueTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionSourceQuery {
+    #[arguments(id: $id)]
+    pub transaction_source: Option<String>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PendingSpendersOfArgs {
+    pub utxo_id: UtxoId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "PendingSpendersOfArgs"
+)]
+pub struct PendingSpendersOfQuery {
+    #[arguments(utxoId: $utxo_id)]
+    pub pending_spenders_of: Vec<OpaqueTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolStats {
+    pub tx_count: U64,
+    pub total_gas: U64,
+    pub estimated_coinbase: Option<Coinbase>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TxPoolStatsQuery {
+    pub tx_pool_stats: TxPoolStats,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionProof {
+    pub block_header: Header,
+    pub proof: MerkleProof,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionInclusionProofQuery {
+    #[arguments(id: $id)]
+    pub transaction_inclusion_proof: Option<TransactionProof>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinOutputType {
+    Coin,
+    Change,
+    Variable,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CreatedUtxo {
+    pub utxo_id: UtxoId,
+    pub output_type: CoinOutputType,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionCreatedUtxosQuery {
+    #[arguments(id: $id)]
+    pub transaction_created_utxos: Option<Vec<CreatedUtxo>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionUnspentCoinsQuery {
+    #[arguments(id: $id)]
+    pub transaction_unspent_coins: Option<Vec<Coin>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Transaction", schema_path = "./assets/schema.sdl")]
+pub struct TransactionFeePayer {
+    pub fee_payer: Option<Address>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionFeePayerQuery {
+    #[arguments(id: $id)]
+    pub transaction: Option<TransactionFeePayer>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -298,6 +473,49 @@ pub struct Submit {
     pub submit: TransactionIdFragment,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SubmitWithIdempotencyArgs {
+    pub tx: HexString,
+    pub idempotency_key: super::Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DuplicateSubmission {
+    pub original_transaction_id: TransactionId,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum IdempotentSubmission {
+    Transaction(TransactionIdFragment),
+    DuplicateSubmission(DuplicateSubmission),
+    #[cynic(fallback)]
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SubmitWithIdempotencyArgs"
+)]
+pub struct SubmitWithIdempotencyMutation {
+    #[arguments(tx: $tx, idempotencyKey: $idempotency_key)]
+    pub submit_with_idempotency: IdempotentSubmission,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxIdArgs"
+)]
+pub struct RemoveFromTxpoolMutation {
+    #[arguments(id: $id)]
+    pub remove_from_txpool: bool,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -309,6 +527,40 @@ pub struct SubmitAndAwaitSubscription {
     pub submit_and_await: TransactionStatus,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SubmitAndAwaitWithExpiryArgs {
+    pub tx: HexString,
+    pub expire_at: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "SubmitAndAwaitWithExpiryArgs"
+)]
+pub struct SubmitAndAwaitWithExpirySubscription {
+    #[arguments(tx: $tx, expireAt: $expire_at)]
+    pub submit_and_await_with_expiry: TransactionStatus,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsWithReceiptArgs {
+    pub receipt_type: Option<ReceiptType>,
+    pub contract_id: Option<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "TransactionsWithReceiptArgs"
+)]
+pub struct TransactionsWithReceiptSubscription {
+    #[arguments(receiptType: $receipt_type, contractId: $contract_id)]
+    pub transactions_with_receipt: OpaqueTransaction,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
 pub struct AllReceipts {
@@ -339,6 +591,89 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transactions_with_receipt_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation =
+            TransactionsWithReceiptSubscription::build(TransactionsWithReceiptArgs {
+                receipt_type: Some(ReceiptType::TransferOut),
+                contract_id: Some(ContractId::default()),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn submit_and_await_with_expiry_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation =
+            SubmitAndAwaitWithExpirySubscription::build(SubmitAndAwaitWithExpiryArgs {
+                tx: HexString(Bytes(vec![])),
+                expire_at: 10.into(),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transaction_inclusion_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionInclusionProofQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transaction_created_utxos_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionCreatedUtxosQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transaction_unspent_coins_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionUnspentCoinsQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transaction_source_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionSourceQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn pending_spenders_of_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = PendingSpendersOfQuery::build(PendingSpendersOfArgs {
+            utxo_id: UtxoId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn tx_pool_stats_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolStatsQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transaction_fee_payer_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionFeePayerQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn transactions_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -385,4 +720,24 @@ pub mod tests {
         });
         insta::assert_snapshot!(query.query)
     }
+
+    #[test]
+    fn submit_with_idempotency_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = SubmitWithIdempotencyMutation::build(SubmitWithIdempotencyArgs {
+            tx: HexString(Bytes(tx.to_bytes())),
+            idempotency_key: Default::default(),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn remove_from_txpool_gql_output() {
+        use cynic::MutationBuilder;
+        let query = RemoveFromTxpoolMutation::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(query.query)
+    }
 }
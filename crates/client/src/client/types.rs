@@ -1,49 +1,135 @@
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain_info;
+pub mod coinbase;
 pub mod coins;
 pub mod contract;
+pub mod deployment;
+pub mod dry_run;
+pub mod fee;
+pub mod funding;
 pub mod gas_costs;
+pub mod lint;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod script_data;
+pub mod signer;
+pub mod txpool;
 
-pub use balance::Balance;
+pub use asset::{
+    AssetInfo,
+    AssetSupplyDelta,
+};
+pub use balance::{
+    Balance,
+    BalanceQuery,
+};
 pub use block::{
     Block,
+    BlockSummary,
+    BlockWithCoinbase,
     Consensus,
+    GenesisInfo,
+};
+pub use chain_info::{
+    ChainConfig,
+    ChainInfo,
+};
+pub use coinbase::{
+    Coinbase,
+    CoinbaseCredit,
 };
-pub use chain_info::ChainInfo;
 pub use coins::{
     Coin,
+    CoinInput,
+    CoinOutputType,
     CoinType,
+    CreatedUtxo,
     MessageCoin,
+    ResourcesExhausted,
+    SpendQuery,
 };
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractBalanceChange,
+    ContractStorageSlot,
+};
+pub use deployment::{
+    deterministic_salt,
+    CreateWithDeterministicSalt,
+};
+pub use dry_run::DryRunDiagnostic;
+pub use fee::{
+    FeeBreakdown,
+    TransactionCost,
+};
+pub use funding::{
+    CoinInfo,
+    Consolidate,
+    FundWith,
+    Funding,
+    InsufficientFunds,
 };
 pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
+pub use lint::{
+    Lint,
+    LintKind,
+};
 pub use merkle_proof::MerkleProof;
 pub use message::{
     Message,
     MessageProof,
 };
-pub use node_info::NodeInfo;
+pub use node_info::{
+    BlockProducerServiceConfig,
+    NodeHealth,
+    NodeInfo,
+    ProducerMetrics,
+    ProductionConfig,
+    RelayerServiceConfig,
+    ServiceConfig,
+    TxPoolServiceConfig,
+};
+pub use script_data::ScriptDataBuilder;
+pub use signer::{
+    FinalizeSigned,
+    SecretKeySigner,
+    Signer,
+};
+pub use txpool::{
+    ResubmitPolicy,
+    SqueezedOutKind,
+    TxpoolStats,
+};
 
 use crate::client::schema::{
     tx::{
+        transparent_receipt::ReceiptType as SchemaReceiptType,
+        IdempotentSubmission as SchemaIdempotentSubmission,
         OpaqueTransaction,
+        ScriptResult as SchemaScriptResult,
+        TransactionProof as SchemaTransactionProof,
         TransactionStatus as SchemaTxStatus,
     },
     ConversionError,
 };
 use fuel_core_types::{
-    fuel_tx::Transaction,
-    fuel_types::canonical::Deserialize,
+    fuel_tx::{
+        Output,
+        Receipt,
+        Transaction,
+    },
+    fuel_types::{
+        canonical::Deserialize,
+        BlockHeight,
+        Word,
+    },
     fuel_vm::ProgramState,
 };
 use tai64::Tai64;
@@ -81,6 +167,51 @@ pub struct TransactionResponse {
     pub status: TransactionStatus,
 }
 
+/// The filter of the receipts produced by the transactions. The unset fields
+/// match any receipt.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReceiptFilter {
+    /// The type of the receipt.
+    pub receipt_type: Option<ReceiptType>,
+    /// The contract emitting the receipt or targeted by it.
+    pub contract_id: Option<primitives::ContractId>,
+}
+
+/// The type of the receipt matched by the [`ReceiptFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiptType {
+    Call,
+    Return,
+    ReturnData,
+    Panic,
+    Revert,
+    Log,
+    LogData,
+    Transfer,
+    TransferOut,
+    ScriptResult,
+    MessageOut,
+    Mint,
+    Burn,
+}
+
+/// The transaction with its status and all receipts.
+#[derive(Debug, Clone)]
+pub struct TransactionWithReceipts {
+    pub transaction: Transaction,
+    pub status: TransactionStatus,
+    pub receipts: Vec<Receipt>,
+}
+
+/// The proof that the transaction is included in the block.
+#[derive(Debug)]
+pub struct TransactionProof {
+    /// The header of the block that contains the transaction.
+    pub block_header: block::Header,
+    /// The proof of the transaction against the `transactions_root` of the block header.
+    pub proof: MerkleProof,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum TransactionStatus {
     Submitted {
@@ -90,16 +221,69 @@ pub enum TransactionStatus {
         block_id: String,
         time: Tai64,
         program_state: Option<ProgramState>,
+        /// The gas spent by the execution of the script.
+        gas_used: u64,
+        /// The fee paid by the transaction after the refund of the unused gas.
+        total_fee: u64,
+        /// The variable outputs of the transaction with the values assigned
+        /// during the execution.
+        variable_outputs: Vec<Output>,
+        /// The outcome of the script derived from the receipts of the transaction.
+        script_result: ScriptResult,
     },
+    /// The transaction was removed from the `TxPool` without being included.
+    /// It was evicted from the full pool or replaced by the transaction paying
+    /// the higher gas price, removed because its dependency was invalidated
+    /// by the committed transaction, or removed on demand. The `kind` tells
+    /// them apart, while the `reason` is the human-readable message.
     SqueezedOut {
         reason: String,
+        kind: SqueezedOutKind,
     },
     Failure {
         block_id: String,
         time: Tai64,
         reason: String,
         program_state: Option<ProgramState>,
+        /// The gas spent by the execution of the script.
+        gas_used: u64,
+        /// The fee paid by the transaction after the refund of the unused gas.
+        total_fee: u64,
     },
+    /// The transaction was dropped from the txpool, because it wasn't included
+    /// in a block by the expiry height.
+    Expired {
+        /// The last height the transaction could be included at.
+        expire_at: BlockHeight,
+    },
+}
+
+/// The outcome of the script of the transaction derived from its receipts.
+///
+/// The fee collection contract returns early without the transfer if there are
+/// no fees to withdraw, so the `transferred` distinguishes the real withdrawal.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ScriptResult {
+    /// The value returned by the `RET` instruction of the script. `None` if the script
+    /// returned the data by the `RETD` instruction or there is no script.
+    pub return_value: Option<Word>,
+    /// `true` if the script or one of the contracts called by it reverted, or one of
+    /// the calls didn't end with the `RET` or `RETD` instruction of the called contract.
+    pub reverted: bool,
+    /// `true` if the script or one of the called contracts transferred any coins.
+    pub transferred: bool,
+}
+
+impl From<SchemaScriptResult> for ScriptResult {
+    fn from(result: SchemaScriptResult) -> Self {
+        Self {
+            return_value: result.return_value.map(Into::into),
+            reverted: result.reverted,
+            transferred: result.transferred,
+        }
+    }
 }
 
 impl TryFrom<SchemaTxStatus> for TransactionStatus {
@@ -114,16 +298,34 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
                 block_id: s.block.id.0.to_string(),
                 time: s.time.0,
                 program_state: s.program_state.map(TryInto::try_into).transpose()?,
+                gas_used: s.gas_used.into(),
+                total_fee: s.total_fee.into(),
+                variable_outputs: s
+                    .variable_outputs
+                    .into_iter()
+                    .map(|output| Output::Variable {
+                        to: output.to.into(),
+                        amount: output.amount.into(),
+                        asset_id: output.asset_id.into(),
+                    })
+                    .collect(),
+                script_result: s.script_result.into(),
             },
             SchemaTxStatus::FailureStatus(s) => TransactionStatus::Failure {
                 block_id: s.block.id.0.to_string(),
                 time: s.time.0,
                 reason: s.reason,
                 program_state: s.program_state.map(TryInto::try_into).transpose()?,
+                gas_used: s.gas_used.into(),
+                total_fee: s.total_fee.into(),
+            },
+            SchemaTxStatus::SqueezedOutStatus(s) => TransactionStatus::SqueezedOut {
+                reason: s.reason,
+                kind: s.kind.into(),
+            },
+            SchemaTxStatus::ExpiredStatus(s) => TransactionStatus::Expired {
+                expire_at: s.expire_at.into(),
             },
-            SchemaTxStatus::SqueezedOutStatus(s) => {
-                TransactionStatus::SqueezedOut { reason: s.reason }
-            }
             SchemaTxStatus::Unknown => {
                 return Err(Self::Error::UnknownVariant("SchemaTxStatus"))
             }
@@ -131,6 +333,26 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
     }
 }
 
+impl From<ReceiptType> for SchemaReceiptType {
+    fn from(value: ReceiptType) -> Self {
+        match value {
+            ReceiptType::Call => SchemaReceiptType::Call,
+            ReceiptType::Return => SchemaReceiptType::Return,
+            ReceiptType::ReturnData => SchemaReceiptType::ReturnData,
+            ReceiptType::Panic => SchemaReceiptType::Panic,
+            ReceiptType::Revert => SchemaReceiptType::Revert,
+            ReceiptType::Log => SchemaReceiptType::Log,
+            ReceiptType::LogData => SchemaReceiptType::LogData,
+            ReceiptType::Transfer => SchemaReceiptType::Transfer,
+            ReceiptType::TransferOut => SchemaReceiptType::TransferOut,
+            ReceiptType::ScriptResult => SchemaReceiptType::ScriptResult,
+            ReceiptType::MessageOut => SchemaReceiptType::MessageOut,
+            ReceiptType::Mint => SchemaReceiptType::Mint,
+            ReceiptType::Burn => SchemaReceiptType::Burn,
+        }
+    }
+}
+
 impl TryFrom<OpaqueTransaction> for TransactionResponse {
     type Error = ConversionError;
 
@@ -149,3 +371,66 @@ impl TryFrom<OpaqueTransaction> for TransactionResponse {
         })
     }
 }
+
+impl TryFrom<OpaqueTransaction> for TransactionWithReceipts {
+    type Error = ConversionError;
+
+    fn try_from(mut value: OpaqueTransaction) -> Result<Self, Self::Error> {
+        let receipts = value
+            .receipts
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+        let TransactionResponse {
+            transaction,
+            status,
+        } = value.try_into()?;
+
+        Ok(Self {
+            transaction,
+            status,
+            receipts,
+        })
+    }
+}
+
+impl From<SchemaTransactionProof> for TransactionProof {
+    fn from(value: SchemaTransactionProof) -> Self {
+        Self {
+            block_header: value.block_header.into(),
+            proof: value.proof.into(),
+        }
+    }
+}
+
+/// The result of the submission with the idempotency key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdempotentSubmission {
+    /// The transaction is inserted into the `TxPool`.
+    Submitted(primitives::TransactionId),
+    /// The transaction is rejected because the idempotency key was already used.
+    DuplicateSubmission {
+        /// The id of the transaction submitted with the idempotency key first.
+        original_tx_id: primitives::TransactionId,
+    },
+}
+
+impl TryFrom<SchemaIdempotentSubmission> for IdempotentSubmission {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaIdempotentSubmission) -> Result<Self, Self::Error> {
+        Ok(match value {
+            SchemaIdempotentSubmission::Transaction(tx) => Self::Submitted(tx.id.into()),
+            SchemaIdempotentSubmission::DuplicateSubmission(duplicate) => {
+                Self::DuplicateSubmission {
+                    original_tx_id: duplicate.original_transaction_id.into(),
+                }
+            }
+            SchemaIdempotentSubmission::Unknown => {
+                return Err(Self::Error::UnknownVariant("SchemaIdempotentSubmission"))
+            }
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use crate::client::{
+    schema,
+    types::primitives::AssetId,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetInfo {
+    pub asset_id: AssetId,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/// The amounts of the asset minted and burned within one block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetSupplyDelta {
+    pub height: u32,
+    pub minted: u64,
+    pub burned: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::chain::AssetInfo> for AssetInfo {
+    fn from(value: schema::chain::AssetInfo) -> Self {
+        AssetInfo {
+            asset_id: value.asset_id.into(),
+            name: value.name,
+            symbol: value.symbol,
+            decimals: value.decimals.into(),
+        }
+    }
+}
+
+impl From<schema::chain::AssetSupplyDelta> for AssetSupplyDelta {
+    fn from(value: schema::chain::AssetSupplyDelta) -> Self {
+        AssetSupplyDelta {
+            height: value.height.into(),
+            minted: value.minted.into(),
+            burned: value.burned.into(),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::client::{
     types::primitives::{
         Address,
         AssetId,
+        ContractId,
     },
     PaginatedResult,
 };
@@ -13,6 +14,19 @@ pub struct Balance {
     pub asset_id: AssetId,
 }
 
+/// The balance requested by the
+/// [`FuelClient::balances_at_height`](crate::client::FuelClient::balances_at_height).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceQuery {
+    /// The balance of the coins and the messages owned by the address.
+    Address { owner: Address, asset_id: AssetId },
+    /// The balance of the contract.
+    Contract {
+        contract_id: ContractId,
+        asset_id: AssetId,
+    },
+}
+
 // GraphQL Translation
 
 impl From<schema::balance::Balance> for Balance {
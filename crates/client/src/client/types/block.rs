@@ -1,15 +1,22 @@
 use crate::client::{
     schema,
-    types::primitives::{
-        BlockId,
-        Hash,
-        MerkleRoot,
-        PublicKey,
-        Signature,
-        TransactionId,
+    types::{
+        primitives::{
+            BlockId,
+            Hash,
+            MerkleRoot,
+            PublicKey,
+            Signature,
+            TransactionId,
+        },
+        Coinbase,
     },
     PaginatedResult,
 };
+use fuel_core_types::fuel_types::{
+    AssetId,
+    Word,
+};
 use tai64::Tai64;
 
 #[derive(Debug)]
@@ -27,6 +34,26 @@ impl Block {
     }
 }
 
+/// The block together with the amounts credited by its mint transaction.
+#[derive(Debug)]
+pub struct BlockWithCoinbase {
+    pub block: Block,
+    /// `None` if the block doesn't have the mint transaction, like the genesis block.
+    /// The amounts are zero if the block didn't collect any fees.
+    pub coinbase: Option<Coinbase>,
+}
+
+/// The compact summary of the produced block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockSummary {
+    pub height: u32,
+    /// The number of transactions in the block, including the mint transaction.
+    pub tx_count: u64,
+    /// The amounts minted by the mint transaction of the block per asset.
+    pub coinbase: Vec<(AssetId, Word)>,
+    pub timestamp: Tai64,
+}
+
 #[derive(Debug)]
 pub struct Header {
     pub id: BlockId,
@@ -56,6 +83,17 @@ pub struct Genesis {
     pub messages_root: MerkleRoot,
 }
 
+/// The genesis block of the network, see [`FuelClient::genesis_info`](crate::client::FuelClient::genesis_info).
+#[derive(Debug)]
+pub struct GenesisInfo {
+    /// The header of the genesis block.
+    pub header: Header,
+    /// The hash of the chain config committed by the genesis block. The hash covers
+    /// the consensus parameters, including the base asset and the gas costs, and
+    /// doesn't depend on the order of the fields in the serialized chain config.
+    pub chain_config_hash: Hash,
+}
+
 #[derive(Debug)]
 pub struct PoAConsensus {
     pub signature: Signature,
@@ -133,6 +171,29 @@ impl From<schema::block::Block> for Block {
     }
 }
 
+impl From<schema::block::BlockWithCoinbase> for BlockWithCoinbase {
+    fn from(value: schema::block::BlockWithCoinbase) -> Self {
+        let transactions = value
+            .transactions
+            .iter()
+            .map(|tx| tx.id.clone())
+            .map(Into::into)
+            .collect::<Vec<TransactionId>>();
+        let block_producer = value.block_producer();
+        let block = Block {
+            id: value.id.into(),
+            header: value.header.into(),
+            consensus: value.consensus.into(),
+            transactions,
+            block_producer,
+        };
+        Self {
+            block,
+            coinbase: value.coinbase.map(Into::into),
+        }
+    }
+}
+
 impl From<schema::block::BlockConnection> for PaginatedResult<Block, String> {
     fn from(conn: schema::block::BlockConnection) -> Self {
         PaginatedResult {
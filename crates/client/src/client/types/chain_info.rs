@@ -2,13 +2,31 @@ use crate::client::{
     schema,
     types::Block,
 };
-use fuel_core_types::fuel_tx::ConsensusParameters;
+use fuel_core_types::{
+    fuel_tx::ConsensusParameters,
+    fuel_types::AssetId,
+};
 
 pub struct ChainInfo {
     pub da_height: u64,
     pub name: String,
     pub peer_count: i32,
     pub latest_block: Block,
+    /// The height of the `latest_block`.
+    pub latest_block_height: u32,
+    pub consensus_parameters: ConsensusParameters,
+    /// The asset used to pay the fees, the same as in the `consensus_parameters`.
+    pub base_asset_id: AssetId,
+    /// The maximum gas that the transactions of one block can consume.
+    pub block_gas_limit: u64,
+}
+
+/// The consensus-relevant part of the chain configuration of the node.
+#[derive(Clone, Debug)]
+pub struct ChainConfig {
+    pub chain_name: String,
+    /// The maximum gas that the transactions of one block can consume.
+    pub block_gas_limit: u64,
     pub consensus_parameters: ConsensusParameters,
 }
 
@@ -16,11 +34,26 @@ pub struct ChainInfo {
 
 impl From<schema::chain::ChainInfo> for ChainInfo {
     fn from(value: schema::chain::ChainInfo) -> Self {
+        let latest_block: Block = value.latest_block.into();
+        let consensus_parameters: ConsensusParameters = value.consensus_parameters.into();
         Self {
             da_height: value.da_height.into(),
             name: value.name,
             peer_count: value.peer_count,
-            latest_block: value.latest_block.into(),
+            latest_block_height: latest_block.header.height,
+            latest_block,
+            base_asset_id: consensus_parameters.base_asset_id,
+            consensus_parameters,
+            block_gas_limit: value.block_gas_limit.into(),
+        }
+    }
+}
+
+impl From<schema::chain::ChainConfig> for ChainConfig {
+    fn from(value: schema::chain::ChainConfig) -> Self {
+        Self {
+            chain_name: value.chain_name,
+            block_gas_limit: value.block_gas_limit.into(),
             consensus_parameters: value.consensus_parameters.into(),
         }
     }
@@ -0,0 +1,70 @@
+use crate::client::schema::block::{
+    Coinbase as SchemaCoinbase,
+    CoinbaseCredit as SchemaCoinbaseCredit,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            InputContract,
+            MintAmount,
+            MintAssetId,
+        },
+        Mint,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        ContractId,
+        Word,
+    },
+};
+
+/// The amounts credited to the coinbase recipient by the mint transaction of the block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coinbase {
+    /// The contract that received the minted amounts.
+    /// `None` if the fee is burned because the block producer doesn't have the recipient.
+    pub recipient: Option<ContractId>,
+    /// The minted amount per asset.
+    pub amounts: Vec<(AssetId, Word)>,
+}
+
+/// The amount credited to the coinbase recipient by the block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoinbaseCredit {
+    pub block_height: BlockHeight,
+    pub asset_id: AssetId,
+    pub amount: Word,
+}
+
+impl From<&Mint> for Coinbase {
+    fn from(mint: &Mint) -> Self {
+        let recipient = mint.input_contract().contract_id;
+        Self {
+            recipient: (recipient != ContractId::zeroed()).then_some(recipient),
+            amounts: vec![(*mint.mint_asset_id(), *mint.mint_amount())],
+        }
+    }
+}
+
+// GraphQL Translation
+
+impl From<SchemaCoinbase> for Coinbase {
+    fn from(value: SchemaCoinbase) -> Self {
+        Self {
+            recipient: value.recipient.map(Into::into),
+            amounts: vec![(value.asset_id.into(), value.amount.into())],
+        }
+    }
+}
+
+impl From<SchemaCoinbaseCredit> for CoinbaseCredit {
+    fn from(value: SchemaCoinbaseCredit) -> Self {
+        let height: u32 = value.block_height.into();
+        Self {
+            block_height: height.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+        }
+    }
+}
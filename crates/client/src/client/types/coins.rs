@@ -8,6 +8,10 @@ use crate::client::{
     },
     PaginatedResult,
 };
+use fuel_core_types::fuel_tx::{
+    Input,
+    TxPointer,
+};
 
 #[derive(Debug)]
 pub enum CoinType {
@@ -36,6 +40,23 @@ pub struct Coin {
     pub owner: Address,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoinOutputType {
+    Coin,
+    Change,
+    Variable,
+}
+
+/// The coin created by the output of the committed transaction.
+#[derive(Debug, PartialEq)]
+pub struct CreatedUtxo {
+    pub utxo_id: UtxoId,
+    pub output_type: CoinOutputType,
+    pub owner: Address,
+    pub amount: u64,
+    pub asset_id: AssetId,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct MessageCoin {
     pub amount: u64,
@@ -45,6 +66,93 @@ pub struct MessageCoin {
     pub da_height: u64,
 }
 
+/// The query of the coins to spend.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpendQuery {
+    /// The target amount per asset. The assets must be unique.
+    pub targets: Vec<(AssetId, u64)>,
+    /// The maximum number of the inputs selected per asset.
+    pub max_inputs: Option<u32>,
+}
+
+impl SpendQuery {
+    pub fn new(targets: Vec<(AssetId, u64)>) -> Self {
+        Self {
+            targets,
+            max_inputs: None,
+        }
+    }
+
+    pub fn with_max_inputs(mut self, max_inputs: u32) -> Self {
+        self.max_inputs = Some(max_inputs);
+        self
+    }
+}
+
+/// The coin selected to cover the [`SpendQuery`].
+#[derive(Debug, PartialEq)]
+pub enum CoinInput {
+    Coin(Coin),
+    MessageCoin(MessageCoin),
+}
+
+impl CoinInput {
+    pub fn amount(&self) -> u64 {
+        match self {
+            CoinInput::Coin(c) => c.amount,
+            CoinInput::MessageCoin(m) => m.amount,
+        }
+    }
+
+    /// Converts the coin into the input unlocked by the `predicate`.
+    /// The owner of the coin must be the root of the `predicate`.
+    ///
+    /// The gas used by the predicate is zero, so the caller should estimate it
+    /// before the submission of the transaction.
+    pub fn into_predicate_input(
+        self,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+    ) -> Input {
+        match self {
+            CoinInput::Coin(coin) => Input::coin_predicate(
+                coin.utxo_id,
+                coin.owner,
+                coin.amount,
+                coin.asset_id,
+                TxPointer::default(),
+                coin.maturity.into(),
+                0,
+                predicate,
+                predicate_data,
+            ),
+            CoinInput::MessageCoin(message) => Input::message_coin_predicate(
+                message.sender,
+                message.recipient,
+                message.amount,
+                message.nonce,
+                0,
+                predicate,
+                predicate_data,
+            ),
+        }
+    }
+}
+
+/// The coins of the owner can't cover the [`SpendQuery`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{reason}")]
+pub struct ResourcesExhausted {
+    /// The reason reported by the node.
+    pub reason: String,
+}
+
+impl ResourcesExhausted {
+    /// The `code` in the extensions of the GraphQL error that the node
+    /// returns when the coins can't cover the query.
+    pub const CODE: &'static str = "RESOURCES_EXHAUSTED";
+}
+
 // GraphQL Translation
 
 impl From<schema::coins::CoinType> for CoinType {
@@ -94,3 +202,25 @@ impl From<schema::coins::CoinConnection> for PaginatedResult<Coin, String> {
         }
     }
 }
+
+impl From<schema::tx::CoinOutputType> for CoinOutputType {
+    fn from(value: schema::tx::CoinOutputType) -> Self {
+        match value {
+            schema::tx::CoinOutputType::Coin => Self::Coin,
+            schema::tx::CoinOutputType::Change => Self::Change,
+            schema::tx::CoinOutputType::Variable => Self::Variable,
+        }
+    }
+}
+
+impl From<schema::tx::CreatedUtxo> for CreatedUtxo {
+    fn from(value: schema::tx::CreatedUtxo) -> Self {
+        Self {
+            utxo_id: value.utxo_id.into(),
+            output_type: value.output_type.into(),
+            owner: value.owner.into(),
+            amount: value.amount.into(),
+            asset_id: value.asset_id.into(),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::client::{
     types::primitives::{
         AssetId,
         Bytes,
+        Bytes32,
         ContractId,
         Salt,
     },
@@ -22,6 +23,23 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+/// The change of the contract's balance of the asset caused by the block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractBalanceChange {
+    pub block_height: u32,
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub old: u64,
+    pub new: u64,
+}
+
+/// The storage slot of the contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractStorageSlot {
+    pub key: Bytes32,
+    pub value: Bytes32,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -44,6 +62,18 @@ impl From<schema::contract::ContractBalance> for ContractBalance {
     }
 }
 
+impl From<schema::contract::ContractBalanceChange> for ContractBalanceChange {
+    fn from(value: schema::contract::ContractBalanceChange) -> Self {
+        Self {
+            block_height: value.block_height.into(),
+            contract_id: value.contract_id.into(),
+            asset_id: value.asset_id.into(),
+            old: value.old.into(),
+            new: value.new.into(),
+        }
+    }
+}
+
 impl From<schema::contract::ContractBalanceConnection>
     for PaginatedResult<ContractBalance, String>
 {
@@ -56,3 +86,25 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl From<schema::contract::ContractStorageSlot> for ContractStorageSlot {
+    fn from(value: schema::contract::ContractStorageSlot) -> Self {
+        Self {
+            key: value.key.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlotConnection>
+    for PaginatedResult<ContractStorageSlot, String>
+{
+    fn from(conn: schema::contract::ContractStorageSlotConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        Create,
+        StorageSlot,
+        TransactionBuilder,
+        Witness,
+    },
+    fuel_types::Salt,
+};
+
+/// Derives the salt of the contract from the `seed` and the `bytecode`. The same seed and
+/// bytecode always produce the same salt, so the contract ID is known before the deployment.
+pub fn deterministic_salt(seed: &[u8], bytecode: &[u8]) -> Salt {
+    let hash = Hasher::default().chain(seed).chain(bytecode).finalize();
+    Salt::new(*hash)
+}
+
+/// Creates the builder of the `Create` transaction with the deterministic salt.
+pub trait CreateWithDeterministicSalt {
+    /// Creates the builder of the `Create` transaction for the `bytecode`, with the salt
+    /// derived by [`deterministic_salt`] from the `seed` and the `bytecode`.
+    fn create_with_deterministic_salt(
+        bytecode: Witness,
+        seed: &[u8],
+        storage_slots: Vec<StorageSlot>,
+    ) -> Self;
+}
+
+impl CreateWithDeterministicSalt for TransactionBuilder<Create> {
+    fn create_with_deterministic_salt(
+        bytecode: Witness,
+        seed: &[u8],
+        storage_slots: Vec<StorageSlot>,
+    ) -> Self {
+        let salt = deterministic_salt(seed, bytecode.as_ref());
+        TransactionBuilder::create(bytecode, salt, storage_slots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use fuel_core_types::{
+        fuel_tx::{
+            field::Salt as _,
+            Cacheable,
+            Finalizable,
+        },
+        fuel_types::ChainId,
+    };
+
+    fn contract_id(bytecode: &[u8], seed: &[u8]) -> fuel_core_types::fuel_tx::ContractId {
+        let mut tx = TransactionBuilder::<Create>::create_with_deterministic_salt(
+            bytecode.to_vec().into(),
+            seed,
+            vec![],
+        )
+        .add_random_fee_input()
+        .finalize();
+        tx.precompute(&ChainId::default())
+            .expect("tx should be valid");
+        tx.metadata().as_ref().unwrap().contract_id
+    }
+
+    #[test]
+    fn create_with_deterministic_salt__uses_derived_salt() {
+        let bytecode = vec![1, 2, 3];
+        let tx = TransactionBuilder::<Create>::create_with_deterministic_salt(
+            bytecode.clone().into(),
+            b"seed",
+            vec![],
+        )
+        .finalize();
+
+        assert_eq!(*tx.salt(), deterministic_salt(b"seed", &bytecode));
+    }
+
+    #[test]
+    fn create_with_deterministic_salt__contract_id_is_stable() {
+        let bytecode = [1, 2, 3];
+
+        assert_eq!(
+            contract_id(&bytecode, b"seed"),
+            contract_id(&bytecode, b"seed")
+        );
+        assert_ne!(
+            contract_id(&bytecode, b"seed"),
+            contract_id(&bytecode, b"other")
+        );
+        assert_ne!(
+            contract_id(&bytecode, b"seed"),
+            contract_id(&[4, 5, 6], b"seed")
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use fuel_core_types::{
+    fuel_asm::PanicReason,
+    fuel_tx::Receipt,
+};
+use std::fmt;
+
+/// The panic found by the dry run that will fail the transaction after the submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DryRunDiagnostic {
+    /// The index of the `Panic` receipt.
+    pub receipt_index: usize,
+    /// The reason of the panic.
+    pub reason: PanicReason,
+    /// The change of the transaction that fixes the panic, if it is known.
+    pub hint: Option<&'static str>,
+}
+
+impl DryRunDiagnostic {
+    /// Collects the diagnostics from the `Panic` receipts of the dry run.
+    pub fn from_receipts(receipts: &[Receipt]) -> Vec<Self> {
+        receipts
+            .iter()
+            .enumerate()
+            .filter_map(|(receipt_index, receipt)| match receipt {
+                Receipt::Panic { reason, .. } => {
+                    let reason = *reason.reason();
+                    Some(Self {
+                        receipt_index,
+                        reason,
+                        hint: hint(reason),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn hint(reason: PanicReason) -> Option<&'static str> {
+    match reason {
+        PanicReason::OutputNotFound => Some("add a variable output"),
+        PanicReason::ContractNotInInputs => {
+            Some("add the contract input and the contract output")
+        }
+        _ => None,
+    }
+}
+
+impl fmt::Display for DryRunDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "transaction will fail: {:?} at receipt {}",
+            self.reason, self.receipt_index
+        )?;
+        if let Some(hint) = self.hint {
+            write!(f, ", {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::PanicInstruction,
+        fuel_tx::ContractId,
+    };
+
+    fn panic_receipt(reason: PanicReason) -> Receipt {
+        Receipt::panic(
+            ContractId::zeroed(),
+            PanicInstruction::error(reason, 0),
+            0,
+            0,
+        )
+    }
+
+    #[test]
+    fn from_receipts__reports_panics_with_hints() {
+        let receipts = vec![
+            Receipt::ret(ContractId::zeroed(), 0, 0, 0),
+            panic_receipt(PanicReason::OutputNotFound),
+            Receipt::script_result(
+                fuel_core_types::fuel_tx::ScriptExecutionResult::Panic,
+                0,
+            ),
+        ];
+
+        let diagnostics = DryRunDiagnostic::from_receipts(&receipts);
+
+        assert_eq!(
+            diagnostics,
+            vec![DryRunDiagnostic {
+                receipt_index: 1,
+                reason: PanicReason::OutputNotFound,
+                hint: Some("add a variable output"),
+            }]
+        );
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "transaction will fail: OutputNotFound at receipt 1, add a variable output"
+        );
+    }
+
+    #[test]
+    fn from_receipts__omits_unknown_hint() {
+        let receipts = vec![panic_receipt(PanicReason::ArithmeticOverflow)];
+
+        let diagnostics = DryRunDiagnostic::from_receipts(&receipts);
+
+        assert_eq!(diagnostics[0].hint, None);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "transaction will fail: ArithmeticOverflow at receipt 0"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use fuel_core_types::{
+    fuel_tx::{
+        Chargeable,
+        ConsensusParameters,
+        Receipt,
+        Transaction,
+        TransactionFee,
+    },
+    fuel_types::Word,
+};
+
+/// The breakdown of the fee paid by the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    /// The gas charged regardless of the execution: the bytes and the predicates.
+    pub min_gas: Word,
+    /// The gas that the transaction may spend at most.
+    pub max_gas: Word,
+    /// The gas spent by the execution of the script.
+    pub gas_used: Word,
+    /// The fee paid by the transaction after the refund of the unused gas.
+    pub total_fee: Word,
+}
+
+impl FeeBreakdown {
+    /// Calculates the fee of the executed `tx` in the same way as the executor does it.
+    ///
+    /// Returns `None` for the `Mint` transaction or if the fee overflows.
+    pub fn new(
+        tx: &Transaction,
+        receipts: &[Receipt],
+        params: &ConsensusParameters,
+    ) -> Option<Self> {
+        match tx {
+            Transaction::Script(script) => {
+                Self::from_chargeable(script, receipts, params)
+            }
+            Transaction::Create(create) => {
+                Self::from_chargeable(create, receipts, params)
+            }
+            Transaction::Mint(_) => None,
+        }
+    }
+
+    fn from_chargeable<Tx: Chargeable>(
+        tx: &Tx,
+        receipts: &[Receipt],
+        params: &ConsensusParameters,
+    ) -> Option<Self> {
+        let gas_costs = params.gas_costs();
+        let fee_params = params.fee_params();
+        let gas_used = receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let max_fee =
+            TransactionFee::checked_from_tx(gas_costs, fee_params, tx)?.max_fee();
+        let refund = tx.refund_fee(gas_costs, fee_params, gas_used)?;
+
+        Some(Self {
+            min_gas: tx.min_gas(gas_costs, fee_params),
+            max_gas: tx.max_gas(gas_costs, fee_params),
+            gas_used,
+            total_fee: max_fee.checked_sub(refund)?,
+        })
+    }
+}
+
+/// The cost of the transaction estimated by the dry run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionCost {
+    /// The gas spent by the execution of the script.
+    pub gas_used: Word,
+    /// The fee charged regardless of the execution.
+    pub min_fee: Word,
+    /// The fee charged if the script spends the whole gas limit.
+    pub max_fee: Word,
+    /// The gas that the transaction may spend at most.
+    pub max_gas: Word,
+}
+
+impl TransactionCost {
+    /// Calculates the cost of the dry-run `tx` from its `receipts`.
+    ///
+    /// Returns `None` for the `Mint` transaction or if the fee overflows.
+    pub fn new(
+        tx: &Transaction,
+        receipts: &[Receipt],
+        params: &ConsensusParameters,
+    ) -> Option<Self> {
+        match tx {
+            Transaction::Script(script) => {
+                Self::from_chargeable(script, receipts, params)
+            }
+            Transaction::Create(create) => {
+                Self::from_chargeable(create, receipts, params)
+            }
+            Transaction::Mint(_) => None,
+        }
+    }
+
+    fn from_chargeable<Tx: Chargeable>(
+        tx: &Tx,
+        receipts: &[Receipt],
+        params: &ConsensusParameters,
+    ) -> Option<Self> {
+        let gas_costs = params.gas_costs();
+        let fee_params = params.fee_params();
+        let breakdown = FeeBreakdown::from_chargeable(tx, receipts, params)?;
+        let fee = TransactionFee::checked_from_tx(gas_costs, fee_params, tx)?;
+
+        Some(Self {
+            gas_used: breakdown.gas_used,
+            min_fee: fee.min_fee(),
+            max_fee: fee.max_fee(),
+            max_gas: breakdown.max_gas,
+        })
+    }
+}
@@ -0,0 +1,496 @@
+use crate::client::types::{
+    Coin,
+    Signer,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        Buildable,
+        ConsensusParameters,
+        Finalizable,
+        Input,
+        Output,
+        Script,
+        TransactionBuilder,
+        TransactionFee,
+        UtxoId,
+        Witness,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Word,
+    },
+};
+
+/// The coin that can be used to fund the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoinInfo {
+    pub utxo_id: UtxoId,
+    pub asset_id: AssetId,
+    pub amount: Word,
+    pub maturity: BlockHeight,
+}
+
+impl From<&Coin> for CoinInfo {
+    fn from(coin: &Coin) -> Self {
+        Self {
+            utxo_id: coin.utxo_id,
+            asset_id: coin.asset_id,
+            amount: coin.amount,
+            maturity: coin.maturity.into(),
+        }
+    }
+}
+
+/// The result of the funding of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Funding {
+    /// The total amount of the selected coins.
+    pub total: Word,
+    /// The amount left after paying the target amount and the max fee.
+    /// It is returned to the signer by the change output.
+    pub change: Word,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Insufficient funds: required {required}, available {available}")]
+pub struct InsufficientFunds {
+    /// The target amount plus the max fee of the transaction.
+    pub required: Word,
+    /// The total amount of the base asset coins.
+    pub available: Word,
+}
+
+/// Funds the transaction from the set of coins.
+pub trait FundWith {
+    /// Greedily selects the base asset coins, starting from the biggest one, until they
+    /// cover the `target_amount` plus the max fee of the transaction. The selected coins
+    /// are added as inputs signed by the `signer`.
+    ///
+    /// The fee is estimated with a change output, so the caller should add the change
+    /// output to the signer after the funding. The builder is left unchanged on error.
+    fn fund_with(
+        &mut self,
+        coins: &[CoinInfo],
+        signer: &SecretKey,
+        target_amount: Word,
+        params: &ConsensusParameters,
+    ) -> Result<Funding, InsufficientFunds>;
+
+    /// Selects the coins in the same way as [`Self::fund_with`], but adds them as the
+    /// inputs owned by the `owner` and referencing the witness at the `witness_index`,
+    /// so they can be signed later by the [`Signer`](super::Signer).
+    ///
+    /// The witness should be added before the funding, so the estimated fee covers it.
+    fn fund_with_witness(
+        &mut self,
+        coins: &[CoinInfo],
+        owner: &Address,
+        witness_index: u8,
+        target_amount: Word,
+        params: &ConsensusParameters,
+    ) -> Result<Funding, InsufficientFunds>;
+}
+
+impl<Tx> FundWith for TransactionBuilder<Tx>
+where
+    Tx: Buildable,
+    Self: Finalizable<Tx>,
+{
+    fn fund_with(
+        &mut self,
+        coins: &[CoinInfo],
+        signer: &SecretKey,
+        target_amount: Word,
+        params: &ConsensusParameters,
+    ) -> Result<Funding, InsufficientFunds> {
+        let owner = Input::owner(&signer.public_key());
+        fund(
+            self,
+            coins,
+            &owner,
+            target_amount,
+            params,
+            |builder, coin| {
+                builder.add_unsigned_coin_input(
+                    *signer,
+                    coin.utxo_id,
+                    coin.amount,
+                    coin.asset_id,
+                    Default::default(),
+                    coin.maturity,
+                );
+            },
+        )
+    }
+
+    fn fund_with_witness(
+        &mut self,
+        coins: &[CoinInfo],
+        owner: &Address,
+        witness_index: u8,
+        target_amount: Word,
+        params: &ConsensusParameters,
+    ) -> Result<Funding, InsufficientFunds> {
+        fund(
+            self,
+            coins,
+            owner,
+            target_amount,
+            params,
+            |builder, coin| {
+                builder.add_input(Input::coin_signed(
+                    coin.utxo_id,
+                    *owner,
+                    coin.amount,
+                    coin.asset_id,
+                    Default::default(),
+                    witness_index,
+                    coin.maturity,
+                ));
+            },
+        )
+    }
+}
+
+fn fund<Tx, F>(
+    tx_builder: &mut TransactionBuilder<Tx>,
+    coins: &[CoinInfo],
+    owner: &Address,
+    target_amount: Word,
+    params: &ConsensusParameters,
+    mut add_input: F,
+) -> Result<Funding, InsufficientFunds>
+where
+    Tx: Buildable,
+    TransactionBuilder<Tx>: Finalizable<Tx>,
+    F: FnMut(&mut TransactionBuilder<Tx>, &CoinInfo),
+{
+    let base_asset_id = params.base_asset_id;
+    let mut candidates: Vec<_> = coins
+        .iter()
+        .filter(|coin| coin.asset_id == base_asset_id)
+        .collect();
+    candidates.sort_by(|a, b| b.amount.cmp(&a.amount));
+
+    let mut builder = tx_builder.clone();
+    let mut total: Word = 0;
+    let mut required = target_amount;
+    for coin in candidates {
+        add_input(&mut builder, coin);
+        total = total.saturating_add(coin.amount);
+
+        let mut estimation = builder.clone();
+        estimation.add_output(Output::change(*owner, 0, base_asset_id));
+        let tx = estimation.finalize();
+        let max_fee =
+            TransactionFee::checked_from_tx(params.gas_costs(), params.fee_params(), &tx)
+                .map(|fee| fee.max_fee())
+                .unwrap_or(Word::MAX);
+        required = target_amount.saturating_add(max_fee);
+
+        if total >= required {
+            *tx_builder = builder;
+            return Ok(Funding {
+                total,
+                change: total.saturating_sub(required),
+            })
+        }
+    }
+
+    Err(InsufficientFunds {
+        required,
+        available: total,
+    })
+}
+
+/// Builds the transaction merging many coins into one, to reduce the fragmentation
+/// of the UTXO set, for example, after the repeated fee collections.
+pub trait Consolidate: Sized {
+    /// The gas limit of the consolidation script, which only returns.
+    const SCRIPT_GAS_LIMIT: Word = 1_000;
+
+    /// Creates the script transaction spending all `coins` owned by the `signer` and
+    /// merging them into one coin per asset owned by the `to` address. The fee is paid
+    /// from the base asset, so the consolidated base asset coin is reduced by the fee.
+    ///
+    /// The coins reference the first witness, like the ones from the
+    /// [`FundWith::fund_with_witness`], so the transaction should be finalized with
+    /// the [`FinalizeSigned`](super::FinalizeSigned) and the same `signer`.
+    ///
+    /// The `coins` should fit into the `max_inputs` of the network. The gas price is
+    /// zero, so update it before the finalization if the network requires the fee.
+    fn consolidate<S>(coins: &[CoinInfo], to: Address, signer: &S) -> Self
+    where
+        S: Signer + ?Sized;
+}
+
+impl Consolidate for TransactionBuilder<Script> {
+    fn consolidate<S>(coins: &[CoinInfo], to: Address, signer: &S) -> Self
+    where
+        S: Signer + ?Sized,
+    {
+        const WITNESS_INDEX: u8 = 0;
+        let owner = Input::owner(&signer.public_key());
+        let mut builder =
+            TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![]);
+        builder.script_gas_limit(Self::SCRIPT_GAS_LIMIT);
+        // The placeholder of the signature, so the fee of the witness is accounted.
+        builder.add_witness(Witness::default());
+
+        let mut assets = vec![];
+        for coin in coins {
+            builder.add_input(Input::coin_signed(
+                coin.utxo_id,
+                owner,
+                coin.amount,
+                coin.asset_id,
+                Default::default(),
+                WITNESS_INDEX,
+                coin.maturity,
+            ));
+            if !assets.contains(&coin.asset_id) {
+                assets.push(coin.asset_id);
+            }
+        }
+        for asset_id in assets {
+            builder.add_output(Output::change(to, 0, asset_id));
+        }
+
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use crate::client::types::SecretKeySigner;
+    use fuel_core_types::fuel_tx::field::{
+        Inputs,
+        Outputs,
+        Witnesses,
+    };
+
+    fn coin(amount: Word, asset_id: AssetId, index: u8) -> CoinInfo {
+        CoinInfo {
+            utxo_id: UtxoId::new([index; 32].into(), 0),
+            asset_id,
+            amount,
+            maturity: Default::default(),
+        }
+    }
+
+    fn builder(gas_price: Word) -> TransactionBuilder<Script> {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.gas_price(gas_price).script_gas_limit(100);
+        builder
+    }
+
+    /// The max fee of the transaction with the change output to the `owner`.
+    fn max_fee_with_change(
+        builder: &TransactionBuilder<Script>,
+        owner: &Address,
+        params: &ConsensusParameters,
+    ) -> Word {
+        let mut builder = builder.clone();
+        builder.add_output(Output::change(*owner, 0, params.base_asset_id));
+        let tx = builder.finalize();
+        TransactionFee::checked_from_tx(params.gas_costs(), params.fee_params(), &tx)
+            .unwrap()
+            .max_fee()
+    }
+
+    #[test]
+    fn fund_with__selects_biggest_coins_first() {
+        let params = ConsensusParameters::default();
+        let signer = SecretKey::try_from([1u8; 32].as_slice()).unwrap();
+        let owner = Input::owner(&signer.public_key());
+        let coins = [
+            coin(10, AssetId::BASE, 0),
+            coin(1000, AssetId::BASE, 1),
+            coin(100, AssetId::BASE, 2),
+            coin(10_000, AssetId::new([1; 32]), 3),
+        ];
+        let mut builder = builder(1);
+
+        let funding = builder.fund_with(&coins, &signer, 500, &params).unwrap();
+
+        let max_fee = max_fee_with_change(&builder, &owner, &params);
+        let tx = builder.finalize();
+        assert_eq!(
+            tx.inputs(),
+            &vec![Input::coin_signed(
+                coins[1].utxo_id,
+                owner,
+                1000,
+                AssetId::BASE,
+                Default::default(),
+                0,
+                Default::default(),
+            )]
+        );
+        assert_eq!(
+            funding,
+            Funding {
+                total: 1000,
+                // The coin of 1000 pays the target amount of 500 and the fee.
+                change: Word::saturating_sub(500, max_fee),
+            }
+        );
+        assert!(tx.outputs().is_empty());
+    }
+
+    #[test]
+    fn fund_with__selects_coins_of_the_network_base_asset() {
+        let base_asset_id = AssetId::new([1; 32]);
+        let mut params = ConsensusParameters::default();
+        params.base_asset_id = base_asset_id;
+        let signer = SecretKey::try_from([1u8; 32].as_slice()).unwrap();
+        let owner = Input::owner(&signer.public_key());
+        let coins = [coin(10_000, AssetId::BASE, 0), coin(1000, base_asset_id, 1)];
+        let mut builder = builder(1);
+
+        let funding = builder.fund_with(&coins, &signer, 500, &params).unwrap();
+
+        let max_fee = max_fee_with_change(&builder, &owner, &params);
+        let tx = builder.finalize();
+        assert_eq!(
+            tx.inputs(),
+            &vec![Input::coin_signed(
+                coins[1].utxo_id,
+                owner,
+                1000,
+                base_asset_id,
+                Default::default(),
+                0,
+                Default::default(),
+            )]
+        );
+        assert_eq!(
+            funding,
+            Funding {
+                total: 1000,
+                // The coin of 1000 pays the target amount of 500 and the fee.
+                change: Word::saturating_sub(500, max_fee),
+            }
+        );
+    }
+
+    #[test]
+    fn fund_with_witness__adds_inputs_referencing_the_witness() {
+        let params = ConsensusParameters::default();
+        let owner = Address::new([2; 32]);
+        let coins = [coin(1000, AssetId::BASE, 0), coin(100, AssetId::BASE, 1)];
+        let mut builder = builder(1);
+        builder.add_witness(vec![0; 64].into());
+
+        let funding = builder
+            .fund_with_witness(&coins, &owner, 0, 500, &params)
+            .unwrap();
+
+        let max_fee = max_fee_with_change(&builder, &owner, &params);
+        let tx = builder.finalize();
+        assert_eq!(
+            funding,
+            Funding {
+                total: 1000,
+                // The coin of 1000 pays the target amount of 500 and the fee.
+                change: Word::saturating_sub(500, max_fee),
+            }
+        );
+        assert_eq!(
+            tx.inputs(),
+            &vec![Input::coin_signed(
+                coins[0].utxo_id,
+                owner,
+                1000,
+                AssetId::BASE,
+                Default::default(),
+                0,
+                Default::default(),
+            )]
+        );
+    }
+
+    #[test]
+    fn consolidate__spends_all_coins_into_one_change_output_per_asset() {
+        let signer =
+            SecretKeySigner::new(SecretKey::try_from([1u8; 32].as_slice()).unwrap());
+        let owner = Input::owner(&signer.public_key());
+        let to = Address::new([2; 32]);
+        let other_asset = AssetId::new([1; 32]);
+        let coins = [
+            coin(10, AssetId::BASE, 0),
+            coin(1000, other_asset, 1),
+            coin(100, AssetId::BASE, 2),
+        ];
+
+        let tx = TransactionBuilder::consolidate(&coins, to, &signer).finalize();
+
+        assert_eq!(
+            tx.inputs(),
+            &coins
+                .iter()
+                .map(|coin| Input::coin_signed(
+                    coin.utxo_id,
+                    owner,
+                    coin.amount,
+                    coin.asset_id,
+                    Default::default(),
+                    0,
+                    coin.maturity,
+                ))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(tx.witnesses().len(), 1);
+        assert_eq!(
+            tx.outputs(),
+            &vec![
+                Output::change(to, 0, AssetId::BASE),
+                Output::change(to, 0, other_asset),
+            ]
+        );
+    }
+
+    #[test]
+    fn fund_with__returns_error_and_keeps_builder_if_funds_are_insufficient() {
+        let params = ConsensusParameters::default();
+        let signer = SecretKey::try_from([1u8; 32].as_slice()).unwrap();
+        let owner = Input::owner(&signer.public_key());
+        let coins = [coin(10, AssetId::BASE, 0), coin(20, AssetId::BASE, 1)];
+        let mut builder = builder(1);
+
+        let err = builder
+            .fund_with(&coins, &signer, 500, &params)
+            .unwrap_err();
+
+        // The required amount is estimated with all coins as inputs.
+        let mut funded = builder.clone();
+        for coin in [coins[1], coins[0]] {
+            funded.add_unsigned_coin_input(
+                signer,
+                coin.utxo_id,
+                coin.amount,
+                coin.asset_id,
+                Default::default(),
+                coin.maturity,
+            );
+        }
+        let max_fee = max_fee_with_change(&funded, &owner, &params);
+        assert_eq!(
+            err,
+            InsufficientFunds {
+                required: max_fee.saturating_add(500),
+                available: 30,
+            }
+        );
+        assert!(builder.finalize().inputs().is_empty());
+    }
+}
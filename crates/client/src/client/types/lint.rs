@@ -0,0 +1,180 @@
+use fuel_core_types::{
+    fuel_asm::{
+        self,
+        Instruction,
+        Opcode,
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        Input,
+        Output,
+        Script,
+    },
+    fuel_types::ContractId,
+};
+use std::fmt;
+
+/// The instruction that will panic after the submission, because the transaction
+/// lacks the inputs or the outputs required by it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lint {
+    /// The contract with the instruction in its code, or `None` for the script.
+    pub contract_id: Option<ContractId>,
+    /// The offset of the instruction in bytes from the start of the code.
+    pub offset: usize,
+    /// The opcode of the instruction.
+    pub opcode: Opcode,
+    pub kind: LintKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// The `TRO` transfers the coins to a variable output,
+    /// but the transaction doesn't have any.
+    MissingVariableOutput,
+    /// The `TR` or the `CALL` of the script needs the contract input,
+    /// but the transaction doesn't have any.
+    MissingContractInput,
+}
+
+impl Lint {
+    /// Finds the instructions of the `code` that need the inputs or the outputs that
+    /// the `tx` doesn't have. The `code` is the script of the `tx` if the `contract_id`
+    /// is `None`, or the code of the contract input otherwise.
+    ///
+    /// The code is checked statically, so the instructions behind the branches that
+    /// are never taken and the data decoded as instructions are reported too.
+    pub fn check(tx: &Script, code: &[u8], contract_id: Option<ContractId>) -> Vec<Self> {
+        let has_variable_outputs = tx
+            .outputs()
+            .iter()
+            .any(|output| matches!(output, Output::Variable { .. }));
+        let has_contract_inputs = tx
+            .inputs()
+            .iter()
+            .any(|input| matches!(input, Input::Contract(_)));
+
+        fuel_asm::from_bytes(code.iter().copied())
+            .enumerate()
+            .filter_map(|(index, instruction)| {
+                let instruction = instruction.ok()?;
+                let kind = match instruction {
+                    Instruction::TRO(_) if !has_variable_outputs => {
+                        LintKind::MissingVariableOutput
+                    }
+                    Instruction::TR(_) | Instruction::CALL(_)
+                        if contract_id.is_none() && !has_contract_inputs =>
+                    {
+                        LintKind::MissingContractInput
+                    }
+                    _ => return None,
+                };
+                Some(Self {
+                    contract_id,
+                    offset: index.saturating_mul(Instruction::SIZE),
+                    opcode: instruction.opcode(),
+                    kind,
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.contract_id {
+            Some(contract_id) => write!(
+                f,
+                "{:?} at offset {} of the contract {contract_id}",
+                self.opcode, self.offset
+            )?,
+            None => write!(
+                f,
+                "{:?} at offset {} of the script",
+                self.opcode, self.offset
+            )?,
+        }
+        match self.kind {
+            LintKind::MissingVariableOutput => write!(f, ", add a variable output"),
+            LintKind::MissingContractInput => {
+                write!(f, ", add the contract input and the contract output")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_tx::{
+            Finalizable,
+            TransactionBuilder,
+        },
+    };
+
+    fn code(instructions: Vec<Instruction>) -> Vec<u8> {
+        instructions.into_iter().collect()
+    }
+
+    #[test]
+    fn check__reports_transfer_to_output_without_variable_outputs() {
+        let contract_code = code(vec![
+            op::noop(),
+            op::tro(0x10, 0x11, 0x12, 0x13),
+            op::ret(RegId::ONE),
+        ]);
+        let contract_id = ContractId::new([1; 32]);
+        let tx = TransactionBuilder::script(vec![], vec![]).finalize();
+
+        let lints = Lint::check(&tx, &contract_code, Some(contract_id));
+
+        assert_eq!(
+            lints,
+            vec![Lint {
+                contract_id: Some(contract_id),
+                offset: Instruction::SIZE,
+                opcode: Opcode::TRO,
+                kind: LintKind::MissingVariableOutput,
+            }]
+        );
+
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_output(Output::variable(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ))
+            .finalize();
+        assert!(Lint::check(&tx, &contract_code, Some(contract_id)).is_empty());
+    }
+
+    #[test]
+    fn check__reports_script_call_without_contract_inputs() {
+        let script = code(vec![
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ]);
+        let tx = TransactionBuilder::script(script.clone(), vec![]).finalize();
+
+        let lints = Lint::check(&tx, &script, None);
+
+        assert_eq!(
+            lints,
+            vec![Lint {
+                contract_id: None,
+                offset: 0,
+                opcode: Opcode::CALL,
+                kind: LintKind::MissingContractInput,
+            }]
+        );
+    }
+}
@@ -1,4 +1,12 @@
-use crate::client::schema;
+use crate::client::{
+    schema::{
+        self,
+        ConversionError,
+    },
+    types::primitives::ContractId,
+};
+use std::time::Duration;
+use tai64::Tai64;
 
 pub struct NodeInfo {
     pub utxo_validation: bool,
@@ -7,6 +15,78 @@ pub struct NodeInfo {
     pub max_tx: u64,
     pub max_depth: u64,
     pub node_version: String,
+    /// The number of blocks on top of the block before its balances are final.
+    pub finalization_depth: u32,
+    /// The maximum `script_gas_limit` of the transaction accepted by the dry run,
+    /// or `None` if the dry run is limited only by the gas limit of the transaction.
+    pub max_dry_run_gas: Option<u64>,
+}
+
+/// The readiness of the node's services.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeHealth {
+    pub up: bool,
+    pub txpool_accepting: bool,
+    pub producer_running: bool,
+    pub p2p_connected_peers: u32,
+    pub db_height: u32,
+}
+
+/// The effective configuration of the internal services.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceConfig {
+    pub txpool: TxPoolServiceConfig,
+    pub block_producer: BlockProducerServiceConfig,
+    pub relayer: Option<RelayerServiceConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxPoolServiceConfig {
+    pub max_tx: u64,
+    pub max_depth: u64,
+    pub min_gas_price: u64,
+    pub utxo_validation: bool,
+    pub metrics: bool,
+    pub transaction_ttl: Duration,
+    pub number_of_active_subscription: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockProducerServiceConfig {
+    pub utxo_validation: bool,
+    pub coinbase_recipient: Option<ContractId>,
+    pub metrics: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayerServiceConfig {
+    pub da_deploy_height: u64,
+    pub eth_v2_listening_contracts: Vec<String>,
+    pub log_page_size: u64,
+    pub sync_minimum_duration: Duration,
+    pub syncing_call_frequency: Duration,
+    pub syncing_log_frequency: Duration,
+}
+
+/// How the node produces the blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductionConfig {
+    /// A new block is produced as soon as the transactions are available.
+    Instant,
+    /// A new block is produced every `block_time`, so it is enough to wait.
+    Interval { block_time: Duration },
+    /// The node doesn't produce blocks on its own, so they should be produced
+    /// explicitly with `FuelClient::produce_blocks`.
+    Manual,
+}
+
+/// The statistics of the block production since the start of the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProducerMetrics {
+    pub blocks_produced: u64,
+    pub average_coinbase: u64,
+    /// `None` if the node hasn't produced blocks since the start.
+    pub last_production_time: Option<Tai64>,
 }
 
 // GraphQL Translation
@@ -20,6 +100,104 @@ impl From<schema::node_info::NodeInfo> for NodeInfo {
             max_tx: value.max_tx.into(),
             max_depth: value.max_depth.into(),
             node_version: value.node_version,
+            finalization_depth: value.finalization_depth.into(),
+            max_dry_run_gas: value.max_dry_run_gas.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::node_info::NodeHealth> for NodeHealth {
+    fn from(value: schema::node_info::NodeHealth) -> Self {
+        Self {
+            up: value.up,
+            txpool_accepting: value.txpool_accepting,
+            producer_running: value.producer_running,
+            p2p_connected_peers: value.p2p_connected_peers.into(),
+            db_height: value.db_height.into(),
+        }
+    }
+}
+
+impl From<schema::node_info::ServiceConfig> for ServiceConfig {
+    fn from(value: schema::node_info::ServiceConfig) -> Self {
+        Self {
+            txpool: value.txpool.into(),
+            block_producer: value.block_producer.into(),
+            relayer: value.relayer.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::node_info::TxPoolServiceConfig> for TxPoolServiceConfig {
+    fn from(value: schema::node_info::TxPoolServiceConfig) -> Self {
+        Self {
+            max_tx: value.max_tx.into(),
+            max_depth: value.max_depth.into(),
+            min_gas_price: value.min_gas_price.into(),
+            utxo_validation: value.utxo_validation,
+            metrics: value.metrics,
+            transaction_ttl: Duration::from_millis(value.transaction_ttl.into()),
+            number_of_active_subscription: value.number_of_active_subscription.into(),
+        }
+    }
+}
+
+impl From<schema::node_info::BlockProducerServiceConfig> for BlockProducerServiceConfig {
+    fn from(value: schema::node_info::BlockProducerServiceConfig) -> Self {
+        Self {
+            utxo_validation: value.utxo_validation,
+            coinbase_recipient: value.coinbase_recipient.map(Into::into),
+            metrics: value.metrics,
+        }
+    }
+}
+
+impl From<schema::node_info::RelayerServiceConfig> for RelayerServiceConfig {
+    fn from(value: schema::node_info::RelayerServiceConfig) -> Self {
+        Self {
+            da_deploy_height: value.da_deploy_height.into(),
+            eth_v2_listening_contracts: value.eth_v2_listening_contracts,
+            log_page_size: value.log_page_size.into(),
+            sync_minimum_duration: Duration::from_millis(
+                value.sync_minimum_duration.into(),
+            ),
+            syncing_call_frequency: Duration::from_millis(
+                value.syncing_call_frequency.into(),
+            ),
+            syncing_log_frequency: Duration::from_millis(
+                value.syncing_log_frequency.into(),
+            ),
+        }
+    }
+}
+
+impl TryFrom<schema::node_info::BlockProductionConfig> for ProductionConfig {
+    type Error = ConversionError;
+
+    fn try_from(
+        value: schema::node_info::BlockProductionConfig,
+    ) -> Result<Self, Self::Error> {
+        Ok(match value.mode {
+            schema::node_info::BlockProductionMode::Instant => Self::Instant,
+            schema::node_info::BlockProductionMode::Interval => {
+                let block_time = value.block_time.ok_or_else(|| {
+                    ConversionError::MissingField("block_time".to_string())
+                })?;
+                Self::Interval {
+                    block_time: Duration::from_millis(block_time.into()),
+                }
+            }
+            schema::node_info::BlockProductionMode::Manual => Self::Manual,
+        })
+    }
+}
+
+impl From<schema::node_info::ProducerMetrics> for ProducerMetrics {
+    fn from(value: schema::node_info::ProducerMetrics) -> Self {
+        Self {
+            blocks_produced: value.blocks_produced.into(),
+            average_coinbase: value.average_coinbase.into(),
+            last_production_time: value.last_production_time.map(|time| time.0),
         }
     }
 }
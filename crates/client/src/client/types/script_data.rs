@@ -0,0 +1,107 @@
+use fuel_core_chain_config::fee_collection_contract::CallStructure;
+use fuel_core_types::fuel_types::{
+    canonical::Serialize,
+    Address,
+    AssetId,
+    ContractId,
+    Word,
+};
+
+/// Builds the `script_data` from the typed values serialized in the canonical
+/// encoding, so the offsets of the values match the sizes expected by the script.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptDataBuilder {
+    data: Vec<u8>,
+}
+
+impl ScriptDataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of the bytes written so far, which is the offset of the next value.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn asset_id(self, asset_id: AssetId) -> Self {
+        self.value(&asset_id)
+    }
+
+    pub fn address(self, address: Address) -> Self {
+        self.value(&address)
+    }
+
+    pub fn contract_id(self, contract_id: ContractId) -> Self {
+        self.value(&contract_id)
+    }
+
+    pub fn u64(self, value: Word) -> Self {
+        self.value(&value)
+    }
+
+    /// Appends the call structure in the layout read by the `CALL` instruction.
+    pub fn call(mut self, call: CallStructure) -> Self {
+        self.data.extend(call.to_bytes());
+        self
+    }
+
+    /// Appends the raw `bytes` as is, without the length prefix.
+    pub fn bytes(mut self, bytes: &[u8]) -> Self {
+        self.data.extend_from_slice(bytes);
+        self
+    }
+
+    pub fn build(self) -> Vec<u8> {
+        self.data
+    }
+
+    fn value<T: Serialize>(mut self, value: &T) -> Self {
+        self.data.extend(value.to_bytes());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_chain_config::fee_collection_contract::{
+        script_data,
+        update_recipient_script_data,
+    };
+
+    #[test]
+    fn builder_matches_the_fee_collection_script_data() {
+        let asset_id = AssetId::new([1; 32]);
+        let contract_id = ContractId::new([2; 32]);
+        let output_index = 1;
+
+        let builder = ScriptDataBuilder::new()
+            .asset_id(asset_id)
+            .u64(output_index);
+        assert_eq!(builder.len(), AssetId::LEN + core::mem::size_of::<Word>());
+        let data = builder.call(CallStructure::new(contract_id, 0, 0)).build();
+
+        assert_eq!(data, script_data(&asset_id, output_index, &contract_id));
+    }
+
+    #[test]
+    fn builder_matches_the_update_recipient_script_data() {
+        let new_recipient = Address::new([1; 32]);
+        let contract_id = ContractId::new([2; 32]);
+
+        let data = ScriptDataBuilder::new()
+            .address(new_recipient)
+            .call(CallStructure::new(contract_id, 1, 0))
+            .build();
+
+        assert_eq!(
+            data,
+            update_recipient_script_data(&new_recipient, &contract_id)
+        );
+    }
+}
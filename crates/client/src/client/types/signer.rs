@@ -0,0 +1,114 @@
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        PublicKey,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Witnesses,
+        },
+        Buildable,
+        Finalizable,
+        Input,
+        TransactionBuilder,
+        UniqueIdentifier,
+        Witness,
+    },
+    fuel_types::ChainId,
+};
+
+/// Signs the transactions without exposing the secret key, so the key may be kept
+/// outside of the process, for example, in an HSM or a KMS.
+#[async_trait::async_trait]
+pub trait Signer: Send + Sync {
+    /// The public key of the signer. The inputs owned by its address are signed.
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs the `message` with the secret key of the signer.
+    async fn sign(&self, message: Message) -> Signature;
+}
+
+/// The [`Signer`] holding the secret key in memory.
+#[derive(Debug, Clone)]
+pub struct SecretKeySigner(SecretKey);
+
+impl SecretKeySigner {
+    pub fn new(secret_key: SecretKey) -> Self {
+        Self(secret_key)
+    }
+}
+
+impl From<SecretKey> for SecretKeySigner {
+    fn from(secret_key: SecretKey) -> Self {
+        Self::new(secret_key)
+    }
+}
+
+#[async_trait::async_trait]
+impl Signer for SecretKeySigner {
+    fn public_key(&self) -> PublicKey {
+        self.0.public_key()
+    }
+
+    async fn sign(&self, message: Message) -> Signature {
+        Signature::sign(&self.0, &message)
+    }
+}
+
+/// Finalizes the transaction signed by the [`Signer`].
+#[async_trait::async_trait]
+pub trait FinalizeSigned<Tx> {
+    /// Finalizes the transaction and signs its id with the `signer`. The signature is
+    /// placed into the witnesses referenced by the signed inputs owned by the address
+    /// of the `signer`, so such inputs should be added with `Input::coin_signed` or
+    /// `Input::message_coin_signed` instead of `add_unsigned_coin_input`.
+    ///
+    /// The inputs added with the secret keys of the builder are signed as before.
+    async fn finalize_signed<S>(&self, signer: &S, chain_id: &ChainId) -> Tx
+    where
+        S: Signer + ?Sized;
+}
+
+#[async_trait::async_trait]
+impl<Tx> FinalizeSigned<Tx> for TransactionBuilder<Tx>
+where
+    Tx: Buildable + UniqueIdentifier + Send,
+    Self: Finalizable<Tx> + Sync,
+{
+    async fn finalize_signed<S>(&self, signer: &S, chain_id: &ChainId) -> Tx
+    where
+        S: Signer + ?Sized,
+    {
+        let mut tx = self.finalize();
+        let owner = Input::owner(&signer.public_key());
+        let mut witness_indexes: Vec<_> = tx
+            .inputs()
+            .iter()
+            .filter(|input| input.input_owner() == Some(&owner))
+            .filter_map(|input| input.witness_index())
+            .collect();
+        witness_indexes.sort_unstable();
+        witness_indexes.dedup();
+
+        let last_index = match witness_indexes.last() {
+            Some(index) => *index as usize,
+            None => return tx,
+        };
+
+        let message = Message::from_bytes(*tx.id(chain_id));
+        let signature = signer.sign(message).await;
+
+        let witnesses = tx.witnesses_mut();
+        if witnesses.len() <= last_index {
+            witnesses.resize(last_index.saturating_add(1), Witness::default());
+        }
+        for index in witness_indexes {
+            witnesses[index as usize] = signature.as_ref().to_vec().into();
+        }
+
+        tx
+    }
+}
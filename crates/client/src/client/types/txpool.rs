@@ -0,0 +1,118 @@
+use crate::client::{
+    schema,
+    types::Coinbase,
+};
+use fuel_core_types::fuel_types::{
+    AssetId,
+    Word,
+};
+
+/// The size and the composition of the `TxPool`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxpoolStats {
+    /// The number of the transactions in the pool.
+    pub tx_count: u64,
+    /// The max gas of all transactions in the pool combined.
+    pub total_gas: Word,
+    /// The amounts per asset that the next block would credit to the coinbase
+    /// recipient if it was produced now, or `None` if the node is not in the
+    /// `debug` mode.
+    pub estimated_coinbase: Option<Vec<(AssetId, Word)>>,
+}
+
+/// Why the transaction was squeezed out of the `TxPool`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SqueezedOutKind {
+    /// Evicted from the full pool by the transaction paying the higher gas price.
+    Evicted,
+    /// Replaced by the transaction spending the same inputs, removed because its
+    /// dependency was invalidated, or removed on demand.
+    Removed,
+}
+
+/// The policy of resubmitting the transaction squeezed out of the full `TxPool`
+/// by the transactions paying the higher gas price.
+///
+/// Only the transactions evicted from the full pool, with the
+/// [`SqueezedOutKind::Evicted`], are resubmitted. The transactions
+/// replaced by the transaction spending the same inputs, or removed because their
+/// dependency was invalidated, are returned as they are, because the resubmission
+/// would either squeeze out the replacement or be rejected.
+///
+/// The transaction with the zero gas price is never resubmitted. Such transactions,
+/// like the fee withdrawals on the permissioned networks with the zero minimal gas
+/// price, often have no coins to pay the fee, so the bumped price would only make
+/// them invalid. Squeezing them out means the pool is full of the paying
+/// transactions, so they should be submitted again once the pool is drained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResubmitPolicy {
+    /// The increase of the gas price on each resubmission.
+    pub gas_price_step: Word,
+    /// The highest gas price the transaction is resubmitted with.
+    pub max_gas_price: Word,
+}
+
+impl ResubmitPolicy {
+    /// The gas price to resubmit the transaction squeezed out with the `gas_price`,
+    /// or `None` if it shouldn't be resubmitted.
+    pub fn next_gas_price(&self, gas_price: Word) -> Option<Word> {
+        if gas_price == 0 || self.gas_price_step == 0 {
+            return None
+        }
+        gas_price
+            .checked_add(self.gas_price_step)
+            .filter(|next| *next <= self.max_gas_price)
+    }
+}
+
+// GraphQL Translation
+
+impl From<schema::tx::TxPoolStats> for TxpoolStats {
+    fn from(value: schema::tx::TxPoolStats) -> Self {
+        Self {
+            tx_count: value.tx_count.into(),
+            total_gas: value.total_gas.into(),
+            estimated_coinbase: value.estimated_coinbase.map(|coinbase| {
+                let coinbase: Coinbase = coinbase.into();
+                coinbase.amounts
+            }),
+        }
+    }
+}
+
+impl From<schema::tx::SqueezedOutKind> for SqueezedOutKind {
+    fn from(value: schema::tx::SqueezedOutKind) -> Self {
+        match value {
+            schema::tx::SqueezedOutKind::Evicted => Self::Evicted,
+            schema::tx::SqueezedOutKind::Removed => Self::Removed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_gas_price_is_bumped_up_to_the_max_gas_price() {
+        let policy = ResubmitPolicy {
+            gas_price_step: 2,
+            max_gas_price: 5,
+        };
+
+        assert_eq!(policy.next_gas_price(1), Some(3));
+        assert_eq!(policy.next_gas_price(3), Some(5));
+        assert_eq!(policy.next_gas_price(4), None);
+        assert_eq!(policy.next_gas_price(Word::MAX), None);
+    }
+
+    #[test]
+    fn next_gas_price_is_none_for_the_zero_gas_price() {
+        let policy = ResubmitPolicy {
+            gas_price_step: 2,
+            max_gas_price: 5,
+        };
+
+        assert_eq!(policy.next_gas_price(0), None);
+    }
+}
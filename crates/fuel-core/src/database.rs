@@ -55,8 +55,12 @@ use tempfile::TempDir;
 
 // Storages implementation
 // TODO: Move to separate `database/storage` folder, because it is only implementation of storages traits.
+mod asset_supply;
 mod block;
+mod block_gas_limits;
 mod code_root;
+mod coinbase_credits;
+mod contract_balance_history;
 mod contracts;
 mod message;
 mod receipts;
@@ -133,6 +137,16 @@ pub enum Column {
     ContractsStateMerkleData = 23,
     /// See [`ContractsStateMerkleMetadata`](storage::ContractsStateMerkleMetadata)
     ContractsStateMerkleMetadata = 24,
+    /// The column of the table of the minted and burned amounts of the asset per block
+    AssetSupplyDeltas = 25,
+    /// The column of the table of the balances of the contracts changed per block
+    ContractBalanceHistory = 26,
+    /// The column of the table of the total amounts minted by the coinbase per asset
+    CoinbaseMinted = 27,
+    /// The column of the table of the coinbase credited to the recipient per block
+    CoinbaseCredits = 28,
+    /// The column of the table of the gas limits of the blocks produced by the node
+    BlockGasLimits = 29,
 }
 
 impl Column {
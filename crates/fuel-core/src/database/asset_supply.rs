@@ -0,0 +1,130 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        Word,
+    },
+    services::graphql_api::AssetSupplyDelta,
+};
+use std::mem::size_of;
+
+impl Database {
+    /// Iterates over a KV mapping of `[asset id + block height] => supply delta`, sorted
+    /// by the block height. Only the blocks that changed the supply of the asset are present.
+    pub fn asset_supply_history(
+        &self,
+        asset_id: &AssetId,
+        start: Option<BlockHeight>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(BlockHeight, AssetSupplyDelta)>> + '_ {
+        let start = start.map(|height| asset_supply_key(asset_id, height));
+        self.iter_all_filtered::<Vec<u8>, AssetSupplyDelta, _, _>(
+            Column::AssetSupplyDeltas,
+            Some(*asset_id),
+            start,
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, delta)| {
+                let mut height_bytes = [0u8; BLOCK_HEIGHT];
+                height_bytes.copy_from_slice(&key[AssetId::LEN..]);
+                (u32::from_be_bytes(height_bytes).into(), delta)
+            })
+        })
+    }
+
+    pub fn record_asset_supply_delta(
+        &self,
+        asset_id: &AssetId,
+        height: BlockHeight,
+        delta: &AssetSupplyDelta,
+    ) -> DatabaseResult<Option<AssetSupplyDelta>> {
+        self.insert(
+            asset_supply_key(asset_id, height),
+            Column::AssetSupplyDeltas,
+            delta,
+        )
+    }
+
+    /// Returns the total amount of the asset minted by the coinbase of all blocks.
+    /// The total isn't backfilled, so the blocks executed by the node before
+    /// the `CoinbaseMinted` column was introduced are not counted.
+    pub fn total_coinbase_minted(&self, asset_id: &AssetId) -> DatabaseResult<u128> {
+        let total = self.get(asset_id.as_ref(), Column::CoinbaseMinted)?;
+        Ok(total.unwrap_or_default())
+    }
+
+    /// Adds the `amount` minted by the coinbase of the block to the total of the asset.
+    pub fn increase_coinbase_minted(
+        &self,
+        asset_id: &AssetId,
+        amount: Word,
+    ) -> DatabaseResult<u128> {
+        let total = self
+            .total_coinbase_minted(asset_id)?
+            .saturating_add(u128::from(amount));
+        let _: Option<u128> = self.insert(asset_id, Column::CoinbaseMinted, &total)?;
+        Ok(total)
+    }
+}
+
+const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
+const KEY_SIZE: usize = AssetId::LEN + BLOCK_HEIGHT;
+
+fn asset_supply_key(asset_id: &AssetId, height: BlockHeight) -> [u8; KEY_SIZE] {
+    let mut key = [0u8; KEY_SIZE];
+    key[..AssetId::LEN].copy_from_slice(asset_id.as_ref());
+    key[AssetId::LEN..].copy_from_slice(height.to_bytes().as_ref());
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_supply_history_is_filtered_by_asset_and_height() {
+        let db = Database::default();
+        let asset_id = AssetId::new([1; 32]);
+        let other_asset_id = AssetId::new([2; 32]);
+        let delta = |minted, burned| AssetSupplyDelta { minted, burned };
+
+        db.record_asset_supply_delta(&asset_id, 1.into(), &delta(100, 0))
+            .unwrap();
+        db.record_asset_supply_delta(&asset_id, 3.into(), &delta(50, 30))
+            .unwrap();
+        db.record_asset_supply_delta(&other_asset_id, 2.into(), &delta(7, 0))
+            .unwrap();
+
+        let history = db
+            .asset_supply_history(&asset_id, Some(2.into()), None)
+            .collect::<DatabaseResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(history, vec![(3.into(), delta(50, 30))]);
+    }
+
+    #[test]
+    fn increase_coinbase_minted_accumulates_the_total_per_asset() {
+        let db = Database::default();
+        let asset_id = AssetId::new([1; 32]);
+        let other_asset_id = AssetId::new([2; 32]);
+
+        assert_eq!(db.total_coinbase_minted(&asset_id).unwrap(), 0);
+        db.increase_coinbase_minted(&asset_id, Word::MAX).unwrap();
+        db.increase_coinbase_minted(&asset_id, 10).unwrap();
+        db.increase_coinbase_minted(&other_asset_id, 7).unwrap();
+
+        assert_eq!(
+            db.total_coinbase_minted(&asset_id).unwrap(),
+            u128::from(Word::MAX).saturating_add(10)
+        );
+        assert_eq!(db.total_coinbase_minted(&other_asset_id).unwrap(), 7);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_types::fuel_types::{
+    BlockHeight,
+    Word,
+};
+
+impl Database {
+    /// Returns the gas limit used to produce the block at the `height`. Only the blocks
+    /// produced by this node are present.
+    pub fn block_gas_limit(&self, height: &BlockHeight) -> DatabaseResult<Option<Word>> {
+        self.get(height.to_bytes().as_ref(), Column::BlockGasLimits)
+    }
+
+    pub fn record_block_gas_limit(
+        &self,
+        height: &BlockHeight,
+        gas_limit: Word,
+    ) -> DatabaseResult<Option<Word>> {
+        self.insert(height.to_bytes(), Column::BlockGasLimits, &gas_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_gas_limit_is_stored_per_height() {
+        let db = Database::default();
+
+        db.record_block_gas_limit(&1.into(), 100).unwrap();
+        db.record_block_gas_limit(&2.into(), 50).unwrap();
+
+        assert_eq!(db.block_gas_limit(&1.into()).unwrap(), Some(100));
+        assert_eq!(db.block_gas_limit(&2.into()).unwrap(), Some(50));
+        assert_eq!(db.block_gas_limit(&3.into()).unwrap(), None);
+    }
+}
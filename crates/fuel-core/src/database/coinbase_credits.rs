@@ -0,0 +1,101 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
+    services::graphql_api::CoinbaseCredit,
+};
+use std::mem::size_of;
+
+impl Database {
+    /// Iterates over a KV mapping of `[recipient + block height] => coinbase credit`,
+    /// sorted by the block height. Only the blocks that credited a non-zero amount
+    /// to the `recipient` are present.
+    pub fn coinbase_credits(
+        &self,
+        recipient: &ContractId,
+        start: Option<BlockHeight>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(BlockHeight, CoinbaseCredit)>> + '_ {
+        let start = start.map(|height| coinbase_credit_key(recipient, height));
+        self.iter_all_filtered::<Vec<u8>, CoinbaseCredit, _, _>(
+            Column::CoinbaseCredits,
+            Some(*recipient),
+            start,
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, credit)| {
+                let mut height_bytes = [0u8; BLOCK_HEIGHT];
+                height_bytes.copy_from_slice(&key[ContractId::LEN..]);
+                (u32::from_be_bytes(height_bytes).into(), credit)
+            })
+        })
+    }
+
+    pub fn record_coinbase_credit(
+        &self,
+        recipient: &ContractId,
+        height: BlockHeight,
+        credit: &CoinbaseCredit,
+    ) -> DatabaseResult<Option<CoinbaseCredit>> {
+        self.insert(
+            coinbase_credit_key(recipient, height),
+            Column::CoinbaseCredits,
+            credit,
+        )
+    }
+}
+
+const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
+const KEY_SIZE: usize = ContractId::LEN + BLOCK_HEIGHT;
+
+fn coinbase_credit_key(recipient: &ContractId, height: BlockHeight) -> [u8; KEY_SIZE] {
+    let mut key = [0u8; KEY_SIZE];
+    key[..ContractId::LEN].copy_from_slice(recipient.as_ref());
+    key[ContractId::LEN..].copy_from_slice(height.to_bytes().as_ref());
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_types::AssetId;
+
+    #[test]
+    fn coinbase_credits_are_filtered_by_recipient_and_height() {
+        let db = Database::default();
+        let recipient = ContractId::new([1; 32]);
+        let other_recipient = ContractId::new([2; 32]);
+        let credit = |amount, accrued| CoinbaseCredit {
+            asset_id: AssetId::BASE,
+            amount,
+            accrued,
+        };
+
+        db.record_coinbase_credit(&recipient, 1.into(), &credit(10, 10))
+            .unwrap();
+        db.record_coinbase_credit(&recipient, 3.into(), &credit(5, 15))
+            .unwrap();
+        db.record_coinbase_credit(&other_recipient, 2.into(), &credit(7, 7))
+            .unwrap();
+
+        let forward = db
+            .coinbase_credits(&recipient, Some(2.into()), None)
+            .collect::<DatabaseResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(forward, vec![(3.into(), credit(5, 15))]);
+
+        let reverse = db
+            .coinbase_credits(&recipient, Some(2.into()), Some(IterDirection::Reverse))
+            .collect::<DatabaseResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(reverse, vec![(1.into(), credit(10, 10))]);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        ContractId,
+    },
+    services::graphql_api::ContractBalanceUpdate,
+};
+use std::mem::size_of;
+
+impl Database {
+    /// Iterates over a KV mapping of `[contract id + asset id + block height] => balance update`,
+    /// sorted by the block height. Only the blocks that changed the balance are present.
+    pub fn contract_balance_history(
+        &self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+        start: Option<BlockHeight>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(BlockHeight, ContractBalanceUpdate)>> + '_
+    {
+        let prefix = balance_prefix(contract_id, asset_id);
+        let start =
+            start.map(|height| contract_balance_key(contract_id, asset_id, height));
+        self.iter_all_filtered::<Vec<u8>, ContractBalanceUpdate, _, _>(
+            Column::ContractBalanceHistory,
+            Some(prefix),
+            start,
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, update)| {
+                let mut height_bytes = [0u8; BLOCK_HEIGHT];
+                height_bytes.copy_from_slice(&key[PREFIX_SIZE..]);
+                (u32::from_be_bytes(height_bytes).into(), update)
+            })
+        })
+    }
+
+    pub fn record_contract_balance_update(
+        &self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+        height: BlockHeight,
+        update: &ContractBalanceUpdate,
+    ) -> DatabaseResult<Option<ContractBalanceUpdate>> {
+        self.insert(
+            contract_balance_key(contract_id, asset_id, height),
+            Column::ContractBalanceHistory,
+            update,
+        )
+    }
+}
+
+const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
+const PREFIX_SIZE: usize = ContractId::LEN + AssetId::LEN;
+const KEY_SIZE: usize = PREFIX_SIZE + BLOCK_HEIGHT;
+
+fn balance_prefix(contract_id: &ContractId, asset_id: &AssetId) -> [u8; PREFIX_SIZE] {
+    let mut prefix = [0u8; PREFIX_SIZE];
+    prefix[..ContractId::LEN].copy_from_slice(contract_id.as_ref());
+    prefix[ContractId::LEN..].copy_from_slice(asset_id.as_ref());
+    prefix
+}
+
+fn contract_balance_key(
+    contract_id: &ContractId,
+    asset_id: &AssetId,
+    height: BlockHeight,
+) -> [u8; KEY_SIZE] {
+    let mut key = [0u8; KEY_SIZE];
+    key[..PREFIX_SIZE].copy_from_slice(&balance_prefix(contract_id, asset_id));
+    key[PREFIX_SIZE..].copy_from_slice(height.to_bytes().as_ref());
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contract_balance_history_is_filtered_by_contract_asset_and_height() {
+        let db = Database::default();
+        let contract_id = ContractId::new([1; 32]);
+        let other_contract_id = ContractId::new([2; 32]);
+        let asset_id = AssetId::new([3; 32]);
+        let other_asset_id = AssetId::new([4; 32]);
+        let update = |old, new| ContractBalanceUpdate { old, new };
+
+        db.record_contract_balance_update(
+            &contract_id,
+            &asset_id,
+            1.into(),
+            &update(0, 10),
+        )
+        .unwrap();
+        db.record_contract_balance_update(
+            &contract_id,
+            &asset_id,
+            3.into(),
+            &update(10, 5),
+        )
+        .unwrap();
+        db.record_contract_balance_update(
+            &contract_id,
+            &other_asset_id,
+            2.into(),
+            &update(0, 7),
+        )
+        .unwrap();
+        db.record_contract_balance_update(
+            &other_contract_id,
+            &asset_id,
+            2.into(),
+            &update(0, 9),
+        )
+        .unwrap();
+
+        let forward = db
+            .contract_balance_history(&contract_id, &asset_id, Some(2.into()), None)
+            .collect::<DatabaseResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(forward, vec![(3.into(), update(10, 5))]);
+
+        let reverse = db
+            .contract_balance_history(
+                &contract_id,
+                &asset_id,
+                Some(2.into()),
+                Some(IterDirection::Reverse),
+            )
+            .collect::<DatabaseResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(reverse, vec![(1.into(), update(0, 10))]);
+    }
+}
@@ -14,6 +14,7 @@ use fuel_core_storage::{
         ContractsRawCode,
     },
     ContractsAssetKey,
+    ContractsStateKey,
     Error as StorageError,
     Mappable,
     Result as StorageResult,
@@ -245,6 +246,55 @@ impl Database {
         })
     }
 
+    /// Iterates over the balances of all contracts ordered by the contract id
+    /// and then by the asset id.
+    pub fn all_contract_balances(
+        &self,
+        start: Option<(ContractId, AssetId)>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(ContractId, AssetId, Word)>> + '_ {
+        self.iter_all_filtered::<Vec<u8>, Word, Vec<u8>, _>(
+            Column::ContractsAssets,
+            None,
+            start
+                .map(|(contract, asset_id)| ContractsAssetKey::new(&contract, &asset_id)),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, balance)| {
+                (
+                    ContractId::new(key[..32].try_into().unwrap()),
+                    AssetId::new(key[32..].try_into().unwrap()),
+                    balance,
+                )
+            })
+        })
+    }
+
+    /// Iterates over the ids of all deployed contracts ordered by the id.
+    pub fn contract_ids(&self) -> impl Iterator<Item = DatabaseResult<ContractId>> + '_ {
+        // The bytecode is not decoded, only the keys are used.
+        self.iter_all::<Vec<u8>, ()>(Column::ContractsRawCode, None)
+            .map(|res| res.map(|(key, _)| ContractId::new(key[..32].try_into().unwrap())))
+    }
+
+    pub fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(Bytes32, Bytes32)>> + '_ {
+        self.iter_all_filtered::<Vec<u8>, Bytes32, _, _>(
+            Column::ContractsState,
+            Some(contract),
+            start_key.map(|key| ContractsStateKey::new(&contract, &key)),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, value)| (Bytes32::new(key[32..].try_into().unwrap()), value))
+        })
+    }
+
     pub fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
         let configs = self
             .iter_all::<Vec<u8>, Word>(Column::ContractsRawCode, None)
@@ -9,6 +9,7 @@ use crate::database::{
     Database,
 };
 use fuel_core_storage::{
+    not_found,
     tables::ContractsState,
     ContractsStateKey,
     Error as StorageError,
@@ -40,6 +41,10 @@ use std::{
         BorrowMut,
         Cow,
     },
+    collections::{
+        HashMap,
+        HashSet,
+    },
     ops::Deref,
 };
 
@@ -201,6 +206,112 @@ impl Database {
 
         Ok(())
     }
+
+    /// Removes the nodes of the contracts' state sparse Merkle trees that are not
+    /// reachable from any contract state root anymore. The roots are not affected.
+    ///
+    /// Returns the number of removed nodes.
+    pub fn compact_contracts_state(&mut self) -> Result<usize, StorageError> {
+        let mut reachability = self.contracts_state_reachability()?;
+        let orphans = self.contracts_state_orphans(&reachability)?;
+        self.remove_contracts_state_orphans(&mut reachability, &orphans)
+    }
+
+    /// Collects the nodes reachable from the current contract state roots.
+    pub fn contracts_state_reachability(
+        &self,
+    ) -> Result<ContractsStateReachability, StorageError> {
+        let mut reachability = ContractsStateReachability::default();
+        self.update_contracts_state_reachability(&mut reachability)?;
+        Ok(reachability)
+    }
+
+    /// Returns the nodes of the contracts' state trees missing in the `reachability`.
+    pub fn contracts_state_orphans(
+        &self,
+        reachability: &ContractsStateReachability,
+    ) -> Result<Vec<Vec<u8>>, StorageError> {
+        self.iter_all::<Vec<u8>, sparse::Primitive>(
+            ContractsStateMerkleData::column(),
+            None,
+        )
+        .map_ok(|(key, _)| key)
+        .filter_ok(|key| !reachability.reachable.contains(key.as_slice()))
+        .try_collect()
+    }
+
+    /// Removes the `orphans` that are still unreachable from the current roots.
+    ///
+    /// The blocks committed after the `reachability` was collected may reference
+    /// the orphans again, so the `reachability` is updated with the changed roots
+    /// first. The caller should prevent the commits during this call.
+    ///
+    /// Returns the number of removed nodes.
+    pub fn remove_contracts_state_orphans(
+        &mut self,
+        reachability: &mut ContractsStateReachability,
+        orphans: &[Vec<u8>],
+    ) -> Result<usize, StorageError> {
+        self.update_contracts_state_reachability(reachability)?;
+
+        let mut removed = 0usize;
+        for key in orphans.iter() {
+            if !reachability.reachable.contains(key.as_slice()) {
+                self.take(key, ContractsStateMerkleData::column())?;
+                removed = removed.saturating_add(1);
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Adds the nodes reachable from the roots changed since the last update.
+    /// The subtrees of the already reachable nodes are not visited again.
+    fn update_contracts_state_reachability(
+        &self,
+        reachability: &mut ContractsStateReachability,
+    ) -> Result<(), StorageError> {
+        let roots = self
+            .iter_all::<Vec<u8>, SparseMerkleMetadata>(
+                ContractsStateMerkleMetadata::column(),
+                None,
+            )
+            .map_ok(|(contract, metadata)| (contract, metadata.root))
+            .try_collect::<_, HashMap<_, _>, _>()?;
+
+        let mut pending = roots
+            .iter()
+            .filter(|(contract, root)| reachability.roots.get(*contract) != Some(*root))
+            .map(|(_, root)| *root)
+            .collect_vec();
+        reachability.roots = roots;
+
+        while let Some(key) = pending.pop() {
+            if key == *sparse::empty_sum() || !reachability.reachable.insert(key) {
+                continue
+            }
+
+            let node = self
+                .storage::<ContractsStateMerkleData>()
+                .get(&key)?
+                .ok_or(not_found!(ContractsStateMerkleData))?;
+            let (height, _, left, right) = node.into_owned();
+            // Leaves are stored at the height zero and don't have children.
+            if height > 0 {
+                pending.push(left);
+                pending.push(right);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The nodes of the contracts' state trees reachable from the roots,
+/// collected by the [`Database::contracts_state_reachability`].
+#[derive(Default)]
+pub struct ContractsStateReachability {
+    roots: HashMap<Vec<u8>, MerkleRoot>,
+    reachable: HashSet<MerkleRoot>,
 }
 
 #[cfg(test)]
@@ -519,4 +630,104 @@ mod tests {
 
         assert!(metadata.is_none());
     }
+
+    #[test]
+    fn compact_contracts_state_removes_orphans_and_keeps_roots() {
+        let rng = &mut rand::thread_rng();
+        let contract_id = ContractId::from([1u8; 32]);
+        let database = &mut Database::default();
+        let count_nodes = |database: &Database| {
+            database
+                .iter_all::<Vec<u8>, sparse::Primitive>(
+                    ContractsStateMerkleData::column(),
+                    None,
+                )
+                .count()
+        };
+
+        // Churn the contract storage: write slots, overwrite them and clear half of them.
+        let state_keys: Vec<Bytes32> = (0..16).map(|_| random_bytes32(rng)).collect();
+        for state_key in state_keys.iter() {
+            let key = (&contract_id, state_key).into();
+            database
+                .storage::<ContractsState>()
+                .insert(&key, &random_bytes32(rng))
+                .unwrap();
+            database
+                .storage::<ContractsState>()
+                .insert(&key, &random_bytes32(rng))
+                .unwrap();
+        }
+        for state_key in state_keys.iter().step_by(2) {
+            let key = (&contract_id, state_key).into();
+            database.storage::<ContractsState>().remove(&key).unwrap();
+        }
+
+        let root_before = database
+            .storage::<ContractsState>()
+            .root(&contract_id)
+            .unwrap();
+        let nodes_before = count_nodes(database);
+
+        let removed = database.compact_contracts_state().unwrap();
+
+        let root_after = database
+            .storage::<ContractsState>()
+            .root(&contract_id)
+            .unwrap();
+        let nodes_after = count_nodes(database);
+        assert_eq!(root_before, root_after);
+        assert!(removed > 0);
+        assert_eq!(nodes_after, nodes_before - removed);
+
+        // The tree is still complete: updating a slot and removing the new slot
+        // again returns the same root.
+        let key = (&contract_id, &random_bytes32(rng)).into();
+        database
+            .storage::<ContractsState>()
+            .insert(&key, &random_bytes32(rng))
+            .unwrap();
+        database.storage::<ContractsState>().remove(&key).unwrap();
+        assert_eq!(
+            database
+                .storage::<ContractsState>()
+                .root(&contract_id)
+                .unwrap(),
+            root_after
+        );
+    }
+
+    #[test]
+    fn remove_contracts_state_orphans_keeps_nodes_referenced_again() {
+        let rng = &mut rand::thread_rng();
+        let contract_id = ContractId::from([1u8; 32]);
+        let database = &mut Database::default();
+        let key = (&contract_id, &random_bytes32(rng)).into();
+        let old_value = random_bytes32(rng);
+        database
+            .storage::<ContractsState>()
+            .insert(&key, &old_value)
+            .unwrap();
+        database
+            .storage::<ContractsState>()
+            .insert(&key, &random_bytes32(rng))
+            .unwrap();
+
+        let mut reachability = database.contracts_state_reachability().unwrap();
+        let orphans = database.contracts_state_orphans(&reachability).unwrap();
+        assert!(!orphans.is_empty());
+
+        // The commit after the scan makes the orphaned nodes reachable again.
+        database
+            .storage::<ContractsState>()
+            .insert(&key, &old_value)
+            .unwrap();
+        let removed = database
+            .remove_contracts_state_orphans(&mut reachability, &orphans)
+            .unwrap();
+
+        assert!(removed < orphans.len());
+        // All nodes reachable from the roots are still present.
+        database.contracts_state_reachability().unwrap();
+    }
 }
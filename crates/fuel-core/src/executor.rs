@@ -6,13 +6,17 @@ use crate::database::{
 };
 use fuel_core_executor::{
     refs::ContractRef,
+    CoinbaseOverflowPolicy,
     Config,
 };
 use fuel_core_storage::{
+    iter::IterDirection,
     tables::{
         Coins,
+        ContractsAssets,
         ContractsInfo,
         ContractsLatestUtxo,
+        ContractsRawCode,
         FuelBlocks,
         Messages,
         Receipts,
@@ -23,6 +27,7 @@ use fuel_core_storage::{
         StorageTransaction,
         Transaction as StorageTransactionTrait,
     },
+    ContractsAssetKey,
     StorageAsMut,
     StorageAsRef,
     StorageInspect,
@@ -50,6 +55,7 @@ use fuel_core_types::{
         field::{
             Inputs,
             Outputs,
+            ReceiptsRoot,
             TxPointer as TxPointerField,
         },
         input::{
@@ -69,10 +75,13 @@ use fuel_core_types::{
         AssetId,
         Bytes32,
         Cacheable,
+        Create,
         Input,
         Mint,
         Output,
         Receipt,
+        Script,
+        ScriptExecutionResult,
         Transaction,
         TransactionFee,
         TxId,
@@ -98,6 +107,7 @@ use fuel_core_types::{
             IntoChecked,
             ScriptCheckedMetadata,
         },
+        crypto::ephemeral_merkle_root,
         interpreter::{
             CheckedMetadata,
             ExecutableTransaction,
@@ -111,6 +121,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            ContractBalanceChange,
             Error as ExecutorError,
             ExecutionKind,
             ExecutionResult,
@@ -122,6 +133,11 @@ use fuel_core_types::{
             TransactionValidityError,
             UncommittedResult,
         },
+        graphql_api::{
+            AssetSupplyDelta,
+            CoinbaseCredit,
+            ContractBalanceUpdate,
+        },
         txpool::TransactionStatus,
     },
 };
@@ -142,6 +158,10 @@ use fuel_core_types::{
 use parking_lot::Mutex as ParkingMutex;
 use std::{
     borrow::Cow,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
     ops::{
         Deref,
         DerefMut,
@@ -150,6 +170,7 @@ use std::{
 };
 use tracing::{
     debug,
+    error,
     warn,
 };
 
@@ -161,6 +182,11 @@ pub use ports::{
     TransactionsSource,
 };
 
+/// The log target of the coinbase decisions of the produced blocks: the fee,
+/// the recipient, the asset, and whether the coinbase was credited. Enable it with
+/// `RUST_LOG=fuel-coinbase=debug` to diagnose the balance of the recipient.
+pub const COINBASE_LOG_TARGET: &str = "fuel-coinbase";
+
 pub type ExecutionBlockWithSource<TxSource> = ExecutionTypes<Components<TxSource>, Block>;
 
 pub struct OnceTransactionsSource {
@@ -404,6 +430,12 @@ where
         // a partial header.
         let block = block.map_v(PartialFuelBlock::from);
 
+        // Only the produced blocks have the gas limit chosen by this node.
+        let production_gas_limit = match &block {
+            ExecutionTypes::Production(component) => Some(component.gas_limit),
+            _ => None,
+        };
+
         // Create a new database transaction.
         let mut block_db_transaction = database.transaction();
 
@@ -481,10 +513,18 @@ where
             }
         }
 
+        let contract_balance_changes = self.contract_balance_changes_for_block(
+            &block,
+            &tx_status,
+            database,
+            block_db_transaction.deref(),
+        )?;
+
         let result = ExecutionResult {
             block,
             skipped_transactions,
             tx_status,
+            contract_balance_changes,
         };
 
         // ------------ GraphQL API Functionality BEGIN ------------
@@ -495,6 +535,25 @@ where
         // save the associated owner for each transaction in the block
         self.index_tx_owners_for_block(&result.block, &mut block_db_transaction)?;
 
+        // save the amounts of the assets minted and burned in the block
+        self.index_asset_supply_for_block(&result, block_db_transaction.deref_mut())?;
+
+        // save the balances of the contracts changed by the block
+        self.index_contract_balance_changes(&result, block_db_transaction.deref_mut())?;
+
+        // add the amount minted by the coinbase to the running total of the asset
+        self.index_coinbase_minted_for_block(&result, block_db_transaction.deref_mut())?;
+
+        // save the coinbase credited to the recipient along with its running total
+        self.index_coinbase_credit_for_block(&result, block_db_transaction.deref_mut())?;
+
+        // save the gas limit of the produced block to replay it later
+        if let Some(gas_limit) = production_gas_limit {
+            block_db_transaction
+                .deref_mut()
+                .record_block_gas_limit(result.block.header().height(), gas_limit)?;
+        }
+
         // ------------ GraphQL API Functionality   END ------------
 
         // insert block into database
@@ -614,8 +673,16 @@ where
 
         // After the execution of all transactions in production mode, we can set the final fee.
         if execution_kind == ExecutionKind::Production {
-            let amount_to_mint = if self.config.coinbase_recipient != ContractId::zeroed()
-            {
+            // The recipient is checked after the transactions of the block, so the
+            // contract deployed by the same block already receives the fee.
+            let coinbase_recipient =
+                self.coinbase_recipient(block_db_transaction.deref())?;
+            let coinbase_recipient = self.coinbase_recipient_for_fee(
+                block_db_transaction.deref(),
+                coinbase_recipient,
+                execution_data.coinbase,
+            )?;
+            let amount_to_mint = if coinbase_recipient != ContractId::zeroed() {
                 execution_data.coinbase
             } else {
                 0
@@ -628,7 +695,7 @@ where
                     balance_root: Bytes32::zeroed(),
                     state_root: Bytes32::zeroed(),
                     tx_pointer: TxPointer::new(BlockHeight::new(0), 0),
-                    contract_id: self.config.coinbase_recipient,
+                    contract_id: coinbase_recipient,
                 },
                 output::contract::Contract {
                     input_index: 0,
@@ -638,11 +705,53 @@ where
                 amount_to_mint,
                 self.config.consensus_parameters.base_asset_id,
             );
+            let coinbase_id = coinbase_tx.id(&self.config.consensus_parameters.chain_id);
+            let span = tracing::debug_span!(
+                target: COINBASE_LOG_TARGET,
+                "coinbase",
+                height = *block_height,
+                fee = execution_data.coinbase,
+                recipient = ?coinbase_recipient,
+                asset_id = ?self.config.consensus_parameters.base_asset_id,
+            );
+            let _entered = span.enter();
+            if coinbase_recipient == ContractId::zeroed() {
+                debug!(
+                    target: COINBASE_LOG_TARGET,
+                    "No coinbase recipient, the fee of the block is not minted"
+                );
+            }
 
             execute_transaction(
                 execution_data,
                 MaybeCheckedTransaction::Transaction(coinbase_tx.into()),
             )?;
+
+            let credited = !execution_data
+                .skipped_transactions
+                .iter()
+                .any(|(tx_id, _)| *tx_id == coinbase_id);
+            debug!(
+                target: COINBASE_LOG_TARGET,
+                amount = amount_to_mint,
+                credited,
+                "Executed the coinbase of the block"
+            );
+
+            if self.config.require_coinbase_success {
+                let failed_mint = execution_data
+                    .skipped_transactions
+                    .iter()
+                    .position(|(tx_id, _)| *tx_id == coinbase_id);
+                if let Some(position) = failed_mint {
+                    let (_, err) = execution_data.skipped_transactions.remove(position);
+                    error!(
+                        "Failed to credit the coinbase to {:#x}: {}",
+                        coinbase_recipient, err
+                    );
+                    return Err(err)
+                }
+            }
         }
 
         if execution_kind != ExecutionKind::DryRun && !data.found_mint {
@@ -652,6 +761,80 @@ where
         Ok(data)
     }
 
+    /// Returns the recipient of the coinbase of the produced block. If the configured
+    /// recipient is not a deployed contract, the fee is not minted, or the production
+    /// fails when the `fail_on_missing_coinbase_recipient` is set. The
+    /// `require_coinbase_success` doesn't apply here, because the mint transaction
+    /// to the zeroed recipient doesn't fail.
+    fn coinbase_recipient(&self, db: &Database) -> ExecutorResult<ContractId> {
+        let recipient = self.config.coinbase_recipient;
+        if recipient == ContractId::zeroed()
+            || db.storage::<ContractsRawCode>().contains_key(&recipient)?
+        {
+            return Ok(recipient)
+        }
+
+        if self.config.fail_on_missing_coinbase_recipient {
+            error!(
+                "The coinbase recipient {:#x} is not a deployed contract",
+                recipient
+            );
+            return Err(ExecutorError::CoinbaseRecipientNotFound(recipient))
+        }
+        warn!(
+            "The coinbase recipient {:#x} is not a deployed contract, \
+            the fee of the block is not minted",
+            recipient
+        );
+        Ok(ContractId::zeroed())
+    }
+
+    /// Returns the recipient that the `fee` of the produced block is credited to.
+    /// If crediting the `fee` would overflow the balance of the `recipient`, the fee
+    /// is burned or the production fails according to the `coinbase_overflow_policy`.
+    /// With the `Skip` policy, the `recipient` is kept, and the mint transaction fails
+    /// like before.
+    fn coinbase_recipient_for_fee(
+        &self,
+        db: &Database,
+        recipient: ContractId,
+        fee: Word,
+    ) -> ExecutorResult<ContractId> {
+        if recipient == ContractId::zeroed() {
+            return Ok(recipient)
+        }
+
+        let asset_id = self.config.consensus_parameters.base_asset_id;
+        let balance = db
+            .storage::<ContractsAssets>()
+            .get(&ContractsAssetKey::new(&recipient, &asset_id))?
+            .map(|balance| *balance)
+            .unwrap_or_default();
+        if balance.checked_add(fee).is_some() {
+            return Ok(recipient)
+        }
+
+        match self.config.coinbase_overflow_policy {
+            CoinbaseOverflowPolicy::Skip => Ok(recipient),
+            CoinbaseOverflowPolicy::Burn => {
+                warn!(
+                    "Crediting the fee {} would overflow the balance {} of the coinbase \
+                    recipient {:#x}, the fee of the block is burned",
+                    fee, balance, recipient
+                );
+                Ok(ContractId::zeroed())
+            }
+            CoinbaseOverflowPolicy::Halt => {
+                error!(
+                    "Crediting the fee {} would overflow the balance {} of the coinbase \
+                    recipient {:#x}",
+                    fee, balance, recipient
+                );
+                Err(ExecutorError::CoinbaseBalanceOverflow(recipient))
+            }
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn execute_transaction(
         &self,
@@ -818,6 +1001,7 @@ where
                 block_db_transaction,
                 inputs.as_slice(),
                 outputs.as_slice(),
+                true,
             )?;
             self.compute_not_utxo_outputs(
                 match execution_kind {
@@ -883,7 +1067,13 @@ where
         options: ExecutionOptions,
     ) -> ExecutorResult<Transaction>
     where
-        Tx: ExecutableTransaction + PartialEq + Cacheable + Send + Sync + 'static,
+        Tx: ExecutableTransaction
+            + RevertScriptResult
+            + PartialEq
+            + Cacheable
+            + Send
+            + Sync
+            + 'static,
         <Tx as IntoChecked>::Metadata: Fee + CheckedMetadata + Clone + Send + Sync,
     {
         let tx_id = checked_tx.id();
@@ -936,15 +1126,28 @@ where
                 transaction_id: tx_id,
             })?
             .into();
-        let reverted = vm_result.should_revert();
+        let vm_reverted = vm_result.should_revert();
 
-        let (state, mut tx, receipts) = vm_result.into_inner();
+        let (state, mut tx, mut receipts) = vm_result.into_inner();
         #[cfg(debug_assertions)]
         {
             tx.precompute(&self.config.consensus_parameters.chain_id)?;
             debug_assert_eq!(tx.id(&self.config.consensus_parameters.chain_id), tx_id);
         }
 
+        // update block commitment
+        let (used_gas, tx_fee) = self.total_fee_paid(&tx, max_fee, &receipts)?;
+
+        // The amounts of the `Change` and `Variable` outputs are known only after the
+        // execution, so the limit is verified against the final outputs.
+        let exceeded_limit = self.verify_coin_outputs_amount(&tx_id, tx.outputs()).err();
+        if exceeded_limit.is_some() {
+            let inputs = tx.inputs().clone();
+            self.revert_outputs(&inputs, tx.outputs_mut(), tx_fee);
+            tx.revert_script_result(&mut receipts);
+        }
+        let reverted = vm_reverted || exceeded_limit.is_some();
+
         // Wrap inputs in the execution kind.
         self.compute_inputs(
             match execution_kind {
@@ -962,9 +1165,6 @@ where
             sub_block_db_commit.commit()?;
         }
 
-        // update block commitment
-        let (used_gas, tx_fee) = self.total_fee_paid(&tx, max_fee, &receipts)?;
-
         // Check or set the executed transaction.
         match execution_kind {
             ExecutionKind::Validation => {
@@ -991,6 +1191,7 @@ where
             tx_db_transaction.deref_mut(),
             tx.inputs(),
             tx.outputs(),
+            exceeded_limit.is_none(),
         )?;
         // TODO: Inputs, in most cases, are heavier than outputs, so cloning them, but we
         //  need to avoid cloning in the future.
@@ -1023,7 +1224,12 @@ where
         // persist receipts
         self.persist_receipts(&tx_id, &receipts, tx_db_transaction.deref_mut())?;
 
-        let status = if reverted {
+        let status = if let Some(error) = exceeded_limit {
+            TransactionExecutionResult::Failed {
+                reason: error.to_string(),
+                result: Some(state),
+            }
+        } else if reverted {
             self.log_backtrace(&vm, &receipts);
             // get reason for revert
             let reason = receipts
@@ -1066,6 +1272,90 @@ where
         Ok(final_tx)
     }
 
+    /// Verifies that coin outputs of the base asset don't exceed the configured limit.
+    /// The `Change` outputs are not verified, because the revert of the transaction
+    /// returns the spent inputs to them.
+    fn verify_coin_outputs_amount(
+        &self,
+        tx_id: &TxId,
+        outputs: &[Output],
+    ) -> Result<(), TransactionValidityError> {
+        let max_amount = match self.config.max_coin_output_amount {
+            Some(max_amount) => max_amount,
+            None => return Ok(()),
+        };
+        let base_asset_id = self.config.consensus_parameters.base_asset_id;
+
+        for output in outputs {
+            match output {
+                Output::Coin {
+                    amount, asset_id, ..
+                }
+                | Output::Variable {
+                    amount, asset_id, ..
+                } if *asset_id == base_asset_id && *amount > max_amount => {
+                    return Err(TransactionValidityError::CoinOutputAmountExceedsLimit {
+                        tx_id: *tx_id,
+                        amount: *amount,
+                        max_amount,
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reverts the outputs of the transaction that exceeded the coin output limit.
+    /// The `Coin` and `Variable` outputs are not created, and the `Change` outputs
+    /// return the amounts of the spent inputs, minus the fee for the base asset.
+    fn revert_outputs(&self, inputs: &[Input], outputs: &mut [Output], fee: Word) {
+        let base_asset_id = self.config.consensus_parameters.base_asset_id;
+
+        let mut balances = BTreeMap::<AssetId, Word>::new();
+        for input in inputs {
+            let (asset_id, amount) = match input {
+                Input::CoinSigned(CoinSigned {
+                    asset_id, amount, ..
+                })
+                | Input::CoinPredicate(CoinPredicate {
+                    asset_id, amount, ..
+                }) => (*asset_id, *amount),
+                Input::MessageCoinSigned(MessageCoinSigned { amount, .. })
+                | Input::MessageCoinPredicate(MessageCoinPredicate { amount, .. }) => {
+                    (base_asset_id, *amount)
+                }
+                // The retryable messages are not spent by the reverted transaction.
+                _ => continue,
+            };
+            let balance = balances.entry(asset_id).or_default();
+            *balance = balance.saturating_add(amount);
+        }
+        let base_balance = balances.entry(base_asset_id).or_default();
+        *base_balance = base_balance.saturating_sub(fee);
+
+        for output in outputs.iter_mut() {
+            match output {
+                Output::Change {
+                    asset_id, amount, ..
+                } => {
+                    *amount = balances.get(asset_id).copied().unwrap_or_default();
+                }
+                Output::Variable {
+                    to,
+                    asset_id,
+                    amount,
+                } => {
+                    *to = Address::zeroed();
+                    *asset_id = AssetId::zeroed();
+                    *amount = 0;
+                }
+                _ => {}
+            }
+        }
+    }
+
     fn verify_input_state(
         &self,
         db: &Database,
@@ -1487,12 +1777,17 @@ where
         db: &mut Database,
         inputs: &[Input],
         outputs: &[Output],
+        create_outputs: bool,
     ) -> ExecutorResult<()> {
         for (output_index, output) in outputs.iter().enumerate() {
             let index = u8::try_from(output_index)
                 .expect("Transaction can have only up to `u8::MAX` outputs");
             let utxo_id = UtxoId::new(*tx_id, index);
             match output {
+                // The transaction reverted because of the coin output limit doesn't
+                // create coins and contracts, its coins are returned by the `Change` outputs.
+                Output::Coin { .. } | Output::ContractCreated { .. }
+                    if !create_outputs => {}
                 Output::Coin {
                     amount,
                     asset_id,
@@ -1686,6 +1981,234 @@ where
         Ok(())
     }
 
+    /// Aggregate the amounts minted and burned within the block per asset
+    fn index_asset_supply_for_block(
+        &self,
+        result: &ExecutionResult,
+        db: &Database,
+    ) -> ExecutorResult<()> {
+        let block_height = *result.block.header().height();
+        let mut deltas = BTreeMap::<AssetId, AssetSupplyDelta>::new();
+        for TransactionExecutionStatus { id, result } in result.tx_status.iter() {
+            // The state changes of the failed transactions are reverted,
+            // so their mint and burn receipts don't affect the supply.
+            if !matches!(result, TransactionExecutionResult::Success { .. }) {
+                continue
+            }
+
+            let receipts = db.storage::<Receipts>().get(id)?.unwrap_or_default();
+            for receipt in receipts.iter() {
+                match receipt {
+                    Receipt::Mint {
+                        sub_id,
+                        contract_id,
+                        val,
+                        ..
+                    } => {
+                        let delta =
+                            deltas.entry(contract_id.asset_id(sub_id)).or_default();
+                        delta.minted = delta.minted.saturating_add(*val);
+                    }
+                    Receipt::Burn {
+                        sub_id,
+                        contract_id,
+                        val,
+                        ..
+                    } => {
+                        let delta =
+                            deltas.entry(contract_id.asset_id(sub_id)).or_default();
+                        delta.burned = delta.burned.saturating_add(*val);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for (asset_id, delta) in deltas {
+            db.record_asset_supply_delta(&asset_id, block_height, &delta)?;
+        }
+
+        Ok(())
+    }
+
+    /// Add the amount minted by the coinbase of the block to the total of its asset
+    fn index_coinbase_minted_for_block(
+        &self,
+        result: &ExecutionResult,
+        db: &Database,
+    ) -> ExecutorResult<()> {
+        for tx in result.block.transactions() {
+            if let Transaction::Mint(mint) = tx {
+                if *mint.mint_amount() > 0 {
+                    db.increase_coinbase_minted(
+                        mint.mint_asset_id(),
+                        *mint.mint_amount(),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Store the coinbase credited to the recipient by the block. Blocks that burn
+    /// the fee or don't collect any fees are not indexed.
+    fn index_coinbase_credit_for_block(
+        &self,
+        result: &ExecutionResult,
+        db: &Database,
+    ) -> ExecutorResult<()> {
+        let block_height = *result.block.header().height();
+        for tx in result.block.transactions() {
+            if let Transaction::Mint(mint) = tx {
+                let recipient = mint.input_contract().contract_id;
+                if recipient == ContractId::zeroed() || *mint.mint_amount() == 0 {
+                    continue
+                }
+
+                let previously_accrued = match db
+                    .coinbase_credits(&recipient, None, Some(IterDirection::Reverse))
+                    .next()
+                {
+                    Some(previous) => previous?.1.accrued,
+                    None => 0,
+                };
+                db.record_coinbase_credit(
+                    &recipient,
+                    block_height,
+                    &CoinbaseCredit {
+                        asset_id: *mint.mint_asset_id(),
+                        amount: *mint.mint_amount(),
+                        accrued: previously_accrued.saturating_add(*mint.mint_amount()),
+                    },
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Store the balances of the contracts before and after the block
+    fn index_contract_balance_changes(
+        &self,
+        result: &ExecutionResult,
+        db: &Database,
+    ) -> ExecutorResult<()> {
+        for change in result.contract_balance_changes.iter() {
+            db.record_contract_balance_update(
+                &change.contract_id,
+                &change.asset_id,
+                change.block_height,
+                &ContractBalanceUpdate {
+                    old: change.old,
+                    new: change.new,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Compares the balances of the contracts touched by the `block` before and after
+    /// its execution. Only the balances that have changed are returned.
+    fn contract_balance_changes_for_block(
+        &self,
+        block: &Block,
+        tx_status: &[TransactionExecutionStatus],
+        pre_state: &Database,
+        post_state: &Database,
+    ) -> ExecutorResult<Vec<ContractBalanceChange>> {
+        let base_asset_id = self.config.consensus_parameters.base_asset_id;
+        let mut touched = BTreeSet::<(ContractId, AssetId)>::new();
+        for TransactionExecutionStatus { id, result } in tx_status.iter() {
+            // The state changes of the failed transactions are reverted.
+            if !matches!(result, TransactionExecutionResult::Success { .. }) {
+                continue
+            }
+
+            let receipts = post_state
+                .storage::<Receipts>()
+                .get(id)?
+                .unwrap_or_default();
+            for receipt in receipts.iter() {
+                match receipt {
+                    Receipt::Call {
+                        id,
+                        to,
+                        amount,
+                        asset_id,
+                        ..
+                    } if *amount > 0 => {
+                        touched.insert((*to, *asset_id));
+                        if id != &ContractId::zeroed() {
+                            touched.insert((*id, *asset_id));
+                        }
+                    }
+                    Receipt::Transfer {
+                        id, to, asset_id, ..
+                    } => {
+                        touched.insert((*id, *asset_id));
+                        touched.insert((*to, *asset_id));
+                    }
+                    Receipt::TransferOut { id, asset_id, .. } => {
+                        touched.insert((*id, *asset_id));
+                    }
+                    Receipt::Mint {
+                        sub_id,
+                        contract_id,
+                        ..
+                    }
+                    | Receipt::Burn {
+                        sub_id,
+                        contract_id,
+                        ..
+                    } => {
+                        touched.insert((*contract_id, contract_id.asset_id(sub_id)));
+                    }
+                    Receipt::MessageOut { sender, .. } => {
+                        // The message sent from the contract's context is paid by it.
+                        touched.insert((ContractId::from(**sender), base_asset_id));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for tx in block.transactions() {
+            if let Transaction::Mint(mint) = tx {
+                touched
+                    .insert((mint.input_contract().contract_id, *mint.mint_asset_id()));
+            }
+        }
+
+        let balance = |db: &Database, key: &ContractsAssetKey| -> ExecutorResult<Word> {
+            Ok(db
+                .storage::<ContractsAssets>()
+                .get(key)?
+                .map(|balance| *balance)
+                .unwrap_or_default())
+        };
+
+        let block_height = *block.header().height();
+        let mut changes = vec![];
+        for (contract_id, asset_id) in touched {
+            let key = ContractsAssetKey::new(&contract_id, &asset_id);
+            let old = balance(pre_state, &key)?;
+            let new = balance(post_state, &key)?;
+            if old != new {
+                changes.push(ContractBalanceChange {
+                    block_height,
+                    contract_id,
+                    asset_id,
+                    old,
+                    new,
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+
     fn persist_transaction_status(
         &self,
         result: &ExecutionResult,
@@ -1748,6 +2271,30 @@ impl Fee for CreateCheckedMetadata {
     }
 }
 
+/// The transactions whose execution can be reverted by the executor after the VM
+/// succeeded, like the transactions exceeding the coin output limit.
+trait RevertScriptResult {
+    /// Marks the script as reverted in the `receipts` and updates the receipts root.
+    fn revert_script_result(&mut self, receipts: &mut [Receipt]);
+}
+
+impl RevertScriptResult for Script {
+    fn revert_script_result(&mut self, receipts: &mut [Receipt]) {
+        for receipt in receipts.iter_mut() {
+            if let Receipt::ScriptResult { result, .. } = receipt {
+                *result = ScriptExecutionResult::Revert;
+            }
+        }
+        *self.receipts_root_mut() =
+            ephemeral_merkle_root(receipts.iter().map(|receipt| receipt.to_bytes()));
+    }
+}
+
+impl RevertScriptResult for Create {
+    // The `Create` transaction doesn't run the script, so it has no receipts.
+    fn revert_script_result(&mut self, _: &mut [Receipt]) {}
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 #[allow(clippy::cast_possible_truncation)]
 #[cfg(test)]
@@ -1796,12 +2343,19 @@ mod tests {
     };
 
     pub(crate) fn setup_executable_script() -> (Create, Script) {
+        setup_executable_script_with_amounts(100, 150)
+    }
+
+    /// Creates a contract transferring the coins to the variable output and a script
+    /// spending the coin input of 1000 to call it.
+    pub(crate) fn setup_executable_script_with_amounts(
+        variable_transfer_amount: u32,
+        coin_output_amount: Word,
+    ) -> (Create, Script) {
         let mut rng = StdRng::seed_from_u64(2322);
         let asset_id: AssetId = rng.gen();
         let owner: Address = rng.gen();
         let input_amount = 1000;
-        let variable_transfer_amount = 100;
-        let coin_output_amount = 150;
 
         let (create, contract_id) = create_contract(
             vec![
@@ -2170,6 +2724,274 @@ mod tests {
                 .unwrap();
             assert_eq!(asset_id, AssetId::zeroed());
             assert_eq!(amount, expected_fee_amount_1 + expected_fee_amount_2);
+
+            let credits = producer
+                .database
+                .coinbase_credits(&recipient, None, None)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(
+                credits,
+                vec![
+                    (
+                        1.into(),
+                        CoinbaseCredit {
+                            asset_id: AssetId::BASE,
+                            amount: expected_fee_amount_1,
+                            accrued: expected_fee_amount_1,
+                        }
+                    ),
+                    (
+                        2.into(),
+                        CoinbaseCredit {
+                            asset_id: AssetId::BASE,
+                            amount: expected_fee_amount_2,
+                            accrued: expected_fee_amount_1 + expected_fee_amount_2,
+                        }
+                    ),
+                ]
+            );
+        }
+
+        fn block_with_fee() -> Block {
+            let script = TxBuilder::new(1u64)
+                .script_gas_limit(0)
+                .gas_price(1)
+                .coin_input(AssetId::BASE, 10000)
+                .change_output(AssetId::BASE)
+                .build()
+                .transaction()
+                .clone();
+            let mut block = Block::default();
+            *block.transactions_mut() = vec![script.into()];
+            block
+        }
+
+        #[test]
+        fn missing_coinbase_recipient_does_not_receive_fee() {
+            let recipient = ContractId::from([1u8; 32]);
+            let config = Config {
+                coinbase_recipient: recipient,
+                ..Default::default()
+            };
+            let producer = Executor::test(Default::default(), config);
+
+            let ExecutionResult { block, .. } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block_with_fee().into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            let mint = block.transactions()[1]
+                .as_mint()
+                .expect("The last transaction should be the mint");
+            assert_eq!(mint.input_contract().contract_id, ContractId::zeroed());
+            assert_eq!(*mint.mint_amount(), 0);
+        }
+
+        #[test]
+        fn missing_coinbase_recipient_fails_production_if_configured() {
+            let recipient = ContractId::from([1u8; 32]);
+            let config = Config {
+                coinbase_recipient: recipient,
+                fail_on_missing_coinbase_recipient: true,
+                ..Default::default()
+            };
+            let producer = Executor::test(Default::default(), config);
+
+            let err = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block_with_fee().into()),
+                    Default::default(),
+                )
+                .expect_err("The recipient is not deployed");
+
+            assert!(matches!(
+                err,
+                ExecutorError::CoinbaseRecipientNotFound(id) if id == recipient
+            ));
+        }
+
+        #[test]
+        fn coinbase_recipient_deployed_by_the_block_receives_fee() {
+            let mut rng = StdRng::seed_from_u64(2322);
+            let (create, recipient) = create_contract(vec![], &mut rng);
+            let config = Config {
+                coinbase_recipient: recipient,
+                fail_on_missing_coinbase_recipient: true,
+                ..Default::default()
+            };
+            let producer = Executor::test(Default::default(), config);
+            let mut block = block_with_fee();
+            block.transactions_mut().insert(0, create.into());
+
+            let ExecutionResult { block, .. } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            let mint = block.transactions()[2]
+                .as_mint()
+                .expect("The last transaction should be the mint");
+            assert_eq!(mint.input_contract().contract_id, recipient);
+            assert!(*mint.mint_amount() > 0);
+        }
+
+        fn overflowing_coinbase_recipient(mut config: Config) -> Executor<Database> {
+            // Charge the whole gas as the fee.
+            config.consensus_parameters.fee_params.gas_price_factor = 1;
+            let recipient = config.coinbase_recipient;
+            let mut database = Database::default();
+            database
+                .storage::<ContractsRawCode>()
+                .insert(&recipient, &[])
+                .expect("Should insert coinbase contract");
+            // Any fee overflows the balance of the recipient.
+            database
+                .storage::<ContractsAssets>()
+                .insert(
+                    &ContractsAssetKey::new(&recipient, &AssetId::BASE),
+                    &Word::MAX,
+                )
+                .expect("Should insert the balance");
+            Executor::test(database, config)
+        }
+
+        #[test]
+        fn failed_coinbase_is_skipped_by_default() {
+            let config = Config {
+                coinbase_recipient: ContractId::from([1u8; 32]),
+                ..Default::default()
+            };
+            let producer = overflowing_coinbase_recipient(config);
+
+            let ExecutionResult {
+                block,
+                skipped_transactions,
+                ..
+            } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block_with_fee().into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            assert_eq!(block.transactions().len(), 1);
+            assert_eq!(skipped_transactions.len(), 1);
+        }
+
+        #[test]
+        fn failed_coinbase_fails_production_if_required() {
+            let config = Config {
+                coinbase_recipient: ContractId::from([1u8; 32]),
+                require_coinbase_success: true,
+                ..Default::default()
+            };
+            let producer = overflowing_coinbase_recipient(config);
+
+            let err = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block_with_fee().into()),
+                    Default::default(),
+                )
+                .expect_err("The balance of the recipient overflows");
+
+            assert!(
+                matches!(err, ExecutorError::CoinbaseCannotIncreaseBalance(_)),
+                "{err:?}"
+            );
+        }
+
+        #[test]
+        fn overflowing_coinbase_fails_production_if_halt_is_configured() {
+            let recipient = ContractId::from([1u8; 32]);
+            let config = Config {
+                coinbase_recipient: recipient,
+                coinbase_overflow_policy: CoinbaseOverflowPolicy::Halt,
+                ..Default::default()
+            };
+            let producer = overflowing_coinbase_recipient(config);
+
+            let err = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block_with_fee().into()),
+                    Default::default(),
+                )
+                .expect_err("The balance of the recipient overflows");
+
+            assert!(
+                matches!(err, ExecutorError::CoinbaseBalanceOverflow(id) if id == recipient),
+                "{err:?}"
+            );
+        }
+
+        #[test]
+        fn overflowing_coinbase_is_burned_if_burn_is_configured() {
+            let recipient = ContractId::from([1u8; 32]);
+            let config = Config {
+                coinbase_recipient: recipient,
+                coinbase_overflow_policy: CoinbaseOverflowPolicy::Burn,
+                require_coinbase_success: true,
+                ..Default::default()
+            };
+            let producer = overflowing_coinbase_recipient(config);
+
+            let ExecutionResult {
+                block,
+                skipped_transactions,
+                ..
+            } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block_with_fee().into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            assert!(skipped_transactions.is_empty());
+            let mint = block.transactions()[1]
+                .as_mint()
+                .expect("The last transaction should be the mint");
+            assert_eq!(mint.input_contract().contract_id, ContractId::zeroed());
+            assert_eq!(*mint.mint_amount(), 0);
+            let balance = producer
+                .database
+                .storage::<ContractsAssets>()
+                .get(&ContractsAssetKey::new(&recipient, &AssetId::BASE))
+                .unwrap()
+                .unwrap();
+            assert_eq!(*balance, Word::MAX);
+        }
+
+        #[test]
+        fn missing_coinbase_recipient_is_not_minted_if_only_coinbase_is_required() {
+            let recipient = ContractId::from([1u8; 32]);
+            let config = Config {
+                coinbase_recipient: recipient,
+                require_coinbase_success: true,
+                ..Default::default()
+            };
+            let producer = Executor::test(Default::default(), config);
+
+            let ExecutionResult {
+                block,
+                skipped_transactions,
+                ..
+            } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block_with_fee().into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            assert!(skipped_transactions.is_empty());
+            let mint = block.transactions()[1]
+                .as_mint()
+                .expect("The last transaction should be the mint");
+            assert_eq!(mint.input_contract().contract_id, ContractId::zeroed());
+            assert_eq!(*mint.mint_amount(), 0);
         }
 
         #[test]
@@ -2495,6 +3317,28 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn produced_mint_uses_configured_base_asset() {
+            let base_asset_id: AssetId = [1u8; 32].into();
+            let mut config = Config::default();
+            config.consensus_parameters.base_asset_id = base_asset_id;
+            let producer = Executor::test(Default::default(), config);
+
+            let ExecutionResult { block, .. } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(Block::default().into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            let mint = block
+                .transactions()
+                .last()
+                .and_then(|tx| tx.as_mint())
+                .expect("The block should end with the `Mint` transaction");
+            assert_eq!(mint.mint_asset_id(), &base_asset_id);
+        }
+
         #[test]
         fn invalidate_mismatch_amount() {
             let mint = Transaction::mint(
@@ -2896,6 +3740,214 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn executor_reverts_tx_with_coin_output_above_max_amount() {
+        let max_amount = 500;
+        let over_limit_tx = TxBuilder::new(1u64)
+            .coin_input(AssetId::default(), 1000)
+            .coin_output(AssetId::default(), 1000)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone();
+        let within_limit_tx = TxBuilder::new(2u64)
+            .coin_input(AssetId::default(), 400)
+            .coin_output(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone();
+        let over_limit_tx_id = over_limit_tx.id(&ChainId::default());
+
+        let database = &Database::default();
+        let executor = Executor::test(
+            database.clone(),
+            Config {
+                max_coin_output_amount: Some(max_amount),
+                ..Default::default()
+            },
+        );
+
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![over_limit_tx.into(), within_limit_tx.into()],
+        };
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            tx_status,
+            ..
+        } = executor
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+
+        // Both transactions are included with `Mint`, but the over limit one is reverted.
+        assert_eq!(block.transactions().len(), 3);
+        assert!(skipped_transactions.is_empty());
+        assert!(matches!(
+            &tx_status[0].result,
+            TransactionExecutionResult::Failed { reason, .. }
+                if reason == &TransactionValidityError::CoinOutputAmountExceedsLimit {
+                    tx_id: over_limit_tx_id,
+                    amount: 1000,
+                    max_amount,
+                }
+                .to_string()
+        ));
+        assert!(matches!(
+            tx_status[1].result,
+            TransactionExecutionResult::Success { .. }
+        ));
+
+        // The coin output is not created, and the change returns the input amount.
+        let outputs = block.transactions()[0].as_script().unwrap().outputs();
+        assert!(matches!(outputs[1], Output::Change { amount: 1000, .. }));
+        let coins = database.storage::<Coins>();
+        assert!(coins
+            .get(&UtxoId::new(over_limit_tx_id, 0))
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            coins
+                .get(&UtxoId::new(over_limit_tx_id, 1))
+                .unwrap()
+                .unwrap()
+                .amount,
+            1000
+        );
+
+        // The receipts report the revert of the script.
+        let receipts = database
+            .storage::<Receipts>()
+            .get(&over_limit_tx_id)
+            .unwrap()
+            .unwrap();
+        assert!(receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::ScriptResult {
+                result: ScriptExecutionResult::Revert,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn executor_doesnt_limit_change_output_above_max_amount() {
+        let max_amount = 500;
+        // The coin output is within the limit, but the change returns 900.
+        let tx = TxBuilder::new(1u64)
+            .coin_input(AssetId::default(), 1000)
+            .coin_output(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone();
+        let tx_id = tx.id(&ChainId::default());
+
+        let database = &Database::default();
+        let executor = Executor::test(
+            database.clone(),
+            Config {
+                max_coin_output_amount: Some(max_amount),
+                ..Default::default()
+            },
+        );
+
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![tx.into()],
+        };
+
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = executor
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+
+        assert!(skipped_transactions.is_empty());
+        assert!(matches!(
+            tx_status[0].result,
+            TransactionExecutionResult::Success { .. }
+        ));
+        let coins = database.storage::<Coins>();
+        assert_eq!(
+            coins.get(&UtxoId::new(tx_id, 0)).unwrap().unwrap().amount,
+            100
+        );
+        assert_eq!(
+            coins.get(&UtxoId::new(tx_id, 1)).unwrap().unwrap().amount,
+            900
+        );
+    }
+
+    #[test]
+    fn executor_reverts_tx_with_variable_output_above_max_amount() {
+        let max_amount = 400;
+        // The script transfers 500 to the variable output and pays 100 to the coin output,
+        // so only the variable output exceeds the limit.
+        let (deploy, script) = setup_executable_script_with_amounts(500, 100);
+        let script_id = script.id(&ChainId::default());
+        let asset_id = match script.outputs()[2] {
+            Output::Change { asset_id, .. } => asset_id,
+            _ => unreachable!("The third output is the change"),
+        };
+        let contract_id = match script.inputs()[0] {
+            Input::Contract(Contract { contract_id, .. }) => contract_id,
+            _ => unreachable!("The first input is the contract"),
+        };
+
+        let database = &Database::default();
+        let mut config = Config {
+            max_coin_output_amount: Some(max_amount),
+            ..Default::default()
+        };
+        config.consensus_parameters.base_asset_id = asset_id;
+        let executor = Executor::test(database.clone(), config);
+
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![deploy.into(), script.into()],
+        };
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            tx_status,
+            ..
+        } = executor
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+
+        assert!(skipped_transactions.is_empty());
+        assert!(matches!(
+            &tx_status[1].result,
+            TransactionExecutionResult::Failed { reason, .. }
+                if reason == &TransactionValidityError::CoinOutputAmountExceedsLimit {
+                    tx_id: script_id,
+                    amount: 500,
+                    max_amount,
+                }
+                .to_string()
+        ));
+
+        // The variable output is zeroed, and the change returns the whole input.
+        let outputs = block.transactions()[1].as_script().unwrap().outputs();
+        assert!(matches!(outputs[0], Output::Variable { amount: 0, .. }));
+        assert!(matches!(outputs[2], Output::Change { amount: 1000, .. }));
+        let coins = database.storage::<Coins>();
+        assert!(coins.get(&UtxoId::new(script_id, 0)).unwrap().is_none());
+        assert!(coins.get(&UtxoId::new(script_id, 1)).unwrap().is_none());
+        // The state changes of the transaction are reverted.
+        assert!(database
+            .storage::<ContractsAssets>()
+            .get(&ContractsAssetKey::new(&contract_id, &asset_id))
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn skipped_tx_not_changed_spent_status() {
         // `tx2` has two inputs: one used by `tx1` and on random. So after the execution of `tx1`,
@@ -0,0 +1,10 @@
+//! # The fee collection contract
+//!
+//! The contract is defined in the `fuel-core-chain-config`; this module re-exports
+//! it along with the helpers that require a running node.
+
+#[doc(no_inline)]
+pub use fuel_core_chain_config::fee_collection_contract::*;
+
+#[cfg(feature = "test-helpers")]
+pub mod testkit;
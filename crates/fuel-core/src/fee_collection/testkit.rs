@@ -0,0 +1,244 @@
+//! # Helpers for testing the fee collection scenarios
+//!
+//! The [`FeeCollectionHarness`] starts a node whose coinbase is credited into
+//! a freshly deployed fee collection contract.
+
+use crate::{
+    fee_collection::{
+        check_script_data_length,
+        generate,
+        script_data,
+        validate_fee_collection_tx,
+        withdrawal_script,
+        WithdrawalOutputs,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    types::{
+        CreateWithDeterministicSalt,
+        TransactionStatus,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        Cacheable,
+        Create,
+        Finalizable,
+        Input,
+        Script,
+        ScriptParameters,
+        StorageSlot,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+        Witness,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ChainId,
+        ContractId,
+        Word,
+    },
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// The node with the deployed fee collection contract set as the coinbase recipient.
+pub struct FeeCollectionHarness {
+    /// The address that receives the withdrawn fees.
+    pub address: Address,
+    /// The id of the fee collection contract.
+    pub contract_id: ContractId,
+    /// The client connected to the `node`.
+    pub client: FuelClient,
+    /// The running node.
+    pub node: FuelService,
+    rng: StdRng,
+}
+
+impl FeeCollectionHarness {
+    /// Deploys the fee collection contract withdrawing to the random address
+    /// generated from the `seed`.
+    pub async fn new(seed: u64) -> Self {
+        let address: Address = StdRng::seed_from_u64(seed).gen();
+        Self::with_contract(seed, generate(address), vec![], address).await
+    }
+
+    /// Deploys the `contract` with `storage_slots` as the fee collection contract
+    /// withdrawing to the `address`. The transactions accruing the fee are signed
+    /// by the keys generated from the `seed`.
+    pub async fn with_contract(
+        seed: u64,
+        contract: Vec<u8>,
+        storage_slots: Vec<StorageSlot>,
+        address: Address,
+    ) -> Self {
+        let witness: Witness = contract.into();
+        let mut create_tx = TransactionBuilder::<Create>::create_with_deterministic_salt(
+            witness,
+            b"fee_collection_contract",
+            storage_slots,
+        )
+        .add_random_fee_input()
+        .finalize();
+        create_tx
+            .precompute(&ChainId::default())
+            .expect("tx should be valid");
+        let contract_id = create_tx.metadata().as_ref().unwrap().contract_id;
+
+        // Start up a node
+        let mut config = Config::local_node_in_memory();
+        config.debug = true;
+        config.block_producer.coinbase_recipient = Some(contract_id);
+        let node = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(node.bound_address);
+
+        // Submit contract creation tx
+        let tx_status = client
+            .submit_and_await_commit(&create_tx.into())
+            .await
+            .unwrap();
+        assert!(matches!(tx_status, TransactionStatus::Success { .. }));
+        let bh = client.produce_blocks(1, None).await.unwrap();
+        assert_eq!(bh, BlockHeight::new(2));
+
+        // No fees should have been collected yet
+        let contract_balance = client.contract_balance(&contract_id, None).await.unwrap();
+        assert_eq!(contract_balance, 0);
+
+        Self {
+            address,
+            contract_id,
+            client,
+            node,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Makes a block with a single transaction that has a fee, so that
+    /// the coinbase fee is collected into the contract.
+    ///
+    /// Returns the amount of the base asset credited to the contract.
+    pub async fn accrue_fee(&mut self) -> Word {
+        let old_balance = self
+            .client
+            .contract_balance(&self.contract_id, None)
+            .await
+            .unwrap();
+
+        // Run a script that does nothing, but will cause fee collection
+        let tx = TransactionBuilder::script(
+            [op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut self.rng),
+            self.rng.gen(),
+            1000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .gas_price(1)
+        .script_gas_limit(1_000_000)
+        .finalize_as_transaction();
+        let tx_status = self.client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(matches!(tx_status, TransactionStatus::Success { .. }));
+
+        // The coinbase of the block with the transaction credits the fee to the contract
+        let height = self
+            .client
+            .chain_info()
+            .await
+            .unwrap()
+            .latest_block
+            .header
+            .height;
+        let coinbase = self
+            .client
+            .block_coinbase(height.into())
+            .await
+            .unwrap()
+            .expect("The block should have the mint transaction");
+        assert_eq!(coinbase.recipient, Some(self.contract_id));
+        let minted: Word = coinbase
+            .amounts
+            .iter()
+            .filter(|(asset_id, _)| *asset_id == AssetId::BASE)
+            .map(|(_, amount)| amount)
+            .sum();
+        assert!(minted > 0);
+
+        // Now the coinbase fee should be reflected in the contract balance
+        let new_balance = self
+            .client
+            .contract_balance(&self.contract_id, None)
+            .await
+            .unwrap();
+        assert_eq!(Some(new_balance), old_balance.checked_add(minted));
+        minted
+    }
+
+    /// Withdraws the fees in the base asset from the contract to the `address`.
+    ///
+    /// Returns the id of the withdrawal transaction.
+    pub async fn collect(&self) -> Bytes32 {
+        let tx = withdrawal_builder(&self.contract_id).finalize();
+        validate_fee_collection_tx(&tx).expect("The withdrawal should be valid");
+        let tx: Transaction = tx.into();
+
+        let tx_status = self.client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(
+            matches!(tx_status, TransactionStatus::Success { .. }),
+            "{tx_status:?}"
+        );
+        tx.id(&ChainId::default())
+    }
+}
+
+/// Builds the transaction withdrawing the fees in the base asset
+/// from the `contract_id` to the variable output.
+pub fn withdrawal_builder(contract_id: &ContractId) -> TransactionBuilder<Script> {
+    let asset_id = AssetId::BASE;
+    // The contract input follows the input paying the fee.
+    let layout = WithdrawalOutputs::new(1, vec![asset_id]);
+    let output_index = layout
+        .variable_output_index_for(&asset_id)
+        .expect("The asset is withdrawn");
+    let script_data = script_data(&asset_id, output_index, contract_id);
+    check_script_data_length(&script_data, &ScriptParameters::default())
+        .expect("Script data should fit into the limit");
+
+    let mut builder = TransactionBuilder::script(withdrawal_script(), script_data);
+    builder
+        .add_random_fee_input() // No coinbase fee for this block
+        .gas_price(0)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            *contract_id,
+        ));
+    for output in layout.outputs() {
+        builder.add_output(output);
+    }
+    builder
+}
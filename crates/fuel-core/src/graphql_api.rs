@@ -1,14 +1,22 @@
+use fuel_core_chain_config::AssetConfig;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
 };
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
-    fuel_tx::ConsensusParameters,
+    fuel_tx::{
+        ConsensusParameters,
+        ContractId,
+    },
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
 
+pub mod idempotency;
 pub(crate) mod metrics_extension;
 pub mod ports;
 pub mod service;
@@ -22,8 +30,97 @@ pub struct Config {
     pub min_gas_price: u64,
     pub max_tx: usize,
     pub max_depth: usize,
+    pub block_gas_limit: u64,
     pub consensus_parameters: ConsensusParameters,
+    pub assets: Vec<AssetConfig>,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    pub read_only: bool,
+    pub txpool: TxPoolConfig,
+    pub block_producer: BlockProducerConfig,
+    pub relayer: Option<RelayerConfig>,
+    /// The time during which the idempotency key of the submitted transaction
+    /// rejects other submissions with the same key.
+    pub idempotency_key_ttl: Duration,
+    /// The number of blocks on top of the block before its balances are final.
+    pub finalization_depth: u32,
+    /// The trigger of the block production by the PoA service.
+    pub block_production: fuel_core_poa::Trigger,
+}
+
+/// The configuration of the transaction pool exposed by the API.
+#[derive(Clone, Debug)]
+pub struct TxPoolConfig {
+    pub max_tx: usize,
+    pub max_depth: usize,
+    pub min_gas_price: u64,
+    pub utxo_validation: bool,
+    pub metrics: bool,
+    pub transaction_ttl: Duration,
+    pub number_of_active_subscription: usize,
+}
+
+impl From<&fuel_core_txpool::Config> for TxPoolConfig {
+    fn from(config: &fuel_core_txpool::Config) -> Self {
+        Self {
+            max_tx: config.max_tx,
+            max_depth: config.max_depth,
+            min_gas_price: config.min_gas_price,
+            utxo_validation: config.utxo_validation,
+            metrics: config.metrics,
+            transaction_ttl: config.transaction_ttl,
+            number_of_active_subscription: config.number_of_active_subscription,
+        }
+    }
+}
+
+/// The configuration of the block producer exposed by the API.
+#[derive(Clone, Debug)]
+pub struct BlockProducerConfig {
+    pub utxo_validation: bool,
+    pub coinbase_recipient: Option<ContractId>,
+    pub metrics: bool,
+    pub max_dry_run_gas: Option<u64>,
+}
+
+impl From<&fuel_core_producer::Config> for BlockProducerConfig {
+    fn from(config: &fuel_core_producer::Config) -> Self {
+        Self {
+            utxo_validation: config.utxo_validation,
+            coinbase_recipient: config.coinbase_recipient,
+            metrics: config.metrics,
+            max_dry_run_gas: config.max_dry_run_gas,
+        }
+    }
+}
+
+/// The configuration of the relayer without the address of the Ethereum client,
+/// because it may contain the credentials.
+#[derive(Clone, Debug)]
+pub struct RelayerConfig {
+    pub da_deploy_height: u64,
+    pub eth_v2_listening_contracts: Vec<String>,
+    pub log_page_size: u64,
+    pub sync_minimum_duration: Duration,
+    pub syncing_call_frequency: Duration,
+    pub syncing_log_frequency: Duration,
+}
+
+#[cfg(feature = "relayer")]
+impl From<&fuel_core_relayer::Config> for RelayerConfig {
+    fn from(config: &fuel_core_relayer::Config) -> Self {
+        Self {
+            da_deploy_height: config.da_deploy_height.0,
+            eth_v2_listening_contracts: config
+                .eth_v2_listening_contracts
+                .iter()
+                .map(|contract| format!("{contract:?}"))
+                .collect(),
+            log_page_size: config.log_page_size,
+            sync_minimum_duration: config.sync_minimum_duration,
+            syncing_call_frequency: config.syncing_call_frequency,
+            syncing_log_frequency: config.syncing_log_frequency,
+        }
+    }
 }
 
 pub trait IntoApiResult<T> {
@@ -0,0 +1,101 @@
+use fuel_core_types::fuel_types::Bytes32;
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        hash_map::Entry,
+        HashMap,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The idempotency keys of the transactions submitted via GraphQL API.
+///
+/// The key is remembered for the `ttl` after the submission. During this time,
+/// the subsequent submissions with the same key are rejected as duplicates.
+pub struct IdempotencyKeys {
+    ttl: Duration,
+    keys: Mutex<HashMap<Bytes32, (Bytes32, Instant)>>,
+}
+
+impl IdempotencyKeys {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserves the `key` for the transaction with the `tx_id`.
+    ///
+    /// Returns the id of the transaction that reserved the `key` before
+    /// if the reservation is not expired yet.
+    pub fn reserve(&self, key: Bytes32, tx_id: Bytes32) -> Result<(), Bytes32> {
+        let now = Instant::now();
+        let mut keys = self.keys.lock();
+        keys.retain(|_, (_, reserved_at)| now.duration_since(*reserved_at) < self.ttl);
+
+        match keys.entry(key) {
+            Entry::Occupied(entry) => Err(entry.get().0),
+            Entry::Vacant(entry) => {
+                entry.insert((tx_id, now));
+                Ok(())
+            }
+        }
+    }
+
+    /// Releases the `key` reserved for the transaction with the `tx_id`,
+    /// so the failed submission can be retried with the same key.
+    pub fn release(&self, key: &Bytes32, tx_id: &Bytes32) {
+        let mut keys = self.keys.lock();
+        if matches!(keys.get(key), Some((reserved, _)) if reserved == tx_id) {
+            keys.remove(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserve_rejects_the_key_reserved_by_another_transaction() {
+        let keys = IdempotencyKeys::new(Duration::from_secs(60));
+        let key = Bytes32::new([1; 32]);
+        let original = Bytes32::new([2; 32]);
+        let duplicate = Bytes32::new([3; 32]);
+
+        keys.reserve(key, original).unwrap();
+
+        assert_eq!(keys.reserve(key, duplicate), Err(original));
+        assert_eq!(keys.reserve(Bytes32::new([4; 32]), duplicate), Ok(()));
+    }
+
+    #[test]
+    fn release_allows_to_reserve_the_key_again() {
+        let keys = IdempotencyKeys::new(Duration::from_secs(60));
+        let key = Bytes32::new([1; 32]);
+        let first = Bytes32::new([2; 32]);
+        let second = Bytes32::new([3; 32]);
+        keys.reserve(key, first).unwrap();
+
+        // Only the transaction that reserved the key can release it.
+        keys.release(&key, &second);
+        assert_eq!(keys.reserve(key, second), Err(first));
+
+        keys.release(&key, &first);
+        assert_eq!(keys.reserve(key, second), Ok(()));
+    }
+
+    #[test]
+    fn reserve_forgets_the_expired_keys() {
+        let keys = IdempotencyKeys::new(Duration::ZERO);
+        let key = Bytes32::new([1; 32]);
+
+        keys.reserve(key, Bytes32::new([2; 32])).unwrap();
+
+        assert_eq!(keys.reserve(key, Bytes32::new([3; 32])), Ok(()));
+    }
+}
@@ -10,6 +10,7 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsInfo,
         ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         Receipts,
@@ -32,6 +33,7 @@ use fuel_core_types::{
         Message,
     },
     fuel_tx::{
+        Mint,
         Receipt,
         Transaction,
         TxId,
@@ -42,13 +44,22 @@ use fuel_core_types::{
         Address,
         AssetId,
         BlockHeight,
+        Bytes32,
         ContractId,
         Nonce,
+        Word,
     },
     services::{
-        graphql_api::ContractBalance,
+        block_importer::ImportResult,
+        graphql_api::{
+            AssetSupplyDelta,
+            CoinbaseCredit,
+            ContractBalance,
+            ContractBalanceUpdate,
+        },
         txpool::{
             InsertionResult,
+            TransactionSource,
             TransactionStatus,
         },
     },
@@ -66,6 +77,7 @@ pub trait DatabasePort:
     + DatabaseCoins
     + DatabaseContracts
     + DatabaseChain
+    + DatabaseAssets
     + DatabaseMessageProof
 {
 }
@@ -84,6 +96,10 @@ pub trait DatabaseBlocks:
     ) -> BoxedIter<'_, StorageResult<(BlockHeight, BlockId)>>;
 
     fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)>;
+
+    /// Returns the gas limit used to produce the block at the `height`, if the block
+    /// was produced by this node.
+    fn block_gas_limit(&self, height: &BlockHeight) -> StorageResult<Option<Word>>;
 }
 
 /// Trait that specifies all the getters required for transactions.
@@ -139,6 +155,7 @@ pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsInfo, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
 {
     fn contract_balances(
         &self,
@@ -146,6 +163,34 @@ pub trait DatabaseContracts:
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the balances of all contracts ordered by the contract and the asset.
+    fn all_contract_balances(
+        &self,
+        start: Option<(ContractId, AssetId)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the storage slots of the `contract` as the pairs of the key and the value.
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Bytes32)>>;
+
+    /// Returns the balances of the `asset_id` of the `contract` before and after each block
+    /// that changed it, starting from the `start` height.
+    fn contract_balance_history(
+        &self,
+        contract: ContractId,
+        asset_id: AssetId,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(BlockHeight, ContractBalanceUpdate)>>;
+
+    /// Returns the ids of all deployed contracts ordered by the id.
+    fn contract_ids(&self) -> BoxedIter<StorageResult<ContractId>>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -155,12 +200,51 @@ pub trait DatabaseChain {
     fn da_height(&self) -> StorageResult<DaBlockHeight>;
 }
 
+/// Trait that specifies all the getters required for assets.
+pub trait DatabaseAssets {
+    /// Returns the supply changes of the asset per block, starting from the `start` height.
+    fn asset_supply_history(
+        &self,
+        asset_id: &AssetId,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, AssetSupplyDelta)>>;
+
+    /// Returns the total amount of the asset minted by the coinbase of all blocks.
+    fn total_coinbase_minted(&self, asset_id: &AssetId) -> StorageResult<u128>;
+
+    /// Returns the coinbase credited to the `recipient` per block, starting from
+    /// the `start` height.
+    fn coinbase_credits(
+        &self,
+        recipient: &ContractId,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, CoinbaseCredit)>>;
+}
+
 #[async_trait]
 pub trait TxPoolPort: Send + Sync {
     fn transaction(&self, id: TxId) -> Option<Transaction>;
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
+    /// Returns the origin of the pending transaction with `id`.
+    fn transaction_source(&self, id: TxId) -> Option<TransactionSource>;
+
+    /// Returns all pending transactions that the transaction with `id` depends on,
+    /// directly or transitively. The transaction itself is not included.
+    fn transaction_dependencies(&self, id: TxId) -> Vec<Transaction>;
+
+    /// Returns all pending transactions that use the coin with `utxo_id` as an input.
+    fn coin_spenders(&self, utxo_id: UtxoId) -> Vec<Transaction>;
+
+    /// Returns the number of transactions in the pool.
+    fn pending_number(&self) -> usize;
+
+    /// Returns the max gas of all transactions in the pool combined.
+    fn total_consumable_gas(&self) -> u64;
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -170,6 +254,14 @@ pub trait TxPoolPort: Send + Sync {
         &self,
         tx_id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>>;
+
+    /// Removes the pending transaction with `id` and the transactions depending on it.
+    /// Returns `false` if the transaction is not in the pool.
+    fn remove(&self, id: TxId) -> bool;
+
+    /// Drops the pending transaction with `id` if it is not included in a block by
+    /// the `expire_at` height. Returns `false` if the transaction is not in the pool.
+    fn set_expiration(&self, id: TxId, expire_at: BlockHeight) -> bool;
 }
 
 #[async_trait]
@@ -182,7 +274,32 @@ pub trait DryRunExecution {
     ) -> anyhow::Result<Vec<Receipt>>;
 }
 
-pub trait BlockProducerPort: Send + Sync + DryRunExecution {}
+#[async_trait]
+pub trait BlockProducerPort: Send + Sync + DryRunExecution {
+    /// Executes the next block with the transactions from the `TxPool` without committing
+    /// it and returns its mint transaction. The block uses at most `max_gas`.
+    async fn pending_coinbase(&self, max_gas: Word) -> anyhow::Result<Mint>;
+
+    /// Executes the `transactions` on top of the current state as the next block with
+    /// the `block_time` without committing it and returns the receipts of each executed
+    /// transaction, including the mint transaction crediting the coinbase. The block
+    /// uses at most `max_gas`.
+    async fn replay_transactions(
+        &self,
+        transactions: Vec<Transaction>,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<Vec<Vec<Receipt>>>;
+}
+
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns a stream of the results of the block imports.
+    fn block_events(&self) -> BoxStream<Arc<ImportResult>>;
+
+    /// Returns `true` if the node stopped importing blocks because of
+    /// the repeated write failures and only serves the queries.
+    fn is_read_only(&self) -> bool;
+}
 
 #[async_trait::async_trait]
 pub trait ConsensusModulePort: Send + Sync {
@@ -191,6 +308,34 @@ pub trait ConsensusModulePort: Send + Sync {
         start_time: Option<Tai64>,
         number_of_blocks: u32,
     ) -> anyhow::Result<()>;
+
+    /// Produces the next block with the `transactions` in the specified order,
+    /// bypassing the `TxPool`.
+    async fn manually_produce_block_with_transactions(
+        &self,
+        start_time: Option<Tai64>,
+        transactions: Vec<Transaction>,
+    ) -> anyhow::Result<()>;
+
+    /// Produces the next block only if it credits more than the `threshold`
+    /// of the `asset_id`, and returns its height.
+    async fn manually_produce_block_if_coinbase_over(
+        &self,
+        asset_id: AssetId,
+        threshold: Word,
+    ) -> anyhow::Result<Option<BlockHeight>>;
+
+    /// Returns `true` if the node produces blocks.
+    fn is_block_production_enabled(&self) -> bool;
+
+    /// Replaces the gas limit of the blocks produced after the call.
+    async fn set_block_gas_limit(&self, block_gas_limit: Word) -> anyhow::Result<()>;
+}
+
+#[async_trait::async_trait]
+pub trait P2pPort: Send + Sync {
+    /// Returns the number of the peers connected to the node.
+    async fn connected_peers_count(&self) -> usize;
 }
 
 /// Trait that specifies queries supported by the database.
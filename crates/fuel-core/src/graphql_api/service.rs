@@ -1,11 +1,14 @@
 use crate::{
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
         ConsensusModulePort,
         DatabasePort,
+        P2pPort,
         TxPoolPort,
     },
     graphql_api::{
+        idempotency::IdempotencyKeys,
         metrics_extension::MetricsExtension,
         Config,
     },
@@ -81,6 +84,8 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
+pub type P2pService = Box<dyn P2pPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -165,10 +170,13 @@ pub fn new_service(
     txpool: TxPool,
     producer: BlockProducer,
     consensus_module: ConsensusModule,
+    block_importer: BlockImporter,
+    p2p_service: P2pService,
     log_threshold_ms: Duration,
     request_timeout: Duration,
 ) -> anyhow::Result<Service> {
     let network_addr = config.addr;
+    let idempotency_keys = IdempotencyKeys::new(config.idempotency_key_ttl);
 
     let schema = schema
         .data(config)
@@ -176,6 +184,9 @@ pub fn new_service(
         .data(txpool)
         .data(producer)
         .data(consensus_module)
+        .data(block_importer)
+        .data(p2p_service)
+        .data(idempotency_keys)
         .extension(async_graphql::extensions::Tracing)
         .extension(MetricsExtension::new(log_threshold_ms))
         .finish();
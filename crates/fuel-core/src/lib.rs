@@ -7,6 +7,8 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[doc(no_inline)]
 pub use fuel_core_chain_config as chain_config;
+#[doc(no_inline)]
+pub use fuel_core_importer as importer;
 #[cfg(feature = "p2p")]
 #[doc(no_inline)]
 pub use fuel_core_p2p as p2p;
@@ -26,6 +28,7 @@ pub use fuel_core_types as types;
 pub mod coins_query;
 pub mod database;
 pub mod executor;
+pub mod fee_collection;
 pub mod model;
 #[cfg(all(feature = "p2p", feature = "test-helpers"))]
 pub mod p2p_test_helpers;
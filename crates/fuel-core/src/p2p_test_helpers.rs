@@ -97,6 +97,9 @@ pub struct ValidatorSetup {
     pub utxo_validation: bool,
     /// Indicates the type of initial connections.
     pub bootstrap_type: BootstrapType,
+    /// The interval and the max number of transactions per interval
+    /// of the outbound transactions gossip.
+    pub tx_gossip_throttle: Option<(Duration, usize)>,
 }
 
 #[derive(Clone)]
@@ -339,11 +342,18 @@ pub async fn make_nodes(
             pub_key,
             utxo_validation,
             bootstrap_type,
+            tx_gossip_throttle,
             ..
         }) = s
         {
             node_config.utxo_validation = utxo_validation;
 
+            if let Some((interval, max_txs)) = tx_gossip_throttle {
+                let p2p = node_config.p2p.as_mut().unwrap();
+                p2p.tx_gossip_interval = Some(interval);
+                p2p.max_txs_per_gossip_interval = max_txs;
+            }
+
             match bootstrap_type {
                 BootstrapType::BootstrapNodes => {
                     node_config.p2p.as_mut().unwrap().bootstrap_nodes = boots.clone();
@@ -562,6 +572,7 @@ impl ValidatorSetup {
             name: Default::default(),
             utxo_validation: true,
             bootstrap_type: BootstrapType::BootstrapNodes,
+            tx_gossip_throttle: None,
         }
     }
 
@@ -585,6 +596,13 @@ impl ValidatorSetup {
             ..self
         }
     }
+
+    pub fn with_tx_gossip_throttle(self, interval: Duration, max_txs: usize) -> Self {
+        Self {
+            tx_gossip_throttle: Some((interval, max_txs)),
+            ..self
+        }
+    }
 }
 impl BootstrapSetup {
     pub fn new(pub_key: Address) -> Self {
@@ -0,0 +1,130 @@
+use crate::graphql_api::ports::{
+    DatabaseAssets,
+    DatabasePort,
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+    },
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        ContractId,
+    },
+    services::graphql_api::{
+        AssetSupplyDelta,
+        CoinbaseCredit,
+    },
+};
+
+pub trait AssetQueryData: Send + Sync {
+    /// Returns the supply changes of the asset within the `[from, to]` range of blocks.
+    fn asset_supply_history(
+        &self,
+        asset_id: &AssetId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> BoxedIter<StorageResult<(BlockHeight, AssetSupplyDelta)>>;
+
+    /// Returns the running total of the asset minted by the coinbase.
+    fn total_coinbase_minted(&self, asset_id: &AssetId) -> StorageResult<u128>;
+
+    /// Returns the sum of the coinbase credited to the `recipient` by the blocks
+    /// up to and including the `up_to` height.
+    fn accrued_coinbase(
+        &self,
+        recipient: &ContractId,
+        up_to: BlockHeight,
+    ) -> StorageResult<u64>;
+
+    /// Returns the coinbase credited to the `recipient` by the blocks within
+    /// the `[from, to)` range of heights.
+    fn coinbase_credits(
+        &self,
+        recipient: &ContractId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> BoxedIter<StorageResult<(BlockHeight, CoinbaseCredit)>>;
+
+    /// Returns the coinbase credited to the `recipient` ordered from the newest block
+    /// to the oldest one.
+    fn recent_coinbase_credits(
+        &self,
+        recipient: &ContractId,
+    ) -> BoxedIter<StorageResult<(BlockHeight, CoinbaseCredit)>>;
+}
+
+impl<D: DatabasePort + ?Sized> AssetQueryData for D {
+    fn asset_supply_history(
+        &self,
+        asset_id: &AssetId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> BoxedIter<StorageResult<(BlockHeight, AssetSupplyDelta)>> {
+        let history = DatabaseAssets::asset_supply_history(
+            self,
+            asset_id,
+            Some(from),
+            IterDirection::Forward,
+        );
+        history
+            .take_while(move |result| match result {
+                Ok((height, _)) => *height <= to,
+                Err(_) => true,
+            })
+            .into_boxed()
+    }
+
+    fn total_coinbase_minted(&self, asset_id: &AssetId) -> StorageResult<u128> {
+        DatabaseAssets::total_coinbase_minted(self, asset_id)
+    }
+
+    fn accrued_coinbase(
+        &self,
+        recipient: &ContractId,
+        up_to: BlockHeight,
+    ) -> StorageResult<u64> {
+        // The latest credit at or below `up_to` holds the running total of the recipient.
+        let latest = DatabaseAssets::coinbase_credits(
+            self,
+            recipient,
+            Some(up_to),
+            IterDirection::Reverse,
+        )
+        .next()
+        .transpose()?;
+        Ok(latest.map(|(_, credit)| credit.accrued).unwrap_or_default())
+    }
+
+    fn coinbase_credits(
+        &self,
+        recipient: &ContractId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> BoxedIter<StorageResult<(BlockHeight, CoinbaseCredit)>> {
+        let credits = DatabaseAssets::coinbase_credits(
+            self,
+            recipient,
+            Some(from),
+            IterDirection::Forward,
+        );
+        credits
+            .take_while(move |result| match result {
+                Ok((height, _)) => *height < to,
+                Err(_) => true,
+            })
+            .into_boxed()
+    }
+
+    fn recent_coinbase_credits(
+        &self,
+        recipient: &ContractId,
+    ) -> BoxedIter<StorageResult<(BlockHeight, CoinbaseCredit)>> {
+        DatabaseAssets::coinbase_credits(self, recipient, None, IterDirection::Reverse)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::graphql_api::ports::DatabasePort;
+use crate::graphql_api::ports::{
+    DatabaseBlocks,
+    DatabasePort,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -19,7 +22,11 @@ use fuel_core_types::{
         consensus::Consensus,
         primitives::BlockId,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+    tai64::Tai64,
 };
 
 pub trait SimpleBlockData: Send + Sync {
@@ -54,6 +61,14 @@ pub trait BlockQueryData: Send + Sync + SimpleBlockData {
     ) -> BoxedIter<StorageResult<CompressedBlock>>;
 
     fn consensus(&self, id: &BlockId) -> StorageResult<Consensus>;
+
+    /// Returns the gas limit used to produce the block at the `height`, if the block
+    /// was produced by this node.
+    fn block_gas_limit(&self, height: &BlockHeight) -> StorageResult<Option<Word>>;
+
+    /// Returns the height of the first block with the timestamp not less than the `time`.
+    fn first_block_height_since(&self, time: Tai64)
+        -> StorageResult<Option<BlockHeight>>;
 }
 
 impl<D: DatabasePort + ?Sized> BlockQueryData for D {
@@ -95,4 +110,40 @@ impl<D: DatabasePort + ?Sized> BlockQueryData for D {
             .map(|c| c.map(|c| c.into_owned()))?
             .ok_or(not_found!(SealedBlockConsensus))
     }
+
+    fn block_gas_limit(&self, height: &BlockHeight) -> StorageResult<Option<Word>> {
+        DatabaseBlocks::block_gas_limit(self, height)
+    }
+
+    fn first_block_height_since(
+        &self,
+        time: Tai64,
+    ) -> StorageResult<Option<BlockHeight>> {
+        let block_time = |height: u32| -> StorageResult<Tai64> {
+            let id = BlockQueryData::block_id(self, &height.into())?;
+            Ok(self.block(&id)?.header().time())
+        };
+
+        let mut low = match self.blocks_ids(None, IterDirection::Forward).next() {
+            Some(result) => *result?.0,
+            None => return Ok(None),
+        };
+        let mut high = *self.latest_block_height()?;
+        if block_time(high)? < time {
+            return Ok(None)
+        }
+
+        // The timestamps of the blocks are non-decreasing,
+        // so we can use the binary search by the height.
+        while low < high {
+            let middle = low.saturating_add(high.saturating_sub(low) / 2);
+            if block_time(middle)? < time {
+                low = middle.saturating_add(1);
+            } else {
+                high = middle;
+            }
+        }
+
+        Ok(Some(low.into()))
+    }
 }
@@ -1,4 +1,7 @@
-use crate::graphql_api::ports::DatabasePort;
+use crate::graphql_api::ports::{
+    DatabaseContracts,
+    DatabasePort,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -9,6 +12,7 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsInfo,
         ContractsRawCode,
+        ContractsState,
     },
     Result as StorageResult,
     StorageAsRef,
@@ -16,6 +20,8 @@ use fuel_core_storage::{
 use fuel_core_types::{
     fuel_types::{
         AssetId,
+        BlockHeight,
+        Bytes32,
         ContractId,
     },
     fuel_vm::Salt,
@@ -29,6 +35,8 @@ pub trait ContractQueryData: Send + Sync {
 
     fn contract_salt(&self, id: ContractId) -> StorageResult<Salt>;
 
+    fn contract_code_root(&self, id: ContractId) -> StorageResult<Bytes32>;
+
     fn contract_balance(
         &self,
         contract_id: ContractId,
@@ -41,6 +49,35 @@ pub trait ContractQueryData: Send + Sync {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn all_contract_balances(
+        &self,
+        start: Option<(ContractId, AssetId)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Bytes32>;
+
+    fn contract_storage_slots(
+        &self,
+        contract_id: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Bytes32)>>;
+
+    /// Returns the balance of the `asset_id` of the contract at the end of each block
+    /// within the `[from, to]` range, computed from the recorded balance changes.
+    fn contract_balance_series(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> StorageResult<Vec<(BlockHeight, u64)>>;
 }
 
 impl<D: DatabasePort + ?Sized> ContractQueryData for D {
@@ -73,6 +110,16 @@ impl<D: DatabasePort + ?Sized> ContractQueryData for D {
         Ok(salt)
     }
 
+    fn contract_code_root(&self, id: ContractId) -> StorageResult<Bytes32> {
+        let (_, root) = self
+            .storage::<ContractsInfo>()
+            .get(&id)?
+            .ok_or(not_found!(ContractsInfo))?
+            .into_owned();
+
+        Ok(root)
+    }
+
     fn contract_balance(
         &self,
         contract_id: ContractId,
@@ -99,4 +146,113 @@ impl<D: DatabasePort + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+
+    fn all_contract_balances(
+        &self,
+        start: Option<(ContractId, AssetId)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractBalance>> {
+        self.all_contract_balances(start, direction)
+    }
+
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Bytes32> {
+        let value = self
+            .storage::<ContractsState>()
+            .get(&(&contract_id, &key).into())?
+            .ok_or(not_found!(ContractsState))?
+            .into_owned();
+
+        Ok(value)
+    }
+
+    fn contract_storage_slots(
+        &self,
+        contract_id: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Bytes32)>> {
+        self.contract_storage_slots(contract_id, start_key, direction)
+    }
+
+    fn contract_balance_series(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> StorageResult<Vec<(BlockHeight, u64)>> {
+        if from > to {
+            return Ok(vec![])
+        }
+
+        let latest_before = DatabaseContracts::contract_balance_history(
+            self,
+            contract_id,
+            asset_id,
+            Some(from),
+            IterDirection::Reverse,
+        )
+        .next()
+        .transpose()?;
+        let changes = DatabaseContracts::contract_balance_history(
+            self,
+            contract_id,
+            asset_id,
+            Some(from),
+            IterDirection::Forward,
+        )
+        .take_while(|result| match result {
+            Ok((height, _)) => *height <= to,
+            Err(_) => true,
+        })
+        .collect::<StorageResult<Vec<_>>>()?;
+
+        // The balance at the `from` height is the result of the latest change before it.
+        // If there is no such change, the balance stayed the same until the first change
+        // in the range, or until the first change after the range. The current balance
+        // is used only if there are no changes after the `from` height at all.
+        let mut balance = match (latest_before, changes.first()) {
+            (Some((_, update)), _) => update.new,
+            (None, Some((_, update))) => update.old,
+            (None, None) => {
+                let first_after = match to.succ() {
+                    Some(after) => DatabaseContracts::contract_balance_history(
+                        self,
+                        contract_id,
+                        asset_id,
+                        Some(after),
+                        IterDirection::Forward,
+                    )
+                    .next()
+                    .transpose()?,
+                    None => None,
+                };
+                match first_after {
+                    Some((_, update)) => update.old,
+                    None => self
+                        .storage::<ContractsAssets>()
+                        .get(&(&contract_id, &asset_id).into())?
+                        .map(|amount| amount.into_owned())
+                        .unwrap_or_default(),
+                }
+            }
+        };
+
+        let mut changes = changes.into_iter().peekable();
+        let mut series = vec![];
+        for height in *from..=*to {
+            while let Some((_, update)) =
+                changes.next_if(|(changed_at, _)| **changed_at <= height)
+            {
+                balance = update.new;
+            }
+            series.push((height.into(), balance));
+        }
+
+        Ok(series)
+    }
 }
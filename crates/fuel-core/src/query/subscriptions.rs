@@ -68,7 +68,9 @@ where
             }
 
             match status {
-                TxStatusMessage::Status(status) => Ok(status.into()),
+                TxStatusMessage::Status(status) => {
+                    Ok(ApiTxStatus::new(transaction_id, status))
+                }
                 // Map a failed status to an error for the api.
                 TxStatusMessage::FailedStatus => {
                     Err(anyhow::anyhow!("Failed to get transaction status"))
@@ -22,7 +22,10 @@
 use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
     fuel_types::Bytes32,
-    services::txpool::TransactionStatus,
+    services::txpool::{
+        SqueezedOutKind,
+        TransactionStatus,
+    },
     tai64::Tai64,
 };
 use futures::StreamExt;
@@ -72,6 +75,14 @@ fn failed() -> TransactionStatus {
 fn squeezed() -> TransactionStatus {
     TransactionStatus::SqueezedOut {
         reason: fuel_core_txpool::Error::SqueezedOut(String::new()).to_string(),
+        kind: SqueezedOutKind::Removed,
+    }
+}
+
+/// Returns a TransactionStatus with Expired status at the height 0
+fn expired() -> TransactionStatus {
+    TransactionStatus::Expired {
+        expire_at: Default::default(),
     }
 }
 
@@ -97,6 +108,8 @@ enum FinalTxStatus {
     Squeezed,
     /// The transaction failed to execute and was included in a block.
     Failed,
+    /// The transaction wasn't included in a block by its expiry height.
+    Expired,
 }
 
 /// Strategy to generate an Option<TransactionStatus>
@@ -124,6 +137,7 @@ fn transaction_status() -> impl Strategy<Value = TransactionStatus> {
         Just(success()),
         Just(failed()),
         Just(squeezed()),
+        Just(expired()),
     ]
 }
 
@@ -202,6 +216,7 @@ fn next_state(state: TransactionStatus) -> Flow {
         TransactionStatus::Success { .. } => Flow::Break(FinalTxStatus::Success),
         TransactionStatus::Failed { .. } => Flow::Break(FinalTxStatus::Failed),
         TransactionStatus::SqueezedOut { .. } => Flow::Break(FinalTxStatus::Squeezed),
+        TransactionStatus::Expired { .. } => Flow::Break(FinalTxStatus::Expired),
     }
 }
 
@@ -272,6 +287,9 @@ impl From<crate::schema::tx::types::TransactionStatus> for TxStatus {
             crate::schema::tx::types::TransactionStatus::Failed(_) => {
                 TxStatus::Final(FinalTxStatus::Failed)
             }
+            crate::schema::tx::types::TransactionStatus::Expired(_) => {
+                TxStatus::Final(FinalTxStatus::Expired)
+            }
         }
     }
 }
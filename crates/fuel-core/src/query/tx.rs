@@ -1,4 +1,10 @@
-use crate::graphql_api::ports::DatabasePort;
+use crate::{
+    fuel_core_graphql_api::{
+        ports::DatabasePort,
+        IntoApiResult,
+    },
+    query::SimpleBlockData,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -10,17 +16,27 @@ use fuel_core_storage::{
         Receipts,
         Transactions,
     },
+    Error as StorageError,
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        header::BlockHeader,
+    },
+    entities::message::MerkleProof,
+    fuel_merkle::binary::in_memory::MerkleTree,
     fuel_tx::{
         Receipt,
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        canonical::Serialize,
+        Address,
+    },
     services::txpool::TransactionStatus,
 };
 
@@ -79,3 +95,65 @@ impl<D: DatabasePort + ?Sized> TransactionQueryData for D {
             .into_boxed()
     }
 }
+
+/// The proof that the transaction is included in the block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionProof {
+    /// The header of the block that contains the transaction.
+    pub block_header: BlockHeader,
+    /// The proof of the transaction against the `transactions_root` of the block.
+    pub proof: MerkleProof,
+}
+
+/// Builds the proof of the inclusion of the transaction into its block.
+///
+/// Returns `None` if the transaction is not included in any block yet.
+pub fn transaction_inclusion_proof<T>(
+    database: &T,
+    transaction_id: &TxId,
+) -> StorageResult<Option<TransactionProof>>
+where
+    T: TransactionQueryData + SimpleBlockData + ?Sized,
+{
+    let block_id = match database
+        .status(transaction_id)
+        .into_api_result::<TransactionStatus, StorageError>()?
+    {
+        Some(TransactionStatus::Success { block_id, .. })
+        | Some(TransactionStatus::Failed { block_id, .. }) => block_id,
+        _ => return Ok(None),
+    };
+
+    let (block_header, block_txs) = match database
+        .block(&block_id)
+        .into_api_result::<CompressedBlock, StorageError>()?
+    {
+        Some(block) => block.into_inner(),
+        None => return Ok(None),
+    };
+
+    // The transactions root is built from the serialized transactions.
+    let mut tree = MerkleTree::new();
+    let mut proof_index = None;
+
+    for (index, id) in block_txs.iter().enumerate() {
+        if id == transaction_id {
+            proof_index = Some(index as u64);
+        }
+
+        let bytes = database.transaction(id)?.to_bytes();
+        tree.push(bytes.as_ref());
+    }
+
+    let proof = proof_index.and_then(|proof_index| {
+        tree.prove(proof_index).map(|(_, proof_set)| MerkleProof {
+            proof_set,
+            proof_index,
+        })
+    });
+
+    Ok(proof.map(|proof| TransactionProof {
+        block_header,
+        proof,
+    }))
+}
@@ -5,25 +5,36 @@ use super::scalars::{
 use crate::{
     fuel_core_graphql_api::{
         service::{
+            BlockImporter,
+            BlockProducer,
             ConsensusModule,
             Database,
+            TxPool,
         },
         Config as GraphQLConfig,
     },
     graphql_api::IntoApiResult,
     query::{
+        AssetQueryData,
         BlockQueryData,
         SimpleBlockData,
         SimpleTransactionData,
+        TransactionQueryData,
     },
     schema::{
         scalars::{
+            AssetId,
             BlockId,
+            ContractId,
+            HexString,
             Signature,
             U32,
             U64,
         },
-        tx::types::Transaction,
+        tx::{
+            receipt::Receipt,
+            types::Transaction,
+        },
     },
 };
 use anyhow::anyhow;
@@ -35,6 +46,7 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -43,6 +55,7 @@ use fuel_core_storage::{
         IntoBoxedIter,
         IterDirection,
     },
+    Error as StorageError,
     Result as StorageResult,
 };
 use fuel_core_types::{
@@ -50,9 +63,51 @@ use fuel_core_types::{
         block::CompressedBlock,
         header::BlockHeader,
     },
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        field::{
+            InputContract,
+            MintAmount,
+            MintAssetId,
+        },
+        policies::Policies,
+        Input,
+        Mint,
+        Output,
+        Transaction as FuelTx,
+        TxPointer,
+        UniqueIdentifier,
+        UtxoId,
+    },
     fuel_types,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        canonical::Deserialize,
+        BlockHeight,
+    },
+    services::{
+        executor::ContractBalanceChange as CoreContractBalanceChange,
+        txpool::TransactionStatus,
+    },
+    tai64::Tai64,
+};
+use futures::Stream;
+use itertools::Itertools;
+use std::sync::{
+    atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    Arc,
 };
+use tokio_stream::StreamExt;
+
+/// The maximum number of the blocks in the range of the `blocks_crediting`.
+const MAX_CREDITING_RANGE_LENGTH: u32 = 10_000;
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -66,6 +121,7 @@ pub enum Consensus {
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
 type CoreConsensus = fuel_core_types::blockchain::consensus::Consensus;
+type CoreCoinbaseCredit = fuel_core_types::services::graphql_api::CoinbaseCredit;
 
 #[derive(SimpleObject)]
 pub struct Genesis {
@@ -84,6 +140,63 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+#[derive(SimpleObject)]
+pub struct ContractBalanceChange {
+    /// The height of the block that changed the balance.
+    pub block_height: U32,
+    /// The contract that owns the balance.
+    pub contract_id: ContractId,
+    /// The asset of the balance.
+    pub asset_id: AssetId,
+    /// The balance before the block.
+    pub old: U64,
+    /// The balance after the block.
+    pub new: U64,
+}
+
+#[derive(SimpleObject)]
+pub struct Coinbase {
+    /// The contract that received the fee. `null` if the fee is burned
+    /// because the block producer doesn't have the coinbase recipient.
+    pub recipient: Option<ContractId>,
+    /// The asset of the fee.
+    pub asset_id: AssetId,
+    /// The total fee amount credited to the recipient by the block.
+    pub amount: U64,
+}
+
+#[derive(SimpleObject)]
+pub struct CoinbaseCredit {
+    /// The height of the block that credited the recipient.
+    pub block_height: U32,
+    /// The asset of the fee.
+    pub asset_id: AssetId,
+    /// The fee amount credited to the recipient by the block.
+    pub amount: U64,
+}
+
+impl From<(BlockHeight, CoreCoinbaseCredit)> for CoinbaseCredit {
+    fn from((height, credit): (BlockHeight, CoreCoinbaseCredit)) -> Self {
+        CoinbaseCredit {
+            block_height: height.into(),
+            asset_id: credit.asset_id.into(),
+            amount: credit.amount.into(),
+        }
+    }
+}
+
+impl From<&Mint> for Coinbase {
+    fn from(mint: &Mint) -> Self {
+        let recipient = mint.input_contract().contract_id;
+        Self {
+            recipient: (recipient != fuel_types::ContractId::zeroed())
+                .then(|| recipient.into()),
+            asset_id: (*mint.mint_asset_id()).into(),
+            amount: (*mint.mint_amount()).into(),
+        }
+    }
+}
+
 #[Object]
 impl Block {
     async fn id(&self) -> BlockId {
@@ -117,6 +230,18 @@ impl Block {
             })
             .collect()
     }
+
+    /// The amount credited by the mint transaction of the block. `null` if the block
+    /// doesn't have the mint transaction, like the genesis block. The amount is zero
+    /// if the block didn't collect any fees.
+    async fn coinbase(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<Coinbase>> {
+        let query: &Database = ctx.data_unchecked();
+        let mint = mint_of_block(query, &self.0)?;
+        Ok(mint.as_ref().map(Into::into))
+    }
 }
 
 #[Object]
@@ -226,6 +351,191 @@ impl BlockQuery {
         })
         .await
     }
+
+    /// Returns the blocks with the timestamps within the `[from_time, to_time]` range.
+    async fn blocks_by_time_range(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The inclusive lower bound of the block timestamp")]
+        from_time: Tai64Timestamp,
+        #[graphql(desc = "The inclusive upper bound of the block timestamp")]
+        to_time: Tai64Timestamp,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, Block, EmptyFields, EmptyFields>> {
+        if from_time.0 > to_time.0 {
+            return Err(anyhow!("`fromTime` can't be greater than `toTime`").into())
+        }
+
+        let db: &Database = ctx.data_unchecked();
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            blocks_by_time_range_query(
+                db,
+                from_time.0,
+                to_time.0,
+                start.map(Into::into),
+                direction,
+            )
+        })
+        .await
+    }
+
+    /// Returns the recipient and the amount credited by the mint transaction of the block
+    /// at the `height`. Returns `null` if the block doesn't exist or doesn't have
+    /// the mint transaction, like the genesis block.
+    async fn coinbase(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<Coinbase>> {
+        let query: &Database = ctx.data_unchecked();
+        let height: u32 = height.into();
+        let mint = block_mint(query, height.into())?;
+        Ok(mint.as_ref().map(Into::into))
+    }
+
+    /// Returns the sum of the coinbase amounts credited to the `contract_id` by the
+    /// blocks up to and including the `up_to` height. Only the blocks executed by
+    /// the node since the coinbase credits are indexed are counted.
+    async fn accrued_coinbase(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coinbase recipient")] contract_id: ContractId,
+        #[graphql(desc = "The height of the last block to include")] up_to: U32,
+    ) -> async_graphql::Result<U64> {
+        let query: &Database = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        let up_to: u32 = up_to.into();
+        let latest_height: u32 = query.latest_block_height()?.into();
+        if up_to > latest_height {
+            return Err(anyhow!(
+                "The block at height {up_to} is not produced yet, the latest height is {latest_height}"
+            )
+            .into())
+        }
+
+        let accrued = query.accrued_coinbase(&contract_id.0, up_to.into())?;
+        Ok(accrued.into())
+    }
+
+    /// Returns the coinbase credited to the `recipient` by the blocks with the heights
+    /// within the `[from, to)` range. The blocks that didn't credit the `recipient`
+    /// are skipped. The range is capped by the latest height, and it can't contain
+    /// more than 10000 blocks. Only the blocks executed by the node since the coinbase
+    /// credits are indexed are counted.
+    async fn blocks_crediting(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coinbase recipient")] recipient: ContractId,
+        #[graphql(desc = "The inclusive lower bound of the block height")] from: U32,
+        #[graphql(desc = "The exclusive upper bound of the block height")] to: U32,
+    ) -> async_graphql::Result<Vec<CoinbaseCredit>> {
+        let query: &Database = ctx.data_unchecked();
+
+        let from: u32 = from.into();
+        let to: u32 = to.into();
+        if from > to {
+            return Err(anyhow!("`from` can't be greater than `to`").into())
+        }
+
+        // The blocks above the latest height are not produced yet.
+        let latest_height: u32 = query.latest_block_height()?.into();
+        let to = to.min(latest_height.saturating_add(1));
+        if to.saturating_sub(from) > MAX_CREDITING_RANGE_LENGTH {
+            return Err(anyhow!(
+                "The range can't contain more than {MAX_CREDITING_RANGE_LENGTH} blocks"
+            )
+            .into())
+        }
+
+        let credits = query
+            .coinbase_credits(&recipient.0, from.into(), to.into())
+            .map(|result| result.map(Into::into))
+            .try_collect()?;
+        Ok(credits)
+    }
+
+    /// Returns the latest `limit` coinbase credits of the `recipient` ordered from
+    /// the newest to the oldest. The blocks that didn't credit the `recipient`
+    /// are skipped. Only the blocks executed by the node since the coinbase credits
+    /// are indexed are counted.
+    async fn recent_coinbase_credits(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coinbase recipient")] recipient: ContractId,
+        #[graphql(desc = "The maximum number of the credits")] limit: U32,
+    ) -> async_graphql::Result<Vec<CoinbaseCredit>> {
+        let query: &Database = ctx.data_unchecked();
+
+        let limit = usize::try_from(u32::from(limit))?;
+        let credits = query
+            .recent_coinbase_credits(&recipient.0)
+            .take(limit)
+            .map(|result| result.map(Into::into))
+            .try_collect()?;
+        Ok(credits)
+    }
+
+    /// Returns the coinbase that the next block would credit if it was produced now
+    /// from the transactions in the `TxPool`. The block is executed without the commit.
+    async fn pending_coinbase(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Coinbase> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        let mint = block_producer
+            .pending_coinbase(config.block_gas_limit)
+            .await?;
+        Ok((&mint).into())
+    }
+}
+
+/// Returns the mint transaction of the block at `height`. Returns `None` if the block
+/// doesn't exist or has no transactions, as the genesis block.
+fn block_mint(
+    query: &Database,
+    height: fuel_types::BlockHeight,
+) -> async_graphql::Result<Option<Mint>> {
+    let block = match query
+        .block_id(&height)
+        .and_then(|id| query.block(&id))
+        .into_api_result::<CompressedBlock, StorageError>()?
+    {
+        Some(block) => block,
+        None => return Ok(None),
+    };
+
+    mint_of_block(query, &block)
+}
+
+fn mint_of_block(
+    query: &Database,
+    block: &CompressedBlock,
+) -> async_graphql::Result<Option<Mint>> {
+    // The mint transaction is always the last one in the block.
+    let mint_id = match block.transactions().last() {
+        Some(id) => *id,
+        None => return Ok(None),
+    };
+    match query.transaction(&mint_id)? {
+        FuelTx::Mint(mint) => Ok(Some(mint)),
+        _ => Err(
+            anyhow!("The last transaction {mint_id} of the block is not a mint").into(),
+        ),
+    }
 }
 
 #[derive(Default)]
@@ -277,6 +587,58 @@ where
     blocks.into_boxed()
 }
 
+fn blocks_by_time_range_query(
+    query: &Database,
+    from_time: Tai64,
+    to_time: Tai64,
+    start: Option<BlockHeight>,
+    direction: IterDirection,
+) -> StorageResult<BoxedIter<StorageResult<(U32, Block)>>> {
+    // Without the cursor, we start from the first block within the range
+    // at the side of the iteration.
+    let start = match (start, direction) {
+        (Some(start), _) => Some(start),
+        (None, IterDirection::Forward) => {
+            match query.first_block_height_since(from_time)? {
+                Some(height) => Some(height),
+                None => return Ok(core::iter::empty().into_boxed()),
+            }
+        }
+        (None, IterDirection::Reverse) => {
+            let after_range = Tai64(to_time.0.saturating_add(1));
+            match query.first_block_height_since(after_range)? {
+                Some(height) => match height.pred() {
+                    Some(height) => Some(height),
+                    None => return Ok(core::iter::empty().into_boxed()),
+                },
+                None => None,
+            }
+        }
+    };
+
+    let blocks = query
+        .compressed_blocks(start, direction)
+        .take_while(move |result| match result {
+            Ok(block) => match direction {
+                IterDirection::Forward => block.header().time() <= to_time,
+                IterDirection::Reverse => block.header().time() >= from_time,
+            },
+            Err(_) => true,
+        })
+        .filter(move |result| match result {
+            Ok(block) => {
+                let time = block.header().time();
+                from_time <= time && time <= to_time
+            }
+            Err(_) => true,
+        })
+        .map(|result| {
+            result.map(|block| ((*block.header().height()).into(), block.into()))
+        });
+
+    Ok(blocks.into_boxed())
+}
+
 #[derive(Default)]
 pub struct BlockMutation;
 
@@ -299,6 +661,7 @@ impl BlockMutation {
         if !config.debug {
             return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
         }
+        require_writable(ctx)?;
 
         let start_time = start_timestamp.map(|timestamp| timestamp.0);
         let blocks_to_produce: u32 = blocks_to_produce.into();
@@ -311,6 +674,361 @@ impl BlockMutation {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// Produces the next block with the `txs` in the specified order instead of the
+    /// transactions from the `TxPool`. The transactions are not submitted to the `TxPool`,
+    /// and the invalid ones are skipped by the executor. The `start_timestamp` is
+    /// the timestamp of the block in seconds.
+    async fn produce_block_with_txs(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        start_timestamp: Option<Tai64Timestamp>,
+    ) -> async_graphql::Result<U32> {
+        let query: &Database = ctx.data_unchecked();
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+        require_writable(ctx)?;
+
+        let transactions = txs
+            .iter()
+            .map(|tx| FuelTx::from_bytes(&tx.0))
+            .collect::<Result<Vec<_>, _>>()?;
+        let start_time = start_timestamp.map(|timestamp| timestamp.0);
+        consensus_module
+            .manually_produce_block_with_transactions(start_time, transactions)
+            .await?;
+
+        query
+            .latest_block_height()
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Produces the next block from the transactions in the `TxPool` only if its coinbase
+    /// exceeds the `threshold` of the base asset. Returns the height of the produced block,
+    /// or `null` if the block is not produced. The coinbase is zero if the block producer
+    /// doesn't have the coinbase recipient.
+    async fn produce_block_if_coinbase_over(
+        &self,
+        ctx: &Context<'_>,
+        threshold: U64,
+    ) -> async_graphql::Result<Option<U32>> {
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+        require_writable(ctx)?;
+
+        // The block is produced and checked by the consensus module at once,
+        // so the coinbase can't change between the check and the production.
+        let height = consensus_module
+            .manually_produce_block_if_coinbase_over(
+                config.consensus_parameters.base_asset_id,
+                threshold.into(),
+            )
+            .await?;
+        Ok(height.map(Into::into))
+    }
+
+    /// Re-executes the transactions of the block at the `height` on top of the current
+    /// state as the next block without committing it, and returns the receipts of each
+    /// transaction, followed by the receipts of the mint transaction crediting the coinbase.
+    ///
+    /// The replay uses the timestamp and the gas limit of the original block. The gas limit
+    /// is known only for the blocks produced by this node, the replay of other blocks uses
+    /// the block gas limit of the chain.
+    ///
+    /// The inputs spent by the block are already spent, so the replay of the blocks with
+    /// coins requires the disabled UTXO validation.
+    async fn replay_block(
+        &self,
+        ctx: &Context<'_>,
+        height: U32,
+    ) -> async_graphql::Result<Vec<Vec<Receipt>>> {
+        let query: &Database = ctx.data_unchecked();
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        let height: BlockHeight = height.0.into();
+        let block = query.block(&query.block_id(&height)?)?;
+        let transactions: Vec<FuelTx> = block
+            .transactions()
+            .iter()
+            .map(|id| query.transaction(id))
+            .filter_ok(|tx| !tx.is_mint())
+            .try_collect()?;
+
+        let gas_limit = query
+            .block_gas_limit(&height)?
+            .unwrap_or(config.block_gas_limit);
+
+        let receipts = block_producer
+            .replay_transactions(transactions, block.header().time(), gas_limit)
+            .await?;
+        Ok(receipts
+            .into_iter()
+            .map(|receipts| receipts.into_iter().map(Receipt).collect())
+            .collect())
+    }
+
+    /// Replaces the gas limit of the blocks produced after the call. The `limit` can't
+    /// exceed the block gas limit of the chain.
+    async fn set_block_gas_limit(
+        &self,
+        ctx: &Context<'_>,
+        limit: U64,
+    ) -> async_graphql::Result<bool> {
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+        let limit: u64 = limit.into();
+        if limit > config.block_gas_limit {
+            return Err(anyhow!(
+                "The limit {limit} exceeds the block gas limit of the chain {}",
+                config.block_gas_limit
+            )
+            .into())
+        }
+
+        consensus_module.set_block_gas_limit(limit).await?;
+        Ok(true)
+    }
+
+    /// Transfers `amount` of `asset_id` to the `contract_id` and produces a block with
+    /// this transfer. The transferred coin is not backed by any UTXO, so it is only available
+    /// if the `debug` is enabled and the UTXO validation is disabled.
+    async fn credit_coinbase(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        asset_id: AssetId,
+        amount: U64,
+    ) -> async_graphql::Result<U32> {
+        let query: &Database = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+        if config.utxo_validation {
+            return Err(
+                anyhow!("`utxo_validation` must be disabled to use this endpoint").into(),
+            )
+        }
+        require_writable(ctx)?;
+
+        // Leave room for the gas charged for the size of the transaction.
+        let gas_limit = config.consensus_parameters.tx_params().max_gas_per_tx / 2;
+        let tx = credit_tx(
+            query.latest_block_height()?,
+            contract_id.0,
+            asset_id.0,
+            amount.0,
+            gas_limit,
+        );
+        let tx_id = tx.id(&config.consensus_parameters.chain_id);
+
+        let _: Vec<_> = txpool
+            .insert(vec![Arc::new(tx)])
+            .await
+            .into_iter()
+            .try_collect()?;
+        consensus_module.manually_produce_blocks(None, 1).await?;
+
+        match query
+            .status(&tx_id)
+            .into_api_result::<TransactionStatus, StorageError>()?
+        {
+            Some(TransactionStatus::Success { .. }) => {}
+            Some(TransactionStatus::Failed { reason, .. }) => {
+                return Err(anyhow!("The credit transaction failed: {reason}").into())
+            }
+            _ => {
+                return Err(
+                    anyhow!("The credit transaction is not included in the block").into(),
+                )
+            }
+        }
+
+        query
+            .latest_block_height()
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+/// Rejects the requests that require writing into the database
+/// when the node is a read replica or is in the read-only mode.
+pub(crate) fn require_writable(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    let block_importer = ctx.data_unchecked::<BlockImporter>();
+
+    if config.read_only {
+        Err(anyhow!(
+            "ReadOnlyNode: The node is a read replica and doesn't accept transactions"
+        )
+        .into())
+    } else if block_importer.is_read_only() {
+        Err(
+            anyhow!("The node is in the read-only mode after repeated write failures")
+                .into(),
+        )
+    } else {
+        Ok(())
+    }
+}
+
+/// The counter of the `credit_tx` calls used to derive the unique coins.
+static CREDIT_NONCE: AtomicU64 = AtomicU64::new(0);
+
+/// Builds a script that transfers the `amount` of the `asset_id` from the unverified coin
+/// to the `contract_id`. The script doesn't pay any fee, so the transfer is the only
+/// change of the contract balance in the block.
+fn credit_tx(
+    latest_height: BlockHeight,
+    contract_id: fuel_types::ContractId,
+    asset_id: fuel_types::AssetId,
+    amount: u64,
+    gas_limit: u64,
+) -> FuelTx {
+    let contract_id_register = 0x10;
+    let asset_id_register = 0x11;
+    let amount_register = 0x12;
+    let script = vec![
+        op::gtf_args(contract_id_register, 0x00, GTFArgs::ScriptData),
+        op::addi(
+            asset_id_register,
+            contract_id_register,
+            fuel_types::ContractId::LEN as u16,
+        ),
+        op::addi(
+            amount_register,
+            asset_id_register,
+            fuel_types::AssetId::LEN as u16,
+        ),
+        op::lw(amount_register, amount_register, 0),
+        op::tr(contract_id_register, amount_register, asset_id_register),
+        op::ret(RegId::ONE),
+    ];
+    let script_data: Vec<u8> = contract_id
+        .iter()
+        .chain(asset_id.iter())
+        .chain(amount.to_be_bytes().iter())
+        .copied()
+        .collect();
+
+    // The concurrent calls may see the same height, so the nonce makes the coin unique.
+    let nonce = CREDIT_NONCE.fetch_add(1, Ordering::Relaxed);
+    let utxo_id = UtxoId::new(
+        Hasher::default()
+            .chain(latest_height.to_bytes())
+            .chain(nonce.to_be_bytes())
+            .finalize(),
+        0,
+    );
+    let coin = Input::coin_signed(
+        utxo_id,
+        Default::default(),
+        amount,
+        asset_id,
+        TxPointer::default(),
+        0,
+        Default::default(),
+    );
+    let contract = Input::contract(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        contract_id,
+    );
+
+    FuelTx::script(
+        gas_limit,
+        script.into_iter().collect(),
+        script_data,
+        Policies::new().with_gas_price(0),
+        vec![coin, contract],
+        vec![Output::contract(1, Default::default(), Default::default())],
+        vec![Vec::new().into()],
+    )
+    .into()
+}
+
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of headers of the newly imported blocks.
+    ///
+    /// It is possible for the stream to skip headers if it is polled slower
+    /// than the blocks arrive.
+    async fn new_block_headers<'a>(
+        &self,
+        ctx: &Context<'a>,
+    ) -> impl Stream<Item = Header> + 'a {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        block_importer
+            .block_events()
+            .map(|result| result.sealed_block.entity.header().clone().into())
+    }
+
+    /// Returns a stream of the contracts' balance changes caused by the newly imported
+    /// blocks. If the `contract_id` is set, only the changes of its balances are returned.
+    ///
+    /// It is possible for the stream to skip changes if it is polled slower
+    /// than the blocks arrive.
+    async fn contract_balance_changes<'a>(
+        &self,
+        ctx: &Context<'a>,
+        contract_id: Option<ContractId>,
+    ) -> impl Stream<Item = ContractBalanceChange> + 'a {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        let contract_id = contract_id.map(|id| id.0);
+        futures::StreamExt::flat_map(block_importer.block_events(), move |result| {
+            let changes = result
+                .contract_balance_changes
+                .iter()
+                .filter(|change| {
+                    contract_id
+                        .map(|id| id == change.contract_id)
+                        .unwrap_or(true)
+                })
+                .copied()
+                .map(Into::into)
+                .collect_vec();
+            futures::stream::iter(changes)
+        })
+    }
+}
+
+impl From<CoreContractBalanceChange> for ContractBalanceChange {
+    fn from(change: CoreContractBalanceChange) -> Self {
+        ContractBalanceChange {
+            block_height: change.block_height.into(),
+            contract_id: change.contract_id.into(),
+            asset_id: change.asset_id.into(),
+            old: change.old.into(),
+            new: change.new.into(),
+        }
+    }
 }
 
 impl From<CompressedBlock> for Block {
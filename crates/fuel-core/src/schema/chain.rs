@@ -4,6 +4,7 @@ use crate::{
         Config as GraphQLConfig,
     },
     query::{
+        AssetQueryData,
         BlockQueryData,
         ChainQueryData,
     },
@@ -11,6 +12,7 @@ use crate::{
         block::Block,
         scalars::{
             AssetId,
+            U128,
             U32,
             U64,
             U8,
@@ -22,9 +24,18 @@ use async_graphql::{
     Object,
     Union,
 };
-use fuel_core_types::fuel_tx;
+use fuel_core_chain_config::{
+    find_asset,
+    AssetConfig,
+};
+use fuel_core_types::{
+    fuel_tx,
+    fuel_types::BlockHeight,
+    services::graphql_api,
+};
 
 pub struct ChainInfo;
+pub struct ChainConfig;
 pub struct ConsensusParameters(fuel_tx::ConsensusParameters);
 pub struct TxParameters(fuel_tx::TxParameters);
 pub struct PredicateParameters(fuel_tx::PredicateParameters);
@@ -34,6 +45,10 @@ pub struct FeeParameters(fuel_tx::FeeParameters);
 
 pub struct GasCosts(fuel_tx::GasCosts);
 
+pub struct AssetInfo(AssetConfig);
+
+pub struct AssetSupplyDelta(BlockHeight, graphql_api::AssetSupplyDelta);
+
 #[derive(Union)]
 pub enum DependentCost {
     LightOperation(LightOperation),
@@ -722,6 +737,75 @@ impl ChainInfo {
 
         Ok(GasCosts(config.consensus_parameters.gas_costs.clone()))
     }
+
+    /// The maximum gas that the transactions of one block can consume.
+    async fn block_gas_limit(&self, ctx: &Context<'_>) -> U64 {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        config.block_gas_limit.into()
+    }
+}
+
+#[Object]
+impl ChainConfig {
+    async fn chain_name(&self, ctx: &Context<'_>) -> async_graphql::Result<String> {
+        let data: &Database = ctx.data_unchecked();
+        Ok(data.name()?)
+    }
+
+    /// The maximum gas that the transactions of one block can consume, as configured
+    /// for the chain.
+    async fn block_gas_limit(&self, ctx: &Context<'_>) -> U64 {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        config.block_gas_limit.into()
+    }
+
+    async fn consensus_parameters(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<ConsensusParameters> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        Ok(ConsensusParameters(config.consensus_parameters.clone()))
+    }
+}
+
+#[Object]
+impl AssetInfo {
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    async fn name(&self) -> &str {
+        self.0.name.as_str()
+    }
+
+    async fn symbol(&self) -> &str {
+        self.0.symbol.as_str()
+    }
+
+    /// The number of decimals used to display the amounts of the asset.
+    async fn decimals(&self) -> U8 {
+        self.0.decimals.into()
+    }
+}
+
+#[Object]
+impl AssetSupplyDelta {
+    async fn height(&self) -> U32 {
+        self.0.into()
+    }
+
+    /// The amount of the asset minted within the block.
+    async fn minted(&self) -> U64 {
+        self.1.minted.into()
+    }
+
+    /// The amount of the asset burned within the block.
+    async fn burned(&self) -> U64 {
+        self.1.burned.into()
+    }
 }
 
 #[derive(Default)]
@@ -732,4 +816,53 @@ impl ChainQuery {
     async fn chain(&self) -> ChainInfo {
         ChainInfo
     }
+
+    /// Returns the metadata of the asset registered in the chain configuration.
+    async fn asset_info(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Asset")] asset_id: AssetId,
+    ) -> Option<AssetInfo> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        find_asset(&config.assets, &asset_id.0)
+            .cloned()
+            .map(AssetInfo)
+    }
+
+    /// Returns the amounts of the asset minted and burned per block in the
+    /// `[fromHeight, toHeight]` range. The blocks that didn't change the supply are skipped.
+    async fn asset_supply_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Asset")] asset_id: AssetId,
+        #[graphql(desc = "The height of the first block in the range")] from_height: U32,
+        #[graphql(desc = "The height of the last block in the range")] to_height: U32,
+    ) -> async_graphql::Result<Vec<AssetSupplyDelta>> {
+        let query: &Database = ctx.data_unchecked();
+
+        let history = query
+            .asset_supply_history(&asset_id.0, from_height.into(), to_height.into())
+            .map(|result| result.map(|(height, delta)| AssetSupplyDelta(height, delta)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(history)
+    }
+
+    /// Returns the total amount of the asset minted by the coinbase of all blocks.
+    /// The blocks executed before the node started tracking the total are not counted.
+    async fn total_coinbase_minted(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Asset")] asset_id: AssetId,
+    ) -> async_graphql::Result<U128> {
+        let query: &Database = ctx.data_unchecked();
+
+        Ok(query.total_coinbase_minted(&asset_id.0)?.into())
+    }
+
+    /// Returns the consensus-relevant part of the chain configuration the node was
+    /// started with.
+    async fn chain_config(&self) -> ChainConfig {
+        ChainConfig
+    }
 }
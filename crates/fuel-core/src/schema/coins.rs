@@ -1,6 +1,7 @@
 use crate::{
     coins_query::{
         random_improve,
+        CoinsQueryError,
         SpendQuery,
     },
     fuel_core_graphql_api::{
@@ -27,6 +28,7 @@ use async_graphql::{
         EmptyFields,
     },
     Context,
+    ErrorExtensions,
 };
 use fuel_core_types::{
     entities::{
@@ -242,7 +244,8 @@ impl CoinQuery {
 
         let db = ctx.data_unchecked::<Database>();
 
-        let coins = random_improve(db, &spend_query)?
+        let coins = random_improve(db, &spend_query)
+            .map_err(|e| e.extend())?
             .into_iter()
             .map(|coins| {
                 coins
@@ -259,6 +262,48 @@ impl CoinQuery {
 
         Ok(coins)
     }
+
+    /// Returns the number of the `owner`'s coins of the `asset_id` required to cover
+    /// the `amount`, selected in the same way as by the `coins_to_spend`. The selection
+    /// is random, so the number may differ between calls. Returns an error if the owner
+    /// doesn't have enough coins or the number exceeds the `max_inputs`.
+    async fn inputs_required(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The asset of the coins.")] asset_id: AssetId,
+        #[graphql(desc = "The amount that the coins should cover.")] amount: U64,
+    ) -> async_graphql::Result<U32> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let db = ctx.data_unchecked::<Database>();
+
+        let max_inputs = config.consensus_parameters.tx_params().max_inputs;
+        let target = AssetSpendTarget::new(asset_id.0, amount.0, usize::from(max_inputs));
+        let base_asset_id = config.consensus_parameters.base_asset_id();
+        let spend_query = SpendQuery::new(owner.0, &[target], None, *base_asset_id)?;
+        let coins = random_improve(db, &spend_query).map_err(|e| e.extend())?;
+        let inputs = coins.first().map(Vec::len).unwrap_or_default();
+
+        Ok(u32::try_from(inputs).unwrap_or(u32::MAX).into())
+    }
+}
+
+/// The `code` in the extensions of the error returned when the coins of the owner
+/// can't cover the query.
+const RESOURCES_EXHAUSTED: &str = "RESOURCES_EXHAUSTED";
+
+impl ErrorExtensions for CoinsQueryError {
+    fn extend(&self) -> async_graphql::Error {
+        async_graphql::Error::new(self.to_string()).extend_with(|_, extensions| {
+            if matches!(
+                self,
+                CoinsQueryError::InsufficientCoins { .. }
+                    | CoinsQueryError::MaxCoinsReached
+            ) {
+                extensions.set("code", RESOURCES_EXHAUSTED);
+            }
+        })
+    }
 }
 
 impl From<CoinModel> for Coin {
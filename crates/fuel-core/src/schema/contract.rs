@@ -1,17 +1,27 @@
 use crate::{
+    fee_collection::is_fee_collection_contract,
     fuel_core_graphql_api::{
         service::Database,
+        Config as GraphQLConfig,
         IntoApiResult,
     },
-    query::ContractQueryData,
+    query::{
+        BlockQueryData,
+        ContractQueryData,
+    },
     schema::scalars::{
+        Address,
         AssetId,
+        Bytes32,
+        ContractBalanceCursor,
         ContractId,
         HexString,
         Salt,
+        U32,
         U64,
     },
 };
+use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -20,12 +30,16 @@ use async_graphql::{
     Context,
     InputObject,
     Object,
+    SimpleObject,
 };
 use fuel_core_types::{
     fuel_types,
     services::graphql_api,
 };
 
+/// The maximum number of the blocks in the range of the `contract_balance_series`.
+const MAX_BALANCE_SERIES_LENGTH: u32 = 10_000;
+
 pub struct Contract(pub(crate) fuel_types::ContractId);
 
 impl From<fuel_types::ContractId> for Contract {
@@ -55,6 +69,15 @@ impl Contract {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// The Merkle root of the bytecode, so the code can be verified without downloading it.
+    async fn code_root(&self, ctx: &Context<'_>) -> async_graphql::Result<Bytes32> {
+        let context: &Database = ctx.data_unchecked();
+        context
+            .contract_code_root(self.0)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
 }
 
 #[derive(Default)]
@@ -70,6 +93,106 @@ impl ContractQuery {
         let data: &Database = ctx.data_unchecked();
         data.contract_id(id.0).into_api_result()
     }
+
+    /// Returns the value of the storage slot at the `key` of the `contract`,
+    /// or `null` if the slot is not set. The node keeps only the latest state,
+    /// so the `height` other than the latest one is rejected.
+    async fn contract_storage_slot(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "The key of the storage slot")] key: Bytes32,
+        #[graphql(desc = "The height of the block, the latest one by default")]
+        height: Option<U32>,
+    ) -> async_graphql::Result<Option<Bytes32>> {
+        let query: &Database = ctx.data_unchecked();
+
+        if let Some(height) = height {
+            let height: u32 = height.into();
+            let latest_height: u32 = query.latest_block_height()?.into();
+            if height != latest_height {
+                return Err(anyhow!(
+                    "The storage is available only at the latest height {latest_height}, but requested at height {height}"
+                )
+                .into())
+            }
+        }
+
+        query
+            .contract_storage_slot(contract.0, key.0)
+            .into_api_result()
+    }
+
+    /// Returns the storage slots of the `contract` ordered by the key.
+    async fn contract_storage_slots(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<Bytes32, ContractStorageSlot, EmptyFields, EmptyFields>,
+    > {
+        let query: &Database = ctx.data_unchecked();
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let slots = query
+                .contract_storage_slots(
+                    contract.into(),
+                    (*start).map(Into::into),
+                    direction,
+                )
+                .map(|slot| {
+                    let (key, value) = slot?;
+
+                    Ok((
+                        key.into(),
+                        ContractStorageSlot {
+                            key: key.into(),
+                            value: value.into(),
+                        },
+                    ))
+                });
+
+            Ok(slots)
+        })
+        .await
+    }
+
+    /// Returns the ids of the deployed fee collection contracts withdrawing to
+    /// the `recipient`, ordered by the id. All deployed contracts are scanned.
+    async fn fee_collection_contracts(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The address receiving the withdrawn fees")] recipient: Address,
+    ) -> async_graphql::Result<Vec<ContractId>> {
+        let query: &Database = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        let mut contracts = vec![];
+        for contract_id in query.contract_ids() {
+            let contract_id = contract_id?;
+            let code = query.contract_bytecode(contract_id)?;
+            if is_fee_collection_contract(&code) == Some(recipient.0) {
+                contracts.push(contract_id.into());
+            }
+        }
+        Ok(contracts)
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ContractStorageSlot {
+    /// The key of the storage slot.
+    key: Bytes32,
+    /// The value stored in the slot.
+    value: Bytes32,
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
@@ -89,6 +212,14 @@ impl ContractBalance {
     }
 }
 
+#[derive(SimpleObject)]
+pub struct ContractBalanceAtHeight {
+    /// The height of the block.
+    height: U32,
+    /// The balance of the contract at the end of the block.
+    amount: U64,
+}
+
 #[derive(InputObject)]
 struct ContractBalanceFilterInput {
     /// Filter assets based on the `contractId` field
@@ -155,6 +286,104 @@ impl ContractBalanceQuery {
         })
         .await
     }
+
+    /// Returns the nonzero balances of all contracts ordered by the contract and
+    /// the asset. The node keeps only the latest state, so the `height` other than
+    /// the latest one is rejected.
+    async fn all_contract_balances(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the block, the latest one by default")]
+        height: Option<U32>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<ContractBalanceCursor, ContractBalance, EmptyFields, EmptyFields>,
+    > {
+        let query: &Database = ctx.data_unchecked();
+
+        if let Some(height) = height {
+            let height: u32 = height.into();
+            let latest_height: u32 = query.latest_block_height()?.into();
+            if height != latest_height {
+                return Err(anyhow!(
+                    "The balances are available only at the latest height {latest_height}, but requested at height {height}"
+                )
+                .into())
+            }
+        }
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<ContractBalanceCursor>, direction| {
+                let start = (*start)
+                    .map(|cursor| (cursor.contract_id.into(), cursor.asset_id.into()));
+                let balances = query
+                    .all_contract_balances(start, direction)
+                    .filter(
+                        |balance| !matches!(balance, Ok(balance) if balance.amount == 0),
+                    )
+                    .map(|balance| {
+                        let balance = balance?;
+                        let cursor = ContractBalanceCursor::new(
+                            balance.owner.into(),
+                            balance.asset_id.into(),
+                        );
+
+                        Ok((cursor, balance.into()))
+                    });
+
+                Ok(balances)
+            },
+        )
+        .await
+    }
+
+    /// Returns the balance of the asset of the contract at the end of each block in the
+    /// `[fromHeight, toHeight]` range. The range is capped by the latest height, and it
+    /// can't contain more than 10000 blocks. The balances are computed from the changes
+    /// indexed by the node, so only the blocks executed by the node with the balance
+    /// history support are covered.
+    async fn contract_balance_series(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        asset: AssetId,
+        #[graphql(desc = "The height of the first block in the range")] from_height: U32,
+        #[graphql(desc = "The height of the last block in the range")] to_height: U32,
+    ) -> async_graphql::Result<Vec<ContractBalanceAtHeight>> {
+        let query: &Database = ctx.data_unchecked();
+
+        let latest_height = query.latest_block_height()?;
+        let from_height = fuel_types::BlockHeight::from(from_height);
+        let to_height = fuel_types::BlockHeight::from(to_height).min(latest_height);
+        let length = (*to_height).saturating_sub(*from_height).saturating_add(1);
+        if from_height <= to_height && length > MAX_BALANCE_SERIES_LENGTH {
+            return Err(anyhow!(
+                "The range can't contain more than {MAX_BALANCE_SERIES_LENGTH} blocks"
+            )
+            .into())
+        }
+        let series = query
+            .contract_balance_series(
+                contract.into(),
+                asset.into(),
+                from_height,
+                to_height,
+            )?
+            .into_iter()
+            .map(|(height, amount)| ContractBalanceAtHeight {
+                height: height.into(),
+                amount: amount.into(),
+            })
+            .collect();
+        Ok(series)
+    }
 }
 
 impl From<graphql_api::ContractBalance> for ContractBalance {
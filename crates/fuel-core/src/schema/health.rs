@@ -1,4 +1,58 @@
-use async_graphql::Object;
+use crate::{
+    fuel_core_graphql_api::{
+        service::{
+            ConsensusModule,
+            Database,
+            P2pService,
+            TxPool,
+        },
+        Config as GraphQLConfig,
+    },
+    query::BlockQueryData,
+    schema::{
+        block::require_writable,
+        scalars::U32,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+
+pub struct NodeHealth {
+    txpool_accepting: bool,
+    producer_running: bool,
+    p2p_connected_peers: U32,
+    db_height: U32,
+}
+
+#[Object]
+impl NodeHealth {
+    /// Always true when the GraphQL API is serving requests.
+    async fn up(&self) -> bool {
+        true
+    }
+
+    /// Returns true when the transaction pool accepts new transactions.
+    async fn txpool_accepting(&self) -> bool {
+        self.txpool_accepting
+    }
+
+    /// Returns true when the node produces blocks.
+    async fn producer_running(&self) -> bool {
+        self.producer_running
+    }
+
+    /// The number of the peers connected to the node.
+    async fn p2p_connected_peers(&self) -> U32 {
+        self.p2p_connected_peers
+    }
+
+    /// The height of the latest block in the database.
+    async fn db_height(&self) -> U32 {
+        self.db_height
+    }
+}
 
 #[derive(Default)]
 pub struct HealthQuery;
@@ -9,4 +63,26 @@ impl HealthQuery {
     async fn health(&self) -> bool {
         true
     }
+
+    /// Returns the readiness of the node's services.
+    async fn node_health(&self, ctx: &Context<'_>) -> async_graphql::Result<NodeHealth> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let database = ctx.data_unchecked::<Database>();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let p2p_service = ctx.data_unchecked::<P2pService>();
+
+        let txpool_accepting =
+            require_writable(ctx).is_ok() && txpool.pending_number() < config.max_tx;
+        let connected_peers = p2p_service.connected_peers_count().await;
+
+        Ok(NodeHealth {
+            txpool_accepting,
+            producer_running: consensus_module.is_block_production_enabled(),
+            p2p_connected_peers: u32::try_from(connected_peers)
+                .unwrap_or(u32::MAX)
+                .into(),
+            db_height: database.latest_block_height()?.into(),
+        })
+    }
 }
@@ -1,9 +1,23 @@
-use super::scalars::U64;
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use super::scalars::{
+    ContractId,
+    Tai64Timestamp,
+    U32,
+    U64,
+};
+use crate::fuel_core_graphql_api::{
+    BlockProducerConfig,
+    Config as GraphQLConfig,
+    RelayerConfig,
+    TxPoolConfig,
+};
 use async_graphql::{
     Context,
+    Enum,
     Object,
 };
+use fuel_core_metrics::producer_metrics::producer_metrics;
+use fuel_core_poa::Trigger;
+use fuel_core_types::tai64::Tai64;
 
 pub struct NodeInfo {
     utxo_validation: bool,
@@ -12,6 +26,8 @@ pub struct NodeInfo {
     max_tx: U64,
     max_depth: U64,
     node_version: String,
+    finalization_depth: U32,
+    max_dry_run_gas: Option<U64>,
 }
 
 #[Object]
@@ -39,6 +55,194 @@ impl NodeInfo {
     async fn node_version(&self) -> String {
         self.node_version.to_owned()
     }
+
+    /// The number of blocks on top of the block before its balances are considered final.
+    async fn finalization_depth(&self) -> U32 {
+        self.finalization_depth
+    }
+
+    /// The maximum `script_gas_limit` of the transaction accepted by the `dryRun`.
+    /// `null` if the dry run is limited only by the gas limit of the transaction.
+    async fn max_dry_run_gas(&self) -> Option<U64> {
+        self.max_dry_run_gas
+    }
+}
+
+/// The effective configuration of the internal services.
+pub struct ServiceConfig {
+    txpool: TxPoolServiceConfig,
+    block_producer: BlockProducerServiceConfig,
+    relayer: Option<RelayerServiceConfig>,
+}
+
+#[Object]
+impl ServiceConfig {
+    async fn txpool(&self) -> &TxPoolServiceConfig {
+        &self.txpool
+    }
+
+    async fn block_producer(&self) -> &BlockProducerServiceConfig {
+        &self.block_producer
+    }
+
+    /// The configuration of the relayer, if it is enabled.
+    async fn relayer(&self) -> Option<&RelayerServiceConfig> {
+        self.relayer.as_ref()
+    }
+}
+
+pub struct TxPoolServiceConfig(TxPoolConfig);
+
+#[Object]
+impl TxPoolServiceConfig {
+    async fn max_tx(&self) -> U64 {
+        (self.0.max_tx as u64).into()
+    }
+
+    async fn max_depth(&self) -> U64 {
+        (self.0.max_depth as u64).into()
+    }
+
+    async fn min_gas_price(&self) -> U64 {
+        self.0.min_gas_price.into()
+    }
+
+    async fn utxo_validation(&self) -> bool {
+        self.0.utxo_validation
+    }
+
+    async fn metrics(&self) -> bool {
+        self.0.metrics
+    }
+
+    /// The time to live of the transaction in the pool in milliseconds.
+    async fn transaction_ttl(&self) -> U64 {
+        u64::try_from(self.0.transaction_ttl.as_millis())
+            .unwrap_or(u64::MAX)
+            .into()
+    }
+
+    async fn number_of_active_subscription(&self) -> U64 {
+        (self.0.number_of_active_subscription as u64).into()
+    }
+}
+
+pub struct BlockProducerServiceConfig(BlockProducerConfig);
+
+#[Object]
+impl BlockProducerServiceConfig {
+    async fn utxo_validation(&self) -> bool {
+        self.0.utxo_validation
+    }
+
+    async fn coinbase_recipient(&self) -> Option<ContractId> {
+        self.0.coinbase_recipient.map(Into::into)
+    }
+
+    async fn metrics(&self) -> bool {
+        self.0.metrics
+    }
+}
+
+pub struct RelayerServiceConfig(RelayerConfig);
+
+#[Object]
+impl RelayerServiceConfig {
+    async fn da_deploy_height(&self) -> U64 {
+        self.0.da_deploy_height.into()
+    }
+
+    async fn eth_v2_listening_contracts(&self) -> Vec<String> {
+        self.0.eth_v2_listening_contracts.clone()
+    }
+
+    async fn log_page_size(&self) -> U64 {
+        self.0.log_page_size.into()
+    }
+
+    /// The minimum duration of the relayer loop in milliseconds.
+    async fn sync_minimum_duration(&self) -> U64 {
+        u64::try_from(self.0.sync_minimum_duration.as_millis())
+            .unwrap_or(u64::MAX)
+            .into()
+    }
+
+    /// The frequency of the calls to the syncing DA node in milliseconds.
+    async fn syncing_call_frequency(&self) -> U64 {
+        u64::try_from(self.0.syncing_call_frequency.as_millis())
+            .unwrap_or(u64::MAX)
+            .into()
+    }
+
+    /// The frequency of the progress logs of the syncing DA node in milliseconds.
+    async fn syncing_log_frequency(&self) -> U64 {
+        u64::try_from(self.0.syncing_log_frequency.as_millis())
+            .unwrap_or(u64::MAX)
+            .into()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum BlockProductionMode {
+    /// A new block is produced as soon as the transactions are available.
+    Instant,
+    /// A new block is produced periodically, every `block_time`.
+    Interval,
+    /// The node doesn't produce blocks on its own. The blocks are produced
+    /// only by the `produceBlocks` mutation or imported from the network.
+    Manual,
+}
+
+/// The configuration of the block production by the node.
+pub struct BlockProductionConfig(Trigger);
+
+#[Object]
+impl BlockProductionConfig {
+    async fn mode(&self) -> BlockProductionMode {
+        match self.0 {
+            Trigger::Instant => BlockProductionMode::Instant,
+            Trigger::Interval { .. } => BlockProductionMode::Interval,
+            Trigger::Never => BlockProductionMode::Manual,
+        }
+    }
+
+    /// The time between the blocks in milliseconds if the mode is `INTERVAL`.
+    async fn block_time(&self) -> Option<U64> {
+        match self.0 {
+            Trigger::Interval { block_time } => Some(
+                u64::try_from(block_time.as_millis())
+                    .unwrap_or(u64::MAX)
+                    .into(),
+            ),
+            Trigger::Instant | Trigger::Never => None,
+        }
+    }
+}
+
+/// The statistics of the block production collected by the metrics of the node.
+pub struct ProducerMetrics {
+    blocks_produced: U64,
+    average_coinbase: U64,
+    last_production_time: Option<Tai64Timestamp>,
+}
+
+#[Object]
+impl ProducerMetrics {
+    /// The number of blocks produced by the node since the start.
+    async fn blocks_produced(&self) -> U64 {
+        self.blocks_produced
+    }
+
+    /// The average coinbase of the blocks produced by the node since the start.
+    async fn average_coinbase(&self) -> U64 {
+        self.average_coinbase
+    }
+
+    /// The time when the node produced the latest block.
+    /// `null` if the node hasn't produced blocks since the start.
+    async fn last_production_time(&self) -> Option<Tai64Timestamp> {
+        self.last_production_time
+    }
 }
 
 #[derive(Default)]
@@ -58,6 +262,52 @@ impl NodeQuery {
             max_tx: (config.max_tx as u64).into(),
             max_depth: (config.max_depth as u64).into(),
             node_version: VERSION.to_owned(),
+            finalization_depth: config.finalization_depth.into(),
+            max_dry_run_gas: config.block_producer.max_dry_run_gas.map(Into::into),
+        })
+    }
+
+    /// Returns the effective configuration of the internal services without secrets.
+    async fn service_config(&self, ctx: &Context<'_>) -> ServiceConfig {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        ServiceConfig {
+            txpool: TxPoolServiceConfig(config.txpool.clone()),
+            block_producer: BlockProducerServiceConfig(config.block_producer.clone()),
+            relayer: config.relayer.clone().map(RelayerServiceConfig),
+        }
+    }
+
+    /// Returns how the node produces the blocks: instantly, periodically, or manually.
+    async fn block_production_config(&self, ctx: &Context<'_>) -> BlockProductionConfig {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        BlockProductionConfig(config.block_production)
+    }
+
+    /// Returns the statistics of the block production since the start of the node.
+    /// `null` if the metrics are disabled on the node.
+    async fn producer_metrics(&self, ctx: &Context<'_>) -> Option<ProducerMetrics> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.block_producer.metrics {
+            return None
+        }
+
+        let metrics = producer_metrics();
+        let blocks_produced = metrics.blocks_produced.get();
+        let average_coinbase = metrics
+            .total_coinbase
+            .get()
+            .checked_div(blocks_produced)
+            .unwrap_or_default();
+        let last_production_time = match metrics.latest_block_production_timestamp.get() {
+            0 => None,
+            timestamp => Some(Tai64Timestamp(Tai64::from_unix(timestamp))),
+        };
+
+        Some(ProducerMetrics {
+            blocks_produced: blocks_produced.into(),
+            average_coinbase: average_coinbase.into(),
+            last_production_time,
         })
     }
 }
@@ -79,6 +79,7 @@ macro_rules! number_scalar {
     };
 }
 
+number_scalar!(U128, u128, "U128");
 number_scalar!(U64, u64, "U64");
 number_scalar!(U32, u32, "U32");
 number_scalar!(U8, u8, "U8");
@@ -156,6 +157,40 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The cursor of the balances of all contracts, ordered by the contract and the asset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContractBalanceCursor {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+}
+
+impl ContractBalanceCursor {
+    pub fn new(contract_id: ContractId, asset_id: AssetId) -> Self {
+        Self {
+            contract_id,
+            asset_id,
+        }
+    }
+}
+
+impl CursorType for ContractBalanceCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let (contract_id, asset_id) =
+            s.split_once('#').ok_or("Incorrect format provided")?;
+
+        Ok(Self::new(
+            ContractId::decode_cursor(contract_id)?,
+            AssetId::decode_cursor(asset_id)?,
+        ))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}", self.contract_id, self.asset_id)
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
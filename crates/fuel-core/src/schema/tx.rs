@@ -1,25 +1,44 @@
 use crate::{
     fuel_core_graphql_api::{
         service::{
+            BlockImporter,
             BlockProducer,
             Database,
             TxPool,
         },
         IntoApiResult,
     },
-    graphql_api::Config,
+    graphql_api::{
+        idempotency::IdempotencyKeys,
+        Config,
+    },
     query::{
+        transaction_inclusion_proof,
         transaction_status_change,
         BlockQueryData,
+        CoinQueryData,
+        SimpleBlockData,
         SimpleTransactionData,
         TransactionQueryData,
     },
-    schema::scalars::{
-        Address,
-        HexString,
-        SortedTxCursor,
-        TransactionId,
-        TxPointer,
+    schema::{
+        block::{
+            require_writable,
+            Coinbase,
+        },
+        coins::Coin,
+        scalars::{
+            Address,
+            Bytes32,
+            ContractId,
+            HexString,
+            SortedTxCursor,
+            TransactionId,
+            TxPointer,
+            UtxoId,
+            U32,
+            U64,
+        },
     },
 };
 use async_graphql::{
@@ -30,6 +49,7 @@ use async_graphql::{
     Context,
     Object,
     Subscription,
+    Union,
 };
 use fuel_core_storage::{
     iter::IterDirection,
@@ -42,6 +62,8 @@ use fuel_core_txpool::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        self,
+        field::Outputs,
         Cacheable,
         Transaction as FuelTx,
         UniqueIdentifier,
@@ -56,12 +78,18 @@ use futures::{
     TryStreamExt,
 };
 use itertools::Itertools;
+use output::CreatedUtxo;
+use receipt::ReceiptType;
 use std::{
     iter,
+    ops::Deref,
     sync::Arc,
 };
 use tokio_stream::StreamExt;
-use types::Transaction;
+use types::{
+    Transaction,
+    TransactionProof,
+};
 
 use self::types::TransactionStatus;
 use fuel_core_types::fuel_vm::checked_transaction::CheckPredicateParams;
@@ -71,6 +99,63 @@ pub mod output;
 pub mod receipt;
 pub mod types;
 
+/// The result of the submission with the idempotency key.
+#[derive(Union)]
+pub enum IdempotentSubmission {
+    Transaction(Transaction),
+    DuplicateSubmission(DuplicateSubmission),
+}
+
+/// The submission is rejected because the idempotency key was already used.
+pub struct DuplicateSubmission {
+    original_transaction_id: TransactionId,
+}
+
+#[Object]
+impl DuplicateSubmission {
+    /// The id of the transaction submitted with the idempotency key first.
+    async fn original_transaction_id(&self) -> TransactionId {
+        self.original_transaction_id
+    }
+}
+
+pub struct TxPoolStats {
+    tx_count: U64,
+    total_gas: U64,
+}
+
+#[Object]
+impl TxPoolStats {
+    /// The number of the transactions in the `TxPool`.
+    async fn tx_count(&self) -> U64 {
+        self.tx_count
+    }
+
+    /// The max gas of all transactions in the `TxPool` combined.
+    async fn total_gas(&self) -> U64 {
+        self.total_gas
+    }
+
+    /// The coinbase that the next block would credit if it was produced now.
+    /// It is `null` if the `debug` mode is disabled on the node.
+    async fn estimated_coinbase(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<Coinbase>> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<Config>();
+
+        if !config.debug {
+            return Ok(None)
+        }
+
+        let mint = block_producer
+            .pending_coinbase(config.block_gas_limit)
+            .await?;
+        Ok(Some((&mint).into()))
+    }
+}
+
 #[derive(Default)]
 pub struct TxQuery;
 
@@ -193,6 +278,139 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the pending transactions from the `TxPool` that the transaction depends on,
+    /// directly or transitively. The list is empty if the transaction is not in the `TxPool`
+    /// or doesn't depend on other pending transactions.
+    async fn transaction_dependencies(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> Vec<Transaction> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+
+        txpool
+            .transaction_dependencies(id.0)
+            .into_iter()
+            .map(|tx| {
+                let id = tx.id(&config.consensus_parameters.chain_id);
+                Transaction(tx, id)
+            })
+            .collect()
+    }
+
+    /// Returns the origin of the pending transaction: the hex-encoded id of the peer
+    /// that gossiped it, or `local` if it was submitted to this node directly.
+    /// Returns `null` if the transaction is not in the `TxPool`.
+    async fn transaction_source(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> Option<String> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+
+        txpool.transaction_source(id.0).map(|source| match source {
+            txpool::TransactionSource::Local => "local".to_string(),
+            txpool::TransactionSource::Peer(peer_id) => {
+                HexString(peer_id.into()).to_string()
+            }
+        })
+    }
+
+    /// Returns the pending transactions from the `TxPool` that use the coin as an input.
+    /// The `TxPool` keeps only one spender of the coin, replacing the cheaper ones,
+    /// so the list contains at most one transaction.
+    async fn pending_spenders_of(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the coin")] utxo_id: UtxoId,
+    ) -> Vec<Transaction> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+
+        txpool
+            .coin_spenders(utxo_id.0)
+            .into_iter()
+            .map(|tx| {
+                let id = tx.id(&config.consensus_parameters.chain_id);
+                Transaction(tx, id)
+            })
+            .collect()
+    }
+
+    /// Returns the number and the total gas of the transactions in the `TxPool`.
+    async fn tx_pool_stats(&self, ctx: &Context<'_>) -> TxPoolStats {
+        let txpool = ctx.data_unchecked::<TxPool>();
+
+        TxPoolStats {
+            tx_count: (txpool.pending_number() as u64).into(),
+            total_gas: txpool.total_consumable_gas().into(),
+        }
+    }
+
+    /// Returns the proof that the transaction is included in the block, verifiable
+    /// against the `transactionsRoot` of the block header. Returns `null` if the
+    /// transaction is not included in any block.
+    async fn transaction_inclusion_proof(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<TransactionProof>> {
+        let query: &Database = ctx.data_unchecked();
+        let proof = transaction_inclusion_proof(query.deref(), &id.0)?;
+        Ok(proof.map(Into::into))
+    }
+
+    /// Returns the coins created by the outputs of the committed transaction together with
+    /// their UTXO ids. Returns `null` if the transaction is not committed.
+    async fn transaction_created_utxos(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<Vec<CreatedUtxo>>> {
+        let query: &Database = ctx.data_unchecked();
+        let id = id.0;
+
+        query
+            .transaction(&id)
+            .map(|tx| match tx {
+                FuelTx::Script(script) => CreatedUtxo::from_outputs(id, script.outputs()),
+                FuelTx::Create(create) => CreatedUtxo::from_outputs(id, create.outputs()),
+                FuelTx::Mint(_) => vec![],
+            })
+            .into_api_result()
+    }
+
+    /// Returns the coins created by the outputs of the committed transaction that are
+    /// not spent yet. Returns `null` if the transaction is not committed.
+    async fn transaction_unspent_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<Vec<Coin>>> {
+        let query: &Database = ctx.data_unchecked();
+        let id = id.0;
+
+        let created_utxos = match query.transaction(&id) {
+            Ok(FuelTx::Script(script)) => CreatedUtxo::from_outputs(id, script.outputs()),
+            Ok(FuelTx::Create(create)) => CreatedUtxo::from_outputs(id, create.outputs()),
+            Ok(FuelTx::Mint(_)) => vec![],
+            Err(StorageError::NotFound(_, _)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let coins = created_utxos
+            .into_iter()
+            .map(|utxo| {
+                query
+                    .coin(utxo.utxo_id)
+                    .into_api_result::<Coin, StorageError>()
+            })
+            .filter_map_ok(|coin| coin)
+            .try_collect()?;
+        Ok(Some(coins))
+    }
+
     /// Estimate the predicate gas for the provided transaction
     async fn estimate_predicates(
         &self,
@@ -258,6 +476,7 @@ impl TxMutation {
         ctx: &Context<'_>,
         tx: HexString,
     ) -> async_graphql::Result<Transaction> {
+        require_writable(ctx)?;
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
         let tx = FuelTx::from_bytes(&tx.0)?;
@@ -272,6 +491,66 @@ impl TxMutation {
         let tx = Transaction(tx, id);
         Ok(tx)
     }
+
+    /// Submits transaction to the `TxPool` if the `idempotency_key` wasn't used by
+    /// another submission recently. Otherwise, returns the id of the transaction
+    /// submitted with the `idempotency_key` first without submitting the transaction.
+    ///
+    /// The key can be reused if the submission failed.
+    async fn submit_with_idempotency(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        idempotency_key: Bytes32,
+    ) -> async_graphql::Result<IdempotentSubmission> {
+        require_writable(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+        let idempotency_keys = ctx.data_unchecked::<IdempotencyKeys>();
+        let tx = FuelTx::from_bytes(&tx.0)?;
+        let id = tx.id(&config.consensus_parameters.chain_id);
+        let key = idempotency_key.into();
+
+        if let Err(original_id) = idempotency_keys.reserve(key, id) {
+            return Ok(IdempotentSubmission::DuplicateSubmission(
+                DuplicateSubmission {
+                    original_transaction_id: original_id.into(),
+                },
+            ))
+        }
+
+        let result: anyhow::Result<Vec<_>> = txpool
+            .insert(vec![Arc::new(tx.clone())])
+            .await
+            .into_iter()
+            .try_collect();
+        if let Err(err) = result {
+            idempotency_keys.release(&key, &id);
+            return Err(err.into())
+        }
+
+        Ok(IdempotentSubmission::Transaction(Transaction(tx, id)))
+    }
+
+    /// Removes the pending transaction from the `TxPool`, along with the transactions
+    /// depending on it. Returns `false` if the transaction is not in the `TxPool`.
+    /// Requires the `debug` mode on the node.
+    async fn remove_from_txpool(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<bool> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+
+        if !config.debug {
+            return Err(
+                anyhow::anyhow!("`debug` must be enabled to use this endpoint").into(),
+            )
+        }
+
+        Ok(txpool.remove(id.0))
+    }
 }
 
 #[derive(Default)]
@@ -327,31 +606,128 @@ impl TxStatusSubscription {
     ) -> async_graphql::Result<
         impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a,
     > {
-        let txpool = ctx.data_unchecked::<TxPool>();
-        let config = ctx.data_unchecked::<Config>();
-        let tx = FuelTx::from_bytes(&tx.0)?;
-        let tx_id = tx.id(&config.consensus_parameters.chain_id);
-        let subscription = txpool.tx_update_subscribe(tx_id)?;
+        submit_and_await_status(ctx, tx, None).await
+    }
 
-        let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx)])
-            .await
-            .into_iter()
-            .try_collect()?;
+    /// Submits transaction to the `TxPool` and await either confirmation or failure.
+    /// The transaction is dropped from the `TxPool` with the `ExpiredStatus` if it is
+    /// not included in a block by the `expire_at` height.
+    async fn submit_and_await_with_expiry<'a>(
+        &self,
+        ctx: &Context<'a>,
+        tx: HexString,
+        expire_at: U32,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a,
+    > {
+        submit_and_await_status(ctx, tx, Some(expire_at.into())).await
+    }
 
-        Ok(subscription
-            .skip_while(|event| {
-                matches!(
-                    event,
-                    TxStatusMessage::Status(txpool::TransactionStatus::Submitted { .. })
-                )
-            })
-            .map(|event| match event {
-                TxStatusMessage::Status(status) => Ok(status.into()),
-                TxStatusMessage::FailedStatus => {
-                    Err(anyhow::anyhow!("Failed to get transaction status").into())
-                }
-            })
-            .take(1))
+    /// Returns a stream of the transactions from the newly imported blocks that
+    /// produced at least one receipt matching the filter. The receipt matches if it has
+    /// the `receipt_type` and touches the contract with the `contract_id`: the contract
+    /// emitted the receipt, is the target of the call or the transfer, or minted or
+    /// burned the asset. The unset parts of the filter match any receipt.
+    ///
+    /// It is possible for the stream to skip transactions if it is polled slower
+    /// than the blocks arrive.
+    async fn transactions_with_receipt<'a>(
+        &self,
+        ctx: &Context<'a>,
+        receipt_type: Option<ReceiptType>,
+        contract_id: Option<ContractId>,
+    ) -> impl Stream<Item = async_graphql::Result<Transaction>> + 'a {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        let query = ctx.data_unchecked::<Database>();
+        let chain_id = ctx.data_unchecked::<Config>().consensus_parameters.chain_id;
+        let contract_id = contract_id.map(|id| id.0);
+        let matches = move |receipt: &fuel_tx::Receipt| {
+            let type_matches = receipt_type
+                .map(|receipt_type| receipt_type == ReceiptType::from(receipt))
+                .unwrap_or(true);
+            let contract_matches = contract_id
+                .map(|id| {
+                    [receipt.id(), receipt.to(), receipt.contract_id()]
+                        .contains(&Some(&id))
+                })
+                .unwrap_or(true);
+            type_matches && contract_matches
+        };
+
+        futures::StreamExt::flat_map(block_importer.block_events(), move |result| {
+            let transactions = result
+                .sealed_block
+                .entity
+                .transactions()
+                .iter()
+                .filter_map(|tx| {
+                    let id = tx.id(&chain_id);
+                    let receipts = match query
+                        .receipts(&id)
+                        .into_api_result::<Vec<_>, async_graphql::Error>()
+                    {
+                        Ok(receipts) => receipts.unwrap_or_default(),
+                        Err(err) => return Some(Err(err)),
+                    };
+                    receipts
+                        .iter()
+                        .any(&matches)
+                        .then(|| Ok(Transaction::from_tx(id, tx.clone())))
+                })
+                .collect_vec();
+            futures::stream::iter(transactions)
+        })
+    }
+}
+
+async fn submit_and_await_status<'a>(
+    ctx: &Context<'a>,
+    tx: HexString,
+    expire_at: Option<fuel_types::BlockHeight>,
+) -> async_graphql::Result<
+    impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a,
+> {
+    require_writable(ctx)?;
+    let txpool = ctx.data_unchecked::<TxPool>();
+    let config = ctx.data_unchecked::<Config>();
+    let tx = FuelTx::from_bytes(&tx.0)?;
+    let tx_id = tx.id(&config.consensus_parameters.chain_id);
+
+    if let Some(expire_at) = expire_at {
+        let query = ctx.data_unchecked::<Database>();
+        let latest_height = query.latest_block_height()?;
+        if expire_at <= latest_height {
+            return Err(anyhow::anyhow!(
+                "The transaction expiring at the height {expire_at} can't be included \
+                after the latest block at the height {latest_height}"
+            )
+            .into())
+        }
     }
+
+    let subscription = txpool.tx_update_subscribe(tx_id)?;
+
+    let _: Vec<_> = txpool
+        .insert(vec![Arc::new(tx)])
+        .await
+        .into_iter()
+        .try_collect()?;
+    if let Some(expire_at) = expire_at {
+        txpool.set_expiration(tx_id, expire_at);
+    }
+
+    Ok(subscription
+        .skip_while(|event| {
+            matches!(
+                event,
+                TxStatusMessage::Status(txpool::TransactionStatus::Submitted { .. })
+            )
+        })
+        .map(|event| match event {
+            TxStatusMessage::Status(status) => Ok(TransactionStatus::new(tx_id, status)),
+            TxStatusMessage::FailedStatus => {
+                Err(anyhow::anyhow!("Failed to get transaction status").into())
+            }
+        })
+        .take(1))
 }
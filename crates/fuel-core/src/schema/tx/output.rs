@@ -4,10 +4,12 @@ use crate::schema::{
         Address,
         AssetId,
         Bytes32,
+        UtxoId,
         U64,
     },
 };
 use async_graphql::{
+    Enum,
     Object,
     Union,
 };
@@ -175,3 +177,91 @@ impl From<&output::contract::Contract> for ContractOutput {
         }
     }
 }
+
+#[derive(Copy, Clone, Debug, Enum, Eq, PartialEq)]
+pub enum CoinOutputType {
+    Coin,
+    Change,
+    Variable,
+}
+
+/// The coin created by the output of the committed transaction.
+pub struct CreatedUtxo {
+    pub(crate) utxo_id: fuel_tx::UtxoId,
+    output_type: CoinOutputType,
+    coin: CoinOutput,
+}
+
+#[Object]
+impl CreatedUtxo {
+    async fn utxo_id(&self) -> UtxoId {
+        self.utxo_id.into()
+    }
+
+    async fn output_type(&self) -> CoinOutputType {
+        self.output_type
+    }
+
+    async fn owner(&self) -> Address {
+        self.coin.to.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.coin.amount.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.coin.asset_id.into()
+    }
+}
+
+impl CreatedUtxo {
+    /// Returns the coins created by the `outputs` of the transaction with `tx_id`.
+    /// The outputs without amount don't create coins, so they are skipped.
+    pub fn from_outputs(
+        tx_id: fuel_types::Bytes32,
+        outputs: &[fuel_tx::Output],
+    ) -> Vec<CreatedUtxo> {
+        outputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| {
+                let (output_type, to, amount, asset_id) = match output {
+                    fuel_tx::Output::Coin {
+                        to,
+                        amount,
+                        asset_id,
+                    } => (CoinOutputType::Coin, to, amount, asset_id),
+                    fuel_tx::Output::Change {
+                        to,
+                        amount,
+                        asset_id,
+                    } => (CoinOutputType::Change, to, amount, asset_id),
+                    fuel_tx::Output::Variable {
+                        to,
+                        amount,
+                        asset_id,
+                    } => (CoinOutputType::Variable, to, amount, asset_id),
+                    fuel_tx::Output::Contract(_)
+                    | fuel_tx::Output::ContractCreated { .. } => return None,
+                };
+
+                if *amount == 0 {
+                    return None
+                }
+
+                let index = u8::try_from(index)
+                    .expect("Transaction can have only up to `u8::MAX` outputs");
+                Some(CreatedUtxo {
+                    utxo_id: fuel_tx::UtxoId::new(tx_id, index),
+                    output_type,
+                    coin: CoinOutput {
+                        to: *to,
+                        amount: *amount,
+                        asset_id: *asset_id,
+                    },
+                })
+            })
+            .collect()
+    }
+}
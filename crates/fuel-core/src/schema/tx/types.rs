@@ -13,14 +13,20 @@ use crate::{
         IntoApiResult,
     },
     query::{
+        self,
         SimpleBlockData,
         SimpleTransactionData,
         TransactionQueryData,
     },
     schema::{
-        block::Block,
+        block::{
+            Block,
+            Header,
+        },
         contract::Contract,
+        message::MerkleProof,
         scalars::{
+            Address,
             AssetId,
             Bytes32,
             HexString,
@@ -41,6 +47,7 @@ use async_graphql::{
     Context,
     Enum,
     Object,
+    SimpleObject,
     Union,
 };
 use fuel_core_storage::Error as StorageError;
@@ -68,11 +75,26 @@ use fuel_core_types::{
             TxPointer as TxPointerField,
             Witnesses,
         },
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+            },
+        },
         policies::PolicyType,
         Chargeable,
         Executable,
+        TransactionFee,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+        Word,
     },
-    fuel_types::canonical::Serialize,
     fuel_vm::ProgramState as VmProgramState,
     services::{
         txpool,
@@ -80,6 +102,7 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+use std::sync::OnceLock;
 
 pub struct ProgramState {
     return_type: ReturnType,
@@ -132,6 +155,7 @@ pub enum TransactionStatus {
     Success(SuccessStatus),
     SqueezedOut(SqueezedOutStatus),
     Failed(FailureStatus),
+    Expired(ExpiredStatus),
 }
 
 #[derive(Debug)]
@@ -146,9 +170,11 @@ impl SubmittedStatus {
 
 #[derive(Debug)]
 pub struct SuccessStatus {
+    tx_id: fuel_tx::TxId,
     block_id: primitives::BlockId,
     time: Tai64,
     result: Option<VmProgramState>,
+    fee: ExecutionFee,
 }
 
 #[Object]
@@ -166,14 +192,108 @@ impl SuccessStatus {
     async fn program_state(&self) -> Option<ProgramState> {
         self.result.map(Into::into)
     }
+
+    /// The gas spent by the execution of the script.
+    async fn gas_used(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        Ok(self.fee.gas_used_and_total_fee(ctx, &self.tx_id)?.0.into())
+    }
+
+    /// The fee paid by the transaction after the refund of the unused gas.
+    async fn total_fee(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        Ok(self.fee.gas_used_and_total_fee(ctx, &self.tx_id)?.1.into())
+    }
+
+    /// The outcome of the script derived from the receipts of the transaction.
+    async fn script_result(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<ScriptResult> {
+        let query: &Database = ctx.data_unchecked();
+        let receipts = query.receipts(&self.tx_id)?;
+        Ok(ScriptResult::from_receipts(&receipts))
+    }
+
+    /// The variable outputs of the transaction with the values assigned during the execution.
+    async fn variable_outputs(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<output::VariableOutput>> {
+        let query: &Database = ctx.data_unchecked();
+        let tx = query.transaction(&self.tx_id)?;
+        let outputs = match &tx {
+            fuel_tx::Transaction::Script(script) => script.outputs().as_slice(),
+            fuel_tx::Transaction::Create(create) => create.outputs().as_slice(),
+            fuel_tx::Transaction::Mint(_) => &[],
+        };
+        Ok(outputs
+            .iter()
+            .filter_map(|output| match Output::from(output) {
+                Output::Variable(variable) => Some(variable),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ScriptResult {
+    /// The value returned by the `RET` instruction of the script. `null` if the script
+    /// returned the data by the `RETD` instruction or there is no script.
+    return_value: Option<U64>,
+    /// `true` if the script or one of the contracts called by it reverted, or one of
+    /// the calls didn't end with the `RET` or `RETD` instruction of the called contract.
+    reverted: bool,
+    /// `true` if the script or one of the called contracts transferred any coins.
+    transferred: bool,
+}
+
+impl ScriptResult {
+    fn from_receipts(receipts: &[fuel_tx::Receipt]) -> Self {
+        use fuel_tx::Receipt;
+
+        // The receipts of the script itself have the zeroed contract id.
+        let return_value = receipts.iter().find_map(|receipt| match receipt {
+            Receipt::Return { id, val, .. } if *id == fuel_tx::ContractId::zeroed() => {
+                Some((*val).into())
+            }
+            _ => None,
+        });
+        let returned_from = |contract_id: &fuel_tx::ContractId| {
+            receipts.iter().any(|receipt| match receipt {
+                Receipt::Return { id, .. } | Receipt::ReturnData { id, .. } => {
+                    id == contract_id
+                }
+                _ => false,
+            })
+        };
+        let reverted = receipts.iter().any(|receipt| match receipt {
+            Receipt::Revert { .. } | Receipt::Panic { .. } => true,
+            Receipt::Call { to, .. } => !returned_from(to),
+            _ => false,
+        });
+        let transferred = receipts.iter().any(|receipt| {
+            matches!(
+                receipt,
+                Receipt::Transfer { .. } | Receipt::TransferOut { .. }
+            )
+        });
+
+        Self {
+            return_value,
+            reverted,
+            transferred,
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct FailureStatus {
+    tx_id: fuel_tx::TxId,
     block_id: primitives::BlockId,
     time: Tai64,
     reason: String,
     state: Option<VmProgramState>,
+    fee: ExecutionFee,
 }
 
 #[Object]
@@ -195,11 +315,86 @@ impl FailureStatus {
     async fn program_state(&self) -> Option<ProgramState> {
         self.state.map(Into::into)
     }
+
+    /// The gas spent by the execution of the script.
+    async fn gas_used(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        Ok(self.fee.gas_used_and_total_fee(ctx, &self.tx_id)?.0.into())
+    }
+
+    /// The fee paid by the transaction after the refund of the unused gas.
+    async fn total_fee(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        Ok(self.fee.gas_used_and_total_fee(ctx, &self.tx_id)?.1.into())
+    }
+}
+
+/// The gas used and the fee paid by the executed transaction, calculated at most once
+/// per status, so the query requesting both fields loads the receipts only once.
+#[derive(Debug, Default)]
+struct ExecutionFee(OnceLock<(Word, Word)>);
+
+impl ExecutionFee {
+    fn gas_used_and_total_fee(
+        &self,
+        ctx: &Context<'_>,
+        tx_id: &fuel_tx::TxId,
+    ) -> async_graphql::Result<(Word, Word)> {
+        if let Some(fee) = self.0.get() {
+            return Ok(*fee)
+        }
+        let fee = gas_used_and_total_fee(ctx, tx_id)?;
+        Ok(*self.0.get_or_init(|| fee))
+    }
+}
+
+/// Calculates the gas used and the fee paid by the executed transaction from its
+/// receipts in the same way as the executor does it. The `Mint` transaction is free.
+fn gas_used_and_total_fee(
+    ctx: &Context<'_>,
+    tx_id: &fuel_tx::TxId,
+) -> async_graphql::Result<(Word, Word)> {
+    let query: &Database = ctx.data_unchecked();
+    let config = ctx.data_unchecked::<Config>();
+    let receipts = query.receipts(tx_id)?;
+    let gas_used = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            fuel_tx::Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    let total_fee = match query.transaction(tx_id)? {
+        fuel_tx::Transaction::Script(script) => total_fee(&script, config, gas_used),
+        fuel_tx::Transaction::Create(create) => total_fee(&create, config, gas_used),
+        fuel_tx::Transaction::Mint(_) => Some(0),
+    }
+    .ok_or_else(|| anyhow::anyhow!("The fee of the transaction {tx_id:#x} overflows"))?;
+
+    Ok((gas_used, total_fee))
+}
+
+fn total_fee<Tx: Chargeable>(tx: &Tx, config: &Config, gas_used: Word) -> Option<Word> {
+    let gas_costs = config.consensus_parameters.gas_costs();
+    let fee_params = config.consensus_parameters.fee_params();
+    let max_fee = TransactionFee::checked_from_tx(gas_costs, fee_params, tx)?.max_fee();
+    let refund = tx.refund_fee(gas_costs, fee_params, gas_used)?;
+    max_fee.checked_sub(refund)
 }
 
 #[derive(Debug)]
 pub struct SqueezedOutStatus {
     pub reason: String,
+    pub kind: txpool::SqueezedOutKind,
+}
+
+/// The machine-readable kind of the reason why the transaction was squeezed out.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SqueezedOutKind {
+    /// Evicted from the full `TxPool` by the transaction paying the higher gas price.
+    Evicted,
+    /// Removed for another reason, like the replacement by the transaction spending
+    /// the same inputs or the invalidated dependency.
+    Removed,
 }
 
 #[Object]
@@ -207,10 +402,30 @@ impl SqueezedOutStatus {
     async fn reason(&self) -> String {
         self.reason.clone()
     }
+
+    async fn kind(&self) -> SqueezedOutKind {
+        match self.kind {
+            txpool::SqueezedOutKind::Evicted => SqueezedOutKind::Evicted,
+            txpool::SqueezedOutKind::Removed => SqueezedOutKind::Removed,
+        }
+    }
 }
 
-impl From<TxStatus> for TransactionStatus {
-    fn from(s: TxStatus) -> Self {
+#[derive(Debug)]
+pub struct ExpiredStatus {
+    pub expire_at: BlockHeight,
+}
+
+#[Object]
+impl ExpiredStatus {
+    /// The last height the transaction could be included at.
+    async fn expire_at(&self) -> U32 {
+        self.expire_at.into()
+    }
+}
+
+impl TransactionStatus {
+    pub fn new(tx_id: fuel_tx::TxId, s: TxStatus) -> Self {
         match s {
             TxStatus::Submitted { time } => {
                 TransactionStatus::Submitted(SubmittedStatus(time))
@@ -220,12 +435,14 @@ impl From<TxStatus> for TransactionStatus {
                 result,
                 time,
             } => TransactionStatus::Success(SuccessStatus {
+                tx_id,
                 block_id,
                 result,
                 time,
+                fee: Default::default(),
             }),
-            TxStatus::SqueezedOut { reason } => {
-                TransactionStatus::SqueezedOut(SqueezedOutStatus { reason })
+            TxStatus::SqueezedOut { reason, kind } => {
+                TransactionStatus::SqueezedOut(SqueezedOutStatus { reason, kind })
             }
             TxStatus::Failed {
                 block_id,
@@ -233,11 +450,16 @@ impl From<TxStatus> for TransactionStatus {
                 time,
                 result,
             } => TransactionStatus::Failed(FailureStatus {
+                tx_id,
                 block_id,
                 reason,
                 time,
                 state: result,
+                fee: Default::default(),
             }),
+            TxStatus::Expired { expire_at } => {
+                TransactionStatus::Expired(ExpiredStatus { expire_at })
+            }
         }
     }
 }
@@ -252,25 +474,30 @@ impl From<TransactionStatus> for TxStatus {
                 block_id,
                 result,
                 time,
+                ..
             }) => TxStatus::Success {
                 block_id,
                 result,
                 time,
             },
-            TransactionStatus::SqueezedOut(SqueezedOutStatus { reason }) => {
-                TxStatus::SqueezedOut { reason }
+            TransactionStatus::SqueezedOut(SqueezedOutStatus { reason, kind }) => {
+                TxStatus::SqueezedOut { reason, kind }
             }
             TransactionStatus::Failed(FailureStatus {
                 block_id,
                 reason,
                 time,
                 state: result,
+                ..
             }) => TxStatus::Failed {
                 block_id,
                 reason,
                 time,
                 result,
             },
+            TransactionStatus::Expired(ExpiredStatus { expire_at }) => {
+                TxStatus::Expired { expire_at }
+            }
         }
     }
 }
@@ -354,6 +581,33 @@ impl Transaction {
         }
     }
 
+    /// The owner of the first base asset input that pays the fee of the transaction.
+    async fn fee_payer(&self, ctx: &Context<'_>) -> Option<Address> {
+        let config = ctx.data_unchecked::<Config>();
+        let base_asset_id = config.consensus_parameters.base_asset_id();
+        let inputs = match &self.0 {
+            fuel_tx::Transaction::Script(script) => script.inputs(),
+            fuel_tx::Transaction::Create(create) => create.inputs(),
+            fuel_tx::Transaction::Mint(_) => return None,
+        };
+        inputs.iter().find_map(|input| match input {
+            fuel_tx::Input::CoinSigned(CoinSigned {
+                owner, asset_id, ..
+            })
+            | fuel_tx::Input::CoinPredicate(CoinPredicate {
+                owner, asset_id, ..
+            }) if asset_id == base_asset_id => Some(Address(*owner)),
+            fuel_tx::Input::MessageCoinSigned(MessageCoinSigned {
+                recipient, ..
+            })
+            | fuel_tx::Input::MessageCoinPredicate(MessageCoinPredicate {
+                recipient,
+                ..
+            }) => Some(Address(*recipient)),
+            _ => None,
+        })
+    }
+
     async fn policies(&self) -> Option<Policies> {
         match &self.0 {
             fuel_tx::Transaction::Script(script) => Some((*script.policies()).into()),
@@ -592,7 +846,7 @@ pub(crate) fn get_tx_status(
         .status(&id)
         .into_api_result::<txpool::TransactionStatus, StorageError>()?
     {
-        Some(status) => Ok(Some(status.into())),
+        Some(status) => Ok(Some(TransactionStatus::new(id, status))),
         None => match txpool.submission_time(id) {
             Some(submitted_time) => Ok(Some(TransactionStatus::Submitted(
                 SubmittedStatus(submitted_time),
@@ -607,3 +861,24 @@ impl From<fuel_tx::policies::Policies> for Policies {
         Policies(value)
     }
 }
+
+pub struct TransactionProof(pub(crate) query::TransactionProof);
+
+#[Object]
+impl TransactionProof {
+    /// The header of the block that contains the transaction.
+    async fn block_header(&self) -> Header {
+        self.0.block_header.clone().into()
+    }
+
+    /// The proof of the transaction against the `transactionsRoot` of the block header.
+    async fn proof(&self) -> MerkleProof {
+        self.0.proof.clone().into()
+    }
+}
+
+impl From<query::TransactionProof> for TransactionProof {
+    fn from(proof: query::TransactionProof) -> Self {
+        TransactionProof(proof)
+    }
+}
@@ -2,6 +2,10 @@ use crate::{
     database::Database,
     service::adapters::P2PAdapter,
 };
+use fuel_core_chain_config::{
+    ChainConfig,
+    StateConfig,
+};
 use fuel_core_services::{
     RunnableService,
     RunnableTask,
@@ -9,12 +13,17 @@ use fuel_core_services::{
     State,
     StateWatcher,
 };
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
 use tracing::warn;
 
 pub use config::{
     Config,
     DbType,
+    GenesisState,
+    StateSnapshot,
     VMConfig,
 };
 pub use fuel_core_services::Service as ServiceTrait;
@@ -28,6 +37,7 @@ pub mod config;
 pub mod genesis;
 pub mod metrics;
 mod query;
+pub mod state_compaction;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -118,6 +128,39 @@ impl FuelService {
         Ok(service)
     }
 
+    /// Captures the coins, the messages, the contracts with their balances and state,
+    /// and the height of the latest block. The history of the blocks is not captured.
+    /// Use [`Config::from_snapshot`] to boot a new node from the snapshot.
+    pub fn snapshot_state(&self) -> anyhow::Result<StateSnapshot> {
+        let state = StateConfig::generate_state_config(self.shared.database.clone())?;
+        Ok(StateSnapshot {
+            chain_config: ChainConfig {
+                initial_state: Some(state),
+                ..self.shared.config.chain_conf.clone()
+            },
+        })
+    }
+
+    /// Stops accepting new transactions, waits until the block production commits
+    /// the transactions already accepted by the `TxPool`, and then stops the services.
+    ///
+    /// Returns `true` if the `TxPool` was drained within the `timeout`. The services are
+    /// stopped in both cases, so the pending transactions are dropped on the timeout.
+    pub async fn shutdown_gracefully(&self, timeout: Duration) -> anyhow::Result<bool> {
+        const DRAIN_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+        self.shared.txpool.stop_accepting_transactions();
+        let drain = async {
+            while self.shared.txpool.pending_number() > 0 {
+                tokio::time::sleep(DRAIN_CHECK_INTERVAL).await;
+            }
+        };
+        let drained = tokio::time::timeout(timeout, drain).await.is_ok();
+
+        self.runner.stop_and_await().await?;
+        Ok(drained)
+    }
+
     #[cfg(feature = "relayer")]
     /// Wait for the [`Relayer`] to be in sync with
     /// the data availability layer.
@@ -24,8 +24,14 @@ use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
     fuel_asm::Word,
-    fuel_tx::TxId,
-    fuel_types::BlockHeight,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+    },
     services::{
         block_importer::{
             BlockImportInfo,
@@ -60,6 +66,42 @@ impl ConsensusModulePort for PoAAdapter {
             .manually_produce_block(start_time, number_of_blocks)
             .await
     }
+
+    async fn manually_produce_block_with_transactions(
+        &self,
+        start_time: Option<Tai64>,
+        transactions: Vec<Transaction>,
+    ) -> anyhow::Result<()> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .manually_produce_block_with_transactions(start_time, transactions)
+            .await
+    }
+
+    async fn manually_produce_block_if_coinbase_over(
+        &self,
+        asset_id: AssetId,
+        threshold: Word,
+    ) -> anyhow::Result<Option<BlockHeight>> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .manually_produce_block_if_coinbase_over(asset_id, threshold)
+            .await
+    }
+
+    fn is_block_production_enabled(&self) -> bool {
+        self.shared_state.is_some()
+    }
+
+    async fn set_block_gas_limit(&self, block_gas_limit: Word) -> anyhow::Result<()> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .set_block_gas_limit(block_gas_limit)
+            .await
+    }
 }
 
 impl TransactionPool for TxPoolAdapter {
@@ -97,6 +139,23 @@ impl fuel_core_poa::ports::BlockProducer for BlockProducerAdapter {
             .produce_and_execute_block(height, block_time, max_gas)
             .await
     }
+
+    async fn produce_and_execute_block_with_transactions(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        transactions: Vec<Transaction>,
+        max_gas: Word,
+    ) -> anyhow::Result<UncommittedResult<StorageTransaction<Database>>> {
+        self.block_producer
+            .produce_and_execute_block_with_transactions(
+                height,
+                block_time,
+                transactions,
+                max_gas,
+            )
+            .await
+    }
 }
 
 impl BlockImporter for BlockImporterAdapter {
@@ -118,6 +177,14 @@ impl BlockImporter for BlockImporterAdapter {
                 .map(|r| r.deref().into()),
         )
     }
+
+    fn queue_depth(&self) -> usize {
+        self.block_importer.queue_depth()
+    }
+
+    fn is_read_only(&self) -> bool {
+        self.block_importer.is_read_only()
+    }
 }
 
 #[cfg(feature = "p2p")]
@@ -5,6 +5,7 @@ use crate::{
         ExecutionBlockWithSource,
         Executor,
         MaybeCheckedTransaction,
+        OnceTransactionsSource,
     },
     service::adapters::{
         ExecutorAdapter,
@@ -25,6 +26,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            ExecutionTypes,
             Result as ExecutorResult,
             UncommittedResult,
         },
@@ -54,6 +56,28 @@ impl ExecutorAdapter {
         executor.execute_without_commit(block, self.config.as_ref().into())
     }
 
+    pub(crate) fn _execute_transactions_without_commit(
+        &self,
+        component: Components<Vec<fuel_tx::Transaction>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: OnceTransactionsSource::new(
+                component.transactions_source,
+            ),
+            gas_limit: component.gas_limit,
+        };
+        executor.execute_without_commit(
+            ExecutionTypes::Production(component),
+            self.config.as_ref().into(),
+        )
+    }
+
     pub(crate) fn _dry_run(
         &self,
         block: Components<fuel_tx::Transaction>,
@@ -4,7 +4,9 @@ use crate::{
         Database,
     },
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
+        DatabaseAssets,
         DatabaseBlocks,
         DatabaseChain,
         DatabaseCoins,
@@ -14,9 +16,14 @@ use crate::{
         DatabasePort,
         DatabaseTransactions,
         DryRunExecution,
+        P2pPort,
         TxPoolPort,
     },
-    service::adapters::TxPoolAdapter,
+    service::adapters::{
+        BlockImporterAdapter,
+        P2PAdapter,
+        TxPoolAdapter,
+    },
 };
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
@@ -27,8 +34,10 @@ use fuel_core_storage::{
         IterDirection,
     },
     not_found,
+    tables::Receipts,
     Error as StorageError,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_txpool::{
     service::TxStatusMessage,
@@ -49,6 +58,7 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         AssetId,
+        Mint,
         Receipt as TxReceipt,
         Transaction,
         TxPointer,
@@ -56,12 +66,22 @@ use fuel_core_types::{
     },
     fuel_types::{
         BlockHeight,
+        Bytes32,
         Nonce,
+        Word,
     },
     services::{
-        graphql_api::ContractBalance,
+        block_importer::ImportResult,
+        executor::ExecutionResult,
+        graphql_api::{
+            AssetSupplyDelta,
+            CoinbaseCredit,
+            ContractBalance,
+            ContractBalanceUpdate,
+        },
         txpool::{
             InsertionResult,
+            TransactionSource,
             TransactionStatus,
         },
     },
@@ -94,6 +114,10 @@ impl DatabaseBlocks for Database {
             .transpose()
             .ok_or(not_found!("BlockId"))??)
     }
+
+    fn block_gas_limit(&self, height: &BlockHeight) -> StorageResult<Option<Word>> {
+        self.block_gas_limit(height).map_err(StorageError::from)
+    }
 }
 
 impl DatabaseTransactions for Database {
@@ -183,6 +207,53 @@ impl DatabaseContracts for Database {
             })
             .into_boxed()
     }
+
+    fn all_contract_balances(
+        &self,
+        start: Option<(ContractId, AssetId)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractBalance>> {
+        self.all_contract_balances(start, Some(direction))
+            .map(|result| {
+                result
+                    .map_err(StorageError::from)
+                    .map(|(owner, asset_id, amount)| ContractBalance {
+                        owner,
+                        amount,
+                        asset_id,
+                    })
+            })
+            .into_boxed()
+    }
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Bytes32)>> {
+        self.contract_storage_slots(contract, start_key, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn contract_balance_history(
+        &self,
+        contract: ContractId,
+        asset_id: AssetId,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(BlockHeight, ContractBalanceUpdate)>> {
+        self.contract_balance_history(&contract, &asset_id, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn contract_ids(&self) -> BoxedIter<StorageResult<ContractId>> {
+        self.contract_ids()
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
 }
 
 impl DatabaseChain for Database {
@@ -207,6 +278,35 @@ impl DatabaseChain for Database {
     }
 }
 
+impl DatabaseAssets for Database {
+    fn asset_supply_history(
+        &self,
+        asset_id: &AssetId,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, AssetSupplyDelta)>> {
+        self.asset_supply_history(asset_id, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn total_coinbase_minted(&self, asset_id: &AssetId) -> StorageResult<u128> {
+        self.total_coinbase_minted(asset_id)
+            .map_err(StorageError::from)
+    }
+
+    fn coinbase_credits(
+        &self,
+        recipient: &ContractId,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, CoinbaseCredit)>> {
+        self.coinbase_credits(recipient, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+}
+
 impl DatabasePort for Database {}
 
 #[async_trait]
@@ -223,6 +323,35 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
+    fn transaction_source(&self, id: TxId) -> Option<TransactionSource> {
+        self.service.find_source(id)
+    }
+
+    fn transaction_dependencies(&self, id: TxId) -> Vec<Transaction> {
+        self.service
+            .find_dependent(vec![id])
+            .into_iter()
+            .filter(|tx| tx.id() != id)
+            .map(|tx| tx.deref().into())
+            .collect()
+    }
+
+    fn coin_spenders(&self, utxo_id: UtxoId) -> Vec<Transaction> {
+        self.service
+            .find_spenders(utxo_id)
+            .into_iter()
+            .map(|tx| tx.deref().into())
+            .collect()
+    }
+
+    fn pending_number(&self) -> usize {
+        self.service.pending_number()
+    }
+
+    fn total_consumable_gas(&self) -> u64 {
+        self.service.total_consumable_gas()
+    }
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -236,6 +365,19 @@ impl TxPoolPort for TxPoolAdapter {
     ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
         self.service.tx_update_subscribe(id)
     }
+
+    fn remove(&self, id: TxId) -> bool {
+        // The removal notifies the subscribers even about the unknown transactions,
+        // so the committed transaction would be reported as squeezed out.
+        if self.service.find_one(id).is_none() {
+            return false
+        }
+        !self.service.remove_txs(vec![id]).is_empty()
+    }
+
+    fn set_expiration(&self, id: TxId, expire_at: BlockHeight) -> bool {
+        self.service.set_expiration(id, expire_at)
+    }
 }
 
 impl DatabaseMessageProof for Database {
@@ -262,6 +404,92 @@ impl DryRunExecution for BlockProducerAdapter {
     }
 }
 
-impl BlockProducerPort for BlockProducerAdapter {}
+#[async_trait]
+impl BlockProducerPort for BlockProducerAdapter {
+    async fn pending_coinbase(&self, max_gas: Word) -> anyhow::Result<Mint> {
+        self.block_producer.pending_coinbase(max_gas).await
+    }
+
+    async fn replay_transactions(
+        &self,
+        transactions: Vec<Transaction>,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<Vec<Vec<TxReceipt>>> {
+        // The database transaction is dropped without the commit.
+        let (
+            ExecutionResult {
+                tx_status,
+                skipped_transactions,
+                ..
+            },
+            db_transaction,
+        ) = self
+            .block_producer
+            .replay_transactions(transactions, block_time, max_gas)
+            .await?
+            .into();
+
+        if let Some((tx_id, err)) = skipped_transactions.into_iter().next() {
+            return Err(anyhow::anyhow!(
+                "The transaction {tx_id:#x} can't be replayed: {err}"
+            ))
+        }
+
+        tx_status
+            .iter()
+            .map(|status| {
+                let receipts = db_transaction
+                    .as_ref()
+                    .storage::<Receipts>()
+                    .get(&status.id)?
+                    .map(|receipts| receipts.into_owned())
+                    .unwrap_or_default();
+                Ok(receipts)
+            })
+            .collect()
+    }
+}
+
+impl BlockImporterPort for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<Arc<ImportResult>> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok()),
+        )
+    }
+
+    fn is_read_only(&self) -> bool {
+        self.block_importer.is_read_only()
+    }
+}
+
+#[cfg(feature = "p2p")]
+#[async_trait]
+impl P2pPort for P2PAdapter {
+    async fn connected_peers_count(&self) -> usize {
+        if let Some(service) = &self.service {
+            service
+                .get_peer_ids()
+                .await
+                .map(|peer_ids| peer_ids.len())
+                .unwrap_or_default()
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+#[async_trait]
+impl P2pPort for P2PAdapter {
+    async fn connected_peers_count(&self) -> usize {
+        0
+    }
+}
 
 use super::BlockProducerAdapter;
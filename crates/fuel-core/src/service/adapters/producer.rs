@@ -75,6 +75,13 @@ impl fuel_core_producer::ports::Executor for ExecutorAdapter {
         self._execute_without_commit(ExecutionTypes::Production(component))
     }
 
+    fn execute_transactions_without_commit(
+        &self,
+        component: Components<Vec<fuel_tx::Transaction>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        self._execute_transactions_without_commit(component)
+    }
+
     fn dry_run(
         &self,
         block: Components<fuel_tx::Transaction>,
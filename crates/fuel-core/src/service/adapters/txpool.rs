@@ -54,6 +54,10 @@ impl BlockImporter for BlockImporterAdapter {
                 .filter_map(|result| result.ok()),
         )
     }
+
+    fn is_read_only(&self) -> bool {
+        self.block_importer.is_read_only()
+    }
 }
 
 #[cfg(feature = "p2p")]
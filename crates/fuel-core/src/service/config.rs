@@ -5,7 +5,9 @@ use fuel_core_chain_config::{
 };
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
+    fuel_types::ContractId,
     secrecy::Secret,
+    tai64::Tai64,
 };
 use std::{
     net::{
@@ -30,7 +32,10 @@ use fuel_core_p2p::config::{
 #[cfg(feature = "relayer")]
 use fuel_core_relayer::Config as RelayerConfig;
 
-pub use fuel_core_poa::Trigger;
+pub use fuel_core_poa::{
+    DeterministicTime,
+    Trigger,
+};
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -66,13 +71,37 @@ pub struct Config {
     pub min_connected_reserved_peers: usize,
     /// Time to wait after receiving the latest block before considered to be Synced.
     pub time_until_synced: Duration,
+    /// The number of the imported blocks waiting for the subscribers of the importer
+    /// above which the block production backs off. `None` disables the throttling.
+    pub max_importer_queue_depth: Option<usize>,
+    /// The delay of the next block for each block in the importer queue above
+    /// the `max_importer_queue_depth`.
+    pub importer_backoff: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// Assigns the timestamps of the produced blocks from their heights instead of
+    /// the clock. `None` uses the clock.
+    pub deterministic_time: Option<DeterministicTime>,
+    /// The interval between removals of the unreachable contracts' state Merkle tree nodes.
+    /// The compaction is disabled if `None`.
+    pub state_compaction_interval: Option<Duration>,
+    /// When `true`, the node works as a read replica:
+    /// - Disables the block production, including the manual one.
+    /// - Rejects the transactions submitted via GraphQL API.
+    /// - Keeps the block import and the GraphQL queries working.
+    pub read_only: bool,
+    /// The time during which the GraphQL API remembers the idempotency key of
+    /// the submitted transaction and rejects other submissions with the same key.
+    pub idempotency_key_ttl: Duration,
+    /// The number of blocks on top of the block before its balances are considered
+    /// final and can't be reverted by a reorg.
+    pub finalization_depth: u32,
 }
 
 impl Config {
     pub fn local_node() -> Self {
         let chain_conf = ChainConfig::local_testnet();
+        let block_gas_limit = chain_conf.block_gas_limit;
         let utxo_validation = false;
         let min_gas_price = 0;
 
@@ -98,7 +127,10 @@ impl Config {
                 transaction_ttl: Duration::from_secs(60 * 100000000),
                 ..fuel_core_txpool::Config::default()
             },
-            block_producer: Default::default(),
+            block_producer: fuel_core_producer::Config {
+                max_dry_run_gas: Some(block_gas_limit),
+                ..Default::default()
+            },
             block_executor: Default::default(),
             block_importer: Default::default(),
             #[cfg(feature = "relayer")]
@@ -112,10 +144,63 @@ impl Config {
             verifier: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            max_importer_queue_depth: None,
+            importer_backoff: Duration::from_millis(100),
             query_log_threshold_time: Duration::from_secs(2),
+            deterministic_time: None,
+            state_compaction_interval: None,
+            read_only: false,
+            idempotency_key_ttl: Duration::from_secs(10 * 60),
+            finalization_depth: 0,
         }
     }
 
+    /// The same as [`Config::local_node`], but the node stores the state in memory
+    /// without the disk I/O. The state is lost when the node is dropped.
+    pub fn local_node_in_memory() -> Self {
+        Self {
+            database_type: DbType::InMemory,
+            ..Self::local_node()
+        }
+    }
+
+    /// Makes the block producer assign the timestamp `start + step * height` to the
+    /// block at the `height`, so the timestamps don't depend on the wall clock.
+    pub fn deterministic_time(mut self, start: Tai64, step: Duration) -> Self {
+        self.deterministic_time = Some(DeterministicTime { start, step });
+        self
+    }
+
+    /// The same as [`Config::local_node`], but the node boots from the state
+    /// captured by the [`FuelService::snapshot_state`](crate::service::FuelService::snapshot_state).
+    pub fn from_snapshot(snapshot: StateSnapshot) -> Self {
+        let mut config = Self::local_node();
+        config.chain_conf = snapshot.chain_config;
+        config.txpool.chain_config = config.chain_conf.clone();
+        config
+    }
+
+    /// Exports the chain configuration used to build the genesis block together with
+    /// the coinbase recipient, so another node can reproduce the same network with the
+    /// [`Config::from_genesis`]. The contracts added to the chain configuration, like
+    /// the fee collection contract, are deployed at the genesis.
+    pub fn export_genesis(&self) -> GenesisState {
+        GenesisState {
+            chain_config: self.chain_conf.clone(),
+            coinbase_recipient: self.block_producer.coinbase_recipient,
+        }
+    }
+
+    /// The same as [`Config::local_node`], but the node uses the genesis exported
+    /// by the [`Config::export_genesis`].
+    pub fn from_genesis(genesis: GenesisState) -> Self {
+        let mut config = Self::local_node();
+        config.chain_conf = genesis.chain_config;
+        config.txpool.chain_config = config.chain_conf.clone();
+        config.block_producer.coinbase_recipient = genesis.coinbase_recipient;
+        config
+    }
+
     // TODO: Rework our configs system to avoid nesting of the same configs.
     pub fn make_config_consistent(mut self) -> Config {
         if !self.debug && !self.utxo_validation {
@@ -148,14 +233,35 @@ impl From<&Config> for fuel_core_poa::Config {
             trigger: config.block_production,
             block_gas_limit: config.chain_conf.block_gas_limit,
             signing_key: config.consensus_key.clone(),
-            metrics: false,
+            metrics: config.block_producer.metrics,
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
+            max_importer_queue_depth: config.max_importer_queue_depth,
+            importer_backoff: config.importer_backoff,
+            deterministic_time: config.deterministic_time,
         }
     }
 }
 
+/// The state of the node captured by the
+/// [`FuelService::snapshot_state`](crate::service::FuelService::snapshot_state).
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct StateSnapshot {
+    /// The chain configuration of the node with the captured state as the initial state.
+    pub chain_config: ChainConfig,
+}
+
+/// The genesis of the network exported by the [`Config::export_genesis`].
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct GenesisState {
+    /// The chain configuration with the initial state of the network.
+    pub chain_config: ChainConfig,
+    /// The contract receiving the fees of the produced blocks.
+    #[serde(default)]
+    pub coinbase_recipient: Option<ContractId>,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct VMConfig {
     pub backtrace: bool,
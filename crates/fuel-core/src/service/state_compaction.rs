@@ -0,0 +1,120 @@
+//! The background task that removes the unreachable nodes of the contracts' state
+//! sparse Merkle trees.
+
+use crate::{
+    database::Database,
+    service::adapters::BlockImporterAdapter,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use std::time::Duration;
+
+pub type Service = ServiceRunner<StateCompaction>;
+
+pub struct StateCompaction {
+    database: Database,
+    block_importer: BlockImporterAdapter,
+    interval: Duration,
+}
+
+pub struct Task {
+    database: Database,
+    block_importer: BlockImporterAdapter,
+    timer: tokio::time::Interval,
+}
+
+/// The number of the orphaned nodes removed at once while holding the commit lock.
+const COMPACTION_CHUNK_SIZE: usize = 1024;
+
+impl Task {
+    async fn compact(&self) -> anyhow::Result<()> {
+        let mut database = self.database.clone();
+        let block_importer = self.block_importer.block_importer.clone();
+        // The scan reads the whole state, so it runs on the blocking thread and
+        // the commit lock is held only while removing a chunk of the orphans.
+        let removed = tokio::task::spawn_blocking(move || -> anyhow::Result<usize> {
+            let mut reachability = database.contracts_state_reachability()?;
+            let orphans = database.contracts_state_orphans(&reachability)?;
+
+            let mut removed = 0usize;
+            for chunk in orphans.chunks(COMPACTION_CHUNK_SIZE) {
+                // The blocks committed since the scan may reference the orphans
+                // again, so they are checked against the latest roots under the lock.
+                let removed_in_chunk = block_importer.with_commit_lock(|| {
+                    database.remove_contracts_state_orphans(&mut reachability, chunk)
+                })?;
+                removed = removed.saturating_add(removed_in_chunk);
+            }
+            Ok(removed)
+        })
+        .await??;
+        tracing::debug!("Removed {removed} unreachable contract state nodes");
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for StateCompaction {
+    const NAME: &'static str = "StateCompaction";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let mut timer = tokio::time::interval(self.interval);
+        // The first tick completes immediately, skip it to not compact during the startup.
+        timer.reset();
+
+        Ok(Task {
+            database: self.database,
+            block_importer: self.block_importer,
+            timer,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                Ok(false /* should_continue */)
+            }
+
+            _ = self.timer.tick() => {
+                self.compact().await?;
+                Ok(true /* should_continue */)
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Nothing to shut down because the compaction is done within one iteration.
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: Database,
+    block_importer: BlockImporterAdapter,
+    interval: Duration,
+) -> Service {
+    ServiceRunner::new(StateCompaction {
+        database,
+        block_importer,
+        interval,
+    })
+}
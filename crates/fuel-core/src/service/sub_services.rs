@@ -77,10 +77,18 @@ pub fn init_sub_services(
             consensus_parameters: config.chain_conf.consensus_parameters.clone(),
             coinbase_recipient: config
                 .block_producer
-                .coinbase_recipient
+                .coinbase_recipient_for(
+                    &config.chain_conf.consensus_parameters.base_asset_id,
+                )
                 .unwrap_or_default(),
+            fail_on_missing_coinbase_recipient: config
+                .block_producer
+                .fail_on_missing_coinbase_recipient,
+            require_coinbase_success: config.block_producer.require_coinbase_success,
+            coinbase_overflow_policy: config.block_producer.coinbase_overflow_policy,
             backtrace: config.vm.backtrace,
             utxo_validation_default: config.utxo_validation,
+            max_coin_output_amount: config.chain_conf.max_coin_output_amount,
         }),
     };
 
@@ -160,6 +168,11 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    if production_enabled && config.read_only {
+        production_enabled = false;
+        tracing::info!("Disabled block production because of `read_only` flag");
+    }
+
     let poa = (production_enabled).then(|| {
         fuel_core_poa::new_service(
             last_block.header(),
@@ -175,7 +188,7 @@ pub fn init_sub_services(
     #[cfg(feature = "p2p")]
     let sync = fuel_core_sync::service::new_service(
         *last_block.header().height(),
-        p2p_adapter,
+        p2p_adapter.clone(),
         importer_adapter.clone(),
         verifier,
         config.sync,
@@ -198,14 +211,28 @@ pub fn init_sub_services(
             min_gas_price: config.txpool.min_gas_price,
             max_tx: config.txpool.max_tx,
             max_depth: config.txpool.max_depth,
+            block_gas_limit: config.chain_conf.block_gas_limit,
             consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+            assets: config.chain_conf.assets.clone(),
             consensus_key: config.consensus_key.clone(),
+            read_only: config.read_only,
+            txpool: (&config.txpool).into(),
+            block_producer: (&config.block_producer).into(),
+            #[cfg(feature = "relayer")]
+            relayer: config.relayer.as_ref().map(Into::into),
+            #[cfg(not(feature = "relayer"))]
+            relayer: None,
+            idempotency_key_ttl: config.idempotency_key_ttl,
+            finalization_depth: config.finalization_depth,
+            block_production: config.block_production,
         },
         schema,
         Box::new(database.clone()),
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
         Box::new(poa_adapter),
+        Box::new(importer_adapter.clone()),
+        Box::new(p2p_adapter),
         config.query_log_threshold_time,
         config.api_request_timeout,
     )?;
@@ -234,6 +261,14 @@ pub fn init_sub_services(
         services.push(Box::new(poa));
     }
 
+    if let Some(interval) = config.state_compaction_interval {
+        services.push(Box::new(crate::service::state_compaction::new_service(
+            database.clone(),
+            shared.block_importer.clone(),
+            interval,
+        )));
+    }
+
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
         services.push(Box::new(relayer));
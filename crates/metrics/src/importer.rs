@@ -18,6 +18,7 @@ pub struct ImporterMetrics {
     pub block_height: Gauge,
     pub latest_block_import_timestamp: Gauge<f64, AtomicU64>,
     pub execute_and_commit_duration: Histogram,
+    pub read_only_mode: Gauge,
 }
 
 impl Default for ImporterMetrics {
@@ -29,6 +30,7 @@ impl Default for ImporterMetrics {
         let latest_block_import_ms = Gauge::default();
         let execute_and_commit_duration =
             Histogram::new(timing_buckets().iter().cloned());
+        let read_only_mode = Gauge::default();
 
         registry.register(
             "importer_tx_count",
@@ -54,12 +56,19 @@ impl Default for ImporterMetrics {
             execute_and_commit_duration.clone(),
         );
 
+        registry.register(
+            "importer_read_only_mode",
+            "Set to 1 when the importer stopped writing blocks after repeated write failures",
+            read_only_mode.clone(),
+        );
+
         Self {
             registry,
             total_txs_count: tx_count_gauge,
             block_height: block_height_gauge,
             latest_block_import_timestamp: latest_block_import_ms,
             execute_and_commit_duration,
+            read_only_mode,
         }
     }
 }
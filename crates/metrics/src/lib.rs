@@ -10,6 +10,7 @@ pub mod future_tracker;
 pub mod graphql_metrics;
 pub mod importer;
 pub mod p2p_metrics;
+pub mod producer_metrics;
 pub mod response;
 pub mod services;
 pub mod txpool_metrics;
@@ -0,0 +1,57 @@
+use prometheus_client::{
+    metrics::{
+        counter::Counter,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+pub struct ProducerMetrics {
+    pub registry: Registry,
+    pub blocks_produced: Counter,
+    pub total_coinbase: Counter,
+    pub latest_block_production_timestamp: Gauge,
+}
+
+impl Default for ProducerMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let blocks_produced = Counter::default();
+        let total_coinbase = Counter::default();
+        let latest_block_production_timestamp = Gauge::default();
+
+        registry.register(
+            "producer_blocks_produced",
+            "The number of blocks produced and imported by the node",
+            blocks_produced.clone(),
+        );
+
+        registry.register(
+            "producer_total_coinbase",
+            "The total coinbase minted by the blocks produced by the node",
+            total_coinbase.clone(),
+        );
+
+        registry.register(
+            "producer_latest_block_production_timestamp_s",
+            "A UNIX timestamp of when the node produced the latest block",
+            latest_block_production_timestamp.clone(),
+        );
+
+        Self {
+            registry,
+            blocks_produced,
+            total_coinbase,
+            latest_block_production_timestamp,
+        }
+    }
+}
+
+// Setup a global static for accessing producer metrics
+static PRODUCER_METRICS: OnceLock<ProducerMetrics> = OnceLock::new();
+
+pub fn producer_metrics() -> &'static ProducerMetrics {
+    PRODUCER_METRICS.get_or_init(ProducerMetrics::default)
+}
@@ -2,6 +2,7 @@ use crate::{
     graphql_metrics::graphql_metrics,
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
+    producer_metrics::producer_metrics,
     services::services_metrics,
     txpool_metrics::txpool_metrics,
 };
@@ -53,6 +54,10 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &producer_metrics().registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
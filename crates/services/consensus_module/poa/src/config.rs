@@ -2,7 +2,9 @@ use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
     fuel_tx::ConsensusParameters,
+    fuel_types::BlockHeight,
     secrecy::Secret,
+    tai64::Tai64,
 };
 use tokio::time::Duration;
 
@@ -15,6 +17,16 @@ pub struct Config {
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
+    /// The number of the imported blocks waiting for the subscribers of the importer
+    /// above which the block production backs off. `None` disables the throttling.
+    pub max_importer_queue_depth: Option<usize>,
+    /// The delay of the next block for each block in the importer queue above
+    /// the `max_importer_queue_depth`.
+    pub importer_backoff: Duration,
+    /// Assigns the timestamps of the blocks from their heights instead of the clock.
+    /// The explicit start time of the manual block production takes precedence, and
+    /// a timestamp below the one of the previous block is raised to it.
+    pub deterministic_time: Option<DeterministicTime>,
 }
 
 impl Default for Config {
@@ -27,6 +39,9 @@ impl Default for Config {
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            max_importer_queue_depth: None,
+            importer_backoff: Duration::from_millis(100),
+            deterministic_time: None,
         }
     }
 }
@@ -43,3 +58,21 @@ pub enum Trigger {
     /// A new block is produced periodically. Used to simulate consensus block delay.
     Interval { block_time: Duration },
 }
+
+/// The timestamps of the blocks derived from their heights, so the tests
+/// don't depend on the wall clock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeterministicTime {
+    /// The timestamp of the genesis block.
+    pub start: Tai64,
+    /// The time between two consecutive blocks, rounded down to seconds.
+    pub step: Duration,
+}
+
+impl DeterministicTime {
+    /// Returns the timestamp of the block at the `height`: `start + step * height`.
+    pub fn timestamp(&self, height: BlockHeight) -> Tai64 {
+        let offset = self.step.as_secs().saturating_mul(u64::from(*height));
+        Tai64(self.start.0.saturating_add(offset))
+    }
+}
@@ -17,6 +17,7 @@ pub mod verifier;
 
 pub use config::{
     Config,
+    DeterministicTime,
     Trigger,
 };
 pub use service::{
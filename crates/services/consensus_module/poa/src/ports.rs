@@ -9,7 +9,10 @@ use fuel_core_types::{
         primitives::DaBlockHeight,
     },
     fuel_asm::Word,
-    fuel_tx::TxId,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::{
         BlockHeight,
         Bytes32,
@@ -51,6 +54,16 @@ pub trait BlockProducer: Send + Sync {
         block_time: Tai64,
         max_gas: Word,
     ) -> anyhow::Result<UncommittedExecutionResult<StorageTransaction<Self::Database>>>;
+
+    /// Produces and executes the block with the `transactions` in the specified order,
+    /// bypassing the `TxPool`.
+    async fn produce_and_execute_block_with_transactions(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        transactions: Vec<Transaction>,
+        max_gas: Word,
+    ) -> anyhow::Result<UncommittedExecutionResult<StorageTransaction<Self::Database>>>;
 }
 
 #[cfg_attr(test, mockall::automock(type Database=EmptyStorage;))]
@@ -63,6 +76,14 @@ pub trait BlockImporter: Send + Sync {
     ) -> anyhow::Result<()>;
 
     fn block_stream(&self) -> BoxStream<BlockImportInfo>;
+
+    /// Returns the number of the imported blocks not yet processed by the subscribers
+    /// of the importer.
+    fn queue_depth(&self) -> usize;
+
+    /// Returns `true` if the importer doesn't commit the blocks anymore
+    /// because of the repeated write failures.
+    fn is_read_only(&self) -> bool;
 }
 
 #[cfg_attr(test, mockall::automock)]
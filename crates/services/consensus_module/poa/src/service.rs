@@ -14,12 +14,14 @@ use crate::{
         SyncTask,
     },
     Config,
+    DeterministicTime,
     Trigger,
 };
 use anyhow::{
     anyhow,
     Context,
 };
+use fuel_core_metrics::producer_metrics::producer_metrics;
 use fuel_core_services::{
     stream::BoxStream,
     RunnableService,
@@ -42,8 +44,18 @@ use fuel_core_types::{
     },
     fuel_asm::Word,
     fuel_crypto::Signature,
-    fuel_tx::TxId,
-    fuel_types::BlockHeight,
+    fuel_tx::{
+        field::{
+            MintAmount,
+            MintAssetId,
+        },
+        Transaction,
+        TxId,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+    },
     secrecy::{
         ExposeSecret,
         Secret,
@@ -60,7 +72,11 @@ use fuel_core_types::{
 };
 use std::{
     ops::Deref,
-    time::Duration,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
 };
 use tokio::{
     sync::{
@@ -96,6 +112,57 @@ impl SharedState {
             .await?;
         receiver.await?
     }
+
+    pub async fn manually_produce_block_with_transactions(
+        &self,
+        start_time: Option<Tai64>,
+        transactions: Vec<Transaction>,
+    ) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(Request::ManualBlockWithTransactions((
+                ManualProductionWithTransactions {
+                    start_time,
+                    transactions,
+                },
+                sender,
+            )))
+            .await?;
+        receiver.await?
+    }
+
+    /// Produces the next block only if it credits more than the `threshold`
+    /// of the `asset_id`. Returns the height of the produced block, or `None`
+    /// if the block is discarded.
+    pub async fn manually_produce_block_if_coinbase_over(
+        &self,
+        asset_id: AssetId,
+        threshold: Word,
+    ) -> anyhow::Result<Option<BlockHeight>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(Request::ManualBlockIfCoinbaseOver((
+                CoinbaseThreshold {
+                    asset_id,
+                    threshold,
+                },
+                sender,
+            )))
+            .await?;
+        receiver.await?
+    }
+
+    /// Replaces the gas limit of the blocks produced after the call.
+    pub async fn set_block_gas_limit(&self, block_gas_limit: Word) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(Request::SetBlockGasLimit((block_gas_limit, sender)))
+            .await?;
+        receiver.await?
+    }
 }
 
 struct ManualProduction {
@@ -103,11 +170,41 @@ struct ManualProduction {
     pub number_of_blocks: u32,
 }
 
+struct ManualProductionWithTransactions {
+    pub start_time: Option<Tai64>,
+    pub transactions: Vec<Transaction>,
+}
+
+/// The block is committed only if its mint transaction credits
+/// more than the `threshold` of the `asset_id`.
+#[derive(Clone, Copy)]
+struct CoinbaseThreshold {
+    pub asset_id: AssetId,
+    pub threshold: Word,
+}
+
 /// Requests accepted by the task.
 enum Request {
     /// Manually produces the next blocks with `Tai64` block timestamp.
     /// The block timestamp should be higher than previous one.
     ManualBlocks((ManualProduction, oneshot::Sender<anyhow::Result<()>>)),
+    /// Manually produces the next block with the specified transactions instead of
+    /// the transactions from the `TxPool`.
+    ManualBlockWithTransactions(
+        (
+            ManualProductionWithTransactions,
+            oneshot::Sender<anyhow::Result<()>>,
+        ),
+    ),
+    /// Manually produces the next block if its coinbase is above the threshold.
+    ManualBlockIfCoinbaseOver(
+        (
+            CoinbaseThreshold,
+            oneshot::Sender<anyhow::Result<Option<BlockHeight>>>,
+        ),
+    ),
+    /// Replaces the gas limit of the next blocks.
+    SetBlockGasLimit((Word, oneshot::Sender<anyhow::Result<()>>)),
 }
 
 impl core::fmt::Debug for Request {
@@ -134,6 +231,10 @@ pub struct MainTask<T, B, I> {
     last_timestamp: Tai64,
     last_block_created: Instant,
     trigger: Trigger,
+    max_importer_queue_depth: Option<usize>,
+    importer_backoff: Duration,
+    deterministic_time: Option<DeterministicTime>,
+    metrics: bool,
     /// Deadline clock, used by the triggers
     timer: DeadlineClock,
     sync_task_handle: ServiceRunner<SyncTask>,
@@ -166,6 +267,10 @@ where
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            max_importer_queue_depth,
+            importer_backoff,
+            deterministic_time,
+            metrics,
             ..
         } = config;
 
@@ -192,6 +297,10 @@ where
             last_timestamp,
             last_block_created,
             trigger,
+            max_importer_queue_depth,
+            importer_backoff,
+            deterministic_time,
+            metrics,
             timer: DeadlineClock::new(),
             sync_task_handle,
         }
@@ -208,6 +317,19 @@ where
         (last_height, last_timestamp, last_block_created)
     }
 
+    /// Returns the delay of the next block while the importer lags behind the producer.
+    /// The delay grows with the number of the blocks above the `max_importer_queue_depth`.
+    fn importer_backoff(&self) -> Duration {
+        match self.max_importer_queue_depth {
+            Some(max_depth) => {
+                let excess = self.block_importer.queue_depth().saturating_sub(max_depth);
+                self.importer_backoff
+                    .saturating_mul(u32::try_from(excess).unwrap_or(u32::MAX))
+            }
+            None => Duration::ZERO,
+        }
+    }
+
     fn next_height(&self) -> BlockHeight {
         self.last_height
             .succ()
@@ -215,6 +337,17 @@ where
     }
 
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
+        if let Some(deterministic_time) = &self.deterministic_time {
+            // The manual start time may be ahead of the deterministic time, but
+            // the timestamps of the blocks can't decrease.
+            let time = deterministic_time.timestamp(self.next_height());
+            return if time > self.last_timestamp {
+                Ok(time)
+            } else {
+                Ok(self.last_timestamp)
+            }
+        }
+
         match request_type {
             RequestType::Manual => match self.trigger {
                 Trigger::Never | Trigger::Instant => {
@@ -243,21 +376,38 @@ where
     B: BlockProducer<Database = D>,
     I: BlockImporter<Database = D>,
 {
-    // Request the block producer to make a new block, and return it when ready
+    // Request the block producer to make a new block, and return it when ready.
+    // If `transactions` are specified, they are used instead of the `TxPool`.
     async fn signal_produce_block(
         &self,
         height: BlockHeight,
         block_time: Tai64,
+        transactions: Option<Vec<Transaction>>,
     ) -> anyhow::Result<UncommittedExecutionResult<StorageTransaction<D>>> {
-        self.block_producer
-            .produce_and_execute_block(height, block_time, self.block_gas_limit)
-            .await
+        match transactions {
+            Some(transactions) => {
+                self.block_producer
+                    .produce_and_execute_block_with_transactions(
+                        height,
+                        block_time,
+                        transactions,
+                        self.block_gas_limit,
+                    )
+                    .await
+            }
+            None => {
+                self.block_producer
+                    .produce_and_execute_block(height, block_time, self.block_gas_limit)
+                    .await
+            }
+        }
     }
 
     pub(crate) async fn produce_next_block(&mut self) -> anyhow::Result<()> {
         self.produce_block(
             self.next_height(),
             self.next_time(RequestType::Trigger)?,
+            None,
             RequestType::Trigger,
         )
         .await
@@ -273,25 +423,82 @@ where
             self.next_time(RequestType::Manual)?
         };
         for _ in 0..block_production.number_of_blocks {
-            self.produce_block(self.next_height(), block_time, RequestType::Manual)
+            self.produce_block(self.next_height(), block_time, None, RequestType::Manual)
                 .await?;
             block_time = self.next_time(RequestType::Manual)?;
         }
         Ok(())
     }
 
+    async fn produce_manual_block_with_transactions(
+        &mut self,
+        block_production: ManualProductionWithTransactions,
+    ) -> anyhow::Result<()> {
+        let block_time = if let Some(time) = block_production.start_time {
+            time
+        } else {
+            self.next_time(RequestType::Manual)?
+        };
+        self.produce_block(
+            self.next_height(),
+            block_time,
+            Some(block_production.transactions),
+            RequestType::Manual,
+        )
+        .await
+    }
+
+    async fn produce_manual_block_if_coinbase_over(
+        &mut self,
+        threshold: CoinbaseThreshold,
+    ) -> anyhow::Result<Option<BlockHeight>> {
+        let height = self.next_height();
+        let block_time = self.next_time(RequestType::Manual)?;
+        let produced = self
+            .try_produce_block(
+                height,
+                block_time,
+                None,
+                RequestType::Manual,
+                Some(threshold),
+            )
+            .await?;
+        Ok(produced.then_some(height))
+    }
+
     async fn produce_block(
         &mut self,
         height: BlockHeight,
         block_time: Tai64,
+        transactions: Option<Vec<Transaction>>,
         request_type: RequestType,
     ) -> anyhow::Result<()> {
+        self.try_produce_block(height, block_time, transactions, request_type, None)
+            .await?;
+        Ok(())
+    }
+
+    // Produces the block and commits it, unless its coinbase is not above
+    // the `coinbase_threshold`. Returns `true` if the block is committed.
+    async fn try_produce_block(
+        &mut self,
+        height: BlockHeight,
+        block_time: Tai64,
+        transactions: Option<Vec<Transaction>>,
+        request_type: RequestType,
+        coinbase_threshold: Option<CoinbaseThreshold>,
+    ) -> anyhow::Result<bool> {
         let last_block_created = Instant::now();
         // verify signing key is set
         if self.signing_key.is_none() {
             return Err(anyhow!("unable to produce blocks without a consensus key"))
         }
 
+        // The block can't be committed, so it is not produced at all.
+        if self.block_importer.is_read_only() {
+            return Err(anyhow!("unable to produce blocks in the read-only mode"))
+        }
+
         if self.last_timestamp > block_time {
             return Err(anyhow!("The block timestamp should monotonically increase"))
         }
@@ -302,9 +509,13 @@ where
                 block,
                 skipped_transactions,
                 tx_status,
+                contract_balance_changes,
             },
             db_transaction,
-        ) = self.signal_produce_block(height, block_time).await?.into();
+        ) = self
+            .signal_produce_block(height, block_time, transactions)
+            .await?
+            .into();
 
         let mut tx_ids_to_remove = Vec::with_capacity(skipped_transactions.len());
         for (tx_id, err) in skipped_transactions {
@@ -317,6 +528,27 @@ where
         }
         self.txpool.remove_txs(tx_ids_to_remove);
 
+        let coinbase = match block.transactions().last() {
+            Some(Transaction::Mint(mint)) => *mint.mint_amount(),
+            _ => 0,
+        };
+        if let Some(CoinbaseThreshold {
+            asset_id,
+            threshold,
+        }) = coinbase_threshold
+        {
+            // The block is checked after the execution, so the coinbase is the one
+            // of the block that would be committed, with the current gas limit.
+            let coinbase = match block.transactions().last() {
+                Some(Transaction::Mint(mint)) if mint.mint_asset_id() == &asset_id => {
+                    *mint.mint_amount()
+                }
+                _ => 0,
+            };
+            if coinbase <= threshold {
+                return Ok(false)
+            }
+        }
         // Sign the block and seal it
         let seal = seal_block(&self.signing_key, &block)?;
         let block = SealedBlock {
@@ -325,10 +557,24 @@ where
         };
         // Import the sealed block
         self.block_importer.commit_result(Uncommitted::new(
-            ImportResult::new_from_local(block, tx_status),
+            ImportResult::new_from_local(block, tx_status)
+                .with_contract_balance_changes(contract_balance_changes),
             db_transaction,
         ))?;
 
+        // Update the producer metrics after the block is successfully imported
+        if self.metrics {
+            producer_metrics().blocks_produced.inc();
+            producer_metrics().total_coinbase.inc_by(coinbase);
+            let current_time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|time| time.as_secs())
+                .unwrap_or_default();
+            producer_metrics()
+                .latest_block_production_timestamp
+                .set(i64::try_from(current_time).unwrap_or(i64::MAX));
+        }
+
         // Update last block time
         self.last_height = height;
         self.last_timestamp = block_time;
@@ -342,6 +588,7 @@ where
             }
             (Trigger::Instant, _) => {}
             (Trigger::Interval { block_time }, RequestType::Trigger) => {
+                let block_time = block_time.saturating_add(self.importer_backoff());
                 let deadline = last_block_created.checked_add(block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
                 self.timer.set_deadline(deadline, OnConflict::Min).await;
             }
@@ -353,7 +600,7 @@ where
             }
         }
 
-        Ok(())
+        Ok(true)
     }
 
     pub(crate) async fn on_txpool_event(&mut self) -> anyhow::Result<()> {
@@ -362,7 +609,13 @@ where
                 let pending_number = self.txpool.pending_number();
                 // skip production if there are no pending transactions
                 if pending_number > 0 {
-                    self.produce_next_block().await?;
+                    let backoff = self.importer_backoff();
+                    if backoff.is_zero() {
+                        self.produce_next_block().await?;
+                    } else {
+                        // Postpone the production until the importer catches up.
+                        self.timer.set_timeout(backoff, OnConflict::Min).await;
+                    }
                 }
                 Ok(())
             }
@@ -372,7 +625,9 @@ where
 
     async fn on_timer(&mut self, _at: Instant) -> anyhow::Result<()> {
         match self.trigger {
-            Trigger::Instant | Trigger::Never => {
+            // In the Instant mode the timer expires only when the production was postponed.
+            Trigger::Instant => self.on_txpool_event().await,
+            Trigger::Never => {
                 unreachable!("Timer is never set in this mode");
             }
             // In the Interval mode the timer expires only when a new block should be created.
@@ -460,6 +715,22 @@ where
                             let result = self.produce_manual_blocks(block).await;
                             let _ = response.send(result);
                         }
+                        Request::ManualBlockWithTransactions((block, response)) => {
+                            let result = self
+                                .produce_manual_block_with_transactions(block)
+                                .await;
+                            let _ = response.send(result);
+                        }
+                        Request::ManualBlockIfCoinbaseOver((threshold, response)) => {
+                            let result = self
+                                .produce_manual_block_if_coinbase_over(threshold)
+                                .await;
+                            let _ = response.send(result);
+                        }
+                        Request::SetBlockGasLimit((block_gas_limit, response)) => {
+                            self.block_gas_limit = block_gas_limit;
+                            let _ = response.send(Ok(()));
+                        }
                     }
                     should_continue = true;
                 } else {
@@ -53,6 +53,10 @@ use rand::{
 use std::{
     collections::HashSet,
     sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
         Arc,
         Mutex as StdMutex,
         Mutex,
@@ -129,6 +133,7 @@ impl TestContextBuilder {
                             block: Default::default(),
                             skipped_transactions: Default::default(),
                             tx_status: Default::default(),
+                            contract_balance_changes: Default::default(),
                         },
                         StorageTransaction::new(EmptyStorage),
                     ))
@@ -138,6 +143,7 @@ impl TestContextBuilder {
 
         let importer = self.importer.unwrap_or_else(|| {
             let mut importer = MockBlockImporter::default();
+            importer.expect_is_read_only().returning(|| false);
             importer.expect_commit_result().returning(|_| Ok(()));
             importer
                 .expect_block_stream()
@@ -287,12 +293,14 @@ async fn remove_skipped_transactions() {
                         })
                         .collect(),
                     tx_status: Default::default(),
+                    contract_balance_changes: Default::default(),
                 },
                 StorageTransaction::new(EmptyStorage),
             ))
         });
 
     let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_is_read_only().returning(|| false);
 
     block_importer
         .expect_commit_result()
@@ -360,6 +368,7 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
         .returning(|_, _, _| panic!("Block production should not be called"));
 
     let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_is_read_only().returning(|| false);
 
     block_importer
         .expect_commit_result()
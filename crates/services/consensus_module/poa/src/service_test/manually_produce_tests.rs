@@ -69,6 +69,7 @@ async fn can_manually_produce_block(
     ctx_builder.with_txpool(txpool);
 
     let mut importer = MockBlockImporter::default();
+    importer.expect_is_read_only().returning(|| false);
     let (tx, mut rx) = tokio::sync::mpsc::channel(times.len());
     importer.expect_commit_result().returning(move |r| {
         tx.try_send(r.into_result().sealed_block.entity.header().time())
@@ -91,6 +92,7 @@ async fn can_manually_produce_block(
                     block,
                     skipped_transactions: Default::default(),
                     tx_status: Default::default(),
+                    contract_balance_changes: Default::default(),
                 },
                 StorageTransaction::new(EmptyStorage),
             ))
@@ -116,3 +118,66 @@ async fn can_manually_produce_block(
     // Stop
     assert_eq!(ctx.stop().await, State::Stopped);
 }
+
+#[tokio::test]
+async fn can_manually_produce_block_with_transactions() {
+    let mut rng = StdRng::seed_from_u64(1234u64);
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Never,
+        block_gas_limit: 100_000,
+        signing_key: Some(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    });
+
+    let txs: Vec<Transaction> = (0..3).map(|_| make_tx(&mut rng).into()).collect();
+
+    let mut importer = MockBlockImporter::default();
+    importer.expect_is_read_only().returning(|| false);
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
+    importer.expect_commit_result().returning(move |r| {
+        sender
+            .try_send(r.into_result().sealed_block.entity.transactions().to_vec())
+            .unwrap();
+        Ok(())
+    });
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    // The transactions from the `TxPool` should not be requested.
+    let mut producer = MockBlockProducer::default();
+    producer.expect_produce_and_execute_block().never();
+    producer
+        .expect_produce_and_execute_block_with_transactions()
+        .returning(|_, time, transactions, _| {
+            let mut block = Block::default();
+            block.header_mut().consensus.time = time;
+            *block.transactions_mut() = transactions;
+            block.header_mut().recalculate_metadata();
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    contract_balance_changes: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    ctx_builder.with_importer(importer);
+    ctx_builder.with_producer(producer);
+    let ctx = ctx_builder.build();
+
+    ctx.service
+        .shared
+        .manually_produce_block_with_transactions(None, txs.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(receiver.recv().await.unwrap(), txs);
+
+    // Stop
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
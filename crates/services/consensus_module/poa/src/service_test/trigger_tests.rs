@@ -50,6 +50,7 @@ async fn never_trigger_never_produces_blocks() {
     ctx_builder.with_txpool(txpool);
 
     let mut importer = MockBlockImporter::default();
+    importer.expect_is_read_only().returning(|| false);
     importer
         .expect_commit_result()
         .returning(|_| panic!("Should not commit result"));
@@ -93,6 +94,7 @@ impl DefaultContext {
 
         let (block_import_sender, block_import_receiver) = broadcast::channel(100);
         let mut importer = MockBlockImporter::default();
+        importer.expect_is_read_only().returning(|| false);
         importer.expect_commit_result().returning(move |result| {
             let (result, _) = result.into();
             let sealed_block = result.sealed_block;
@@ -244,3 +246,101 @@ async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Runs the producer with the importer that processes one block per second for
+/// `duration`, and returns the number of produced blocks with the maximum queue depth.
+async fn produce_with_slow_importer(
+    config: Config,
+    duration: Duration,
+) -> anyhow::Result<(usize, usize)> {
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(config);
+    let TxPoolContext { txpool, .. } = MockTransactionPool::new_with_txs(vec![]);
+    ctx_builder.with_txpool(txpool);
+
+    let produced = Arc::new(AtomicUsize::new(0));
+    let queue_depth = Arc::new(AtomicUsize::new(0));
+    let max_queue_depth = Arc::new(AtomicUsize::new(0));
+
+    let mut importer = MockBlockImporter::default();
+    importer.expect_is_read_only().returning(|| false);
+    importer.expect_commit_result().returning({
+        let produced = produced.clone();
+        let queue_depth = queue_depth.clone();
+        let max_queue_depth = max_queue_depth.clone();
+        move |_| {
+            produced.fetch_add(1, Ordering::SeqCst);
+            let depth = queue_depth.fetch_add(1, Ordering::SeqCst) + 1;
+            max_queue_depth.fetch_max(depth, Ordering::SeqCst);
+            Ok(())
+        }
+    });
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    importer.expect_queue_depth().returning({
+        let queue_depth = queue_depth.clone();
+        move || queue_depth.load(Ordering::SeqCst)
+    });
+    ctx_builder.with_importer(importer);
+
+    // The subscriber of the importer processes one block per second.
+    let slow_subscriber = tokio::spawn({
+        let queue_depth = queue_depth.clone();
+        async move {
+            loop {
+                time::sleep(Duration::from_secs(1)).await;
+                let _ = queue_depth.fetch_update(
+                    Ordering::SeqCst,
+                    Ordering::SeqCst,
+                    |depth| depth.checked_sub(1),
+                );
+            }
+        }
+    });
+
+    let ctx = ctx_builder.build();
+    time::sleep(duration).await;
+    ctx.service.stop_and_await().await?;
+    slow_subscriber.abort();
+
+    Ok((
+        produced.load(Ordering::SeqCst),
+        max_queue_depth.load(Ordering::SeqCst),
+    ))
+}
+
+#[tokio::test(start_paused = true)]
+async fn interval_trigger_backs_off_while_importer_lags() -> anyhow::Result<()> {
+    const MAX_QUEUE_DEPTH: usize = 2;
+    let config = Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::from_millis(100),
+        },
+        block_gas_limit: 100_000,
+        signing_key: Some(test_signing_key()),
+        metrics: false,
+        importer_backoff: Duration::from_millis(500),
+        ..Default::default()
+    };
+
+    let (unthrottled_blocks, unthrottled_depth) =
+        produce_with_slow_importer(config.clone(), Duration::from_secs(10)).await?;
+    let (throttled_blocks, throttled_depth) = produce_with_slow_importer(
+        Config {
+            max_importer_queue_depth: Some(MAX_QUEUE_DEPTH),
+            ..config
+        },
+        Duration::from_secs(10),
+    )
+    .await?;
+
+    // Without the throttling the queue grows with each block.
+    assert!(unthrottled_blocks >= 90);
+    assert!(unthrottled_depth >= 80);
+    // With the throttling the producer follows the pace of the importer.
+    assert!(throttled_blocks * 4 < unthrottled_blocks);
+    assert!(throttled_depth <= MAX_QUEUE_DEPTH * 3);
+
+    Ok(())
+}
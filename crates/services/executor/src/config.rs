@@ -1,7 +1,11 @@
-use fuel_core_types::fuel_tx::{
-    ConsensusParameters,
-    ContractId,
+use fuel_core_types::{
+    fuel_tx::{
+        ConsensusParameters,
+        ContractId,
+    },
+    fuel_types::Word,
 };
+use std::str::FromStr;
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
@@ -9,8 +13,78 @@ pub struct Config {
     pub consensus_parameters: ConsensusParameters,
     /// The `ContractId` of the fee recipient.
     pub coinbase_recipient: ContractId,
+    /// Fail the block production if the `coinbase_recipient` is not a deployed contract.
+    /// Otherwise, the fee is not minted.
+    pub fail_on_missing_coinbase_recipient: bool,
+    /// Fail the block production if the mint transaction fails to credit the coinbase
+    /// to the `coinbase_recipient`. The case when the `coinbase_recipient` is not
+    /// a deployed contract is controlled only by the `fail_on_missing_coinbase_recipient`.
+    /// Otherwise, the failed mint transaction is skipped and the fee is lost.
+    pub require_coinbase_success: bool,
+    /// What to do if crediting the fee of the block would overflow the balance
+    /// of the `coinbase_recipient`.
+    pub coinbase_overflow_policy: CoinbaseOverflowPolicy,
     /// Print execution backtraces if transaction execution reverts.
     pub backtrace: bool,
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
+    /// The maximum amount of the base asset that a single `Coin` or `Variable` output
+    /// can hold. Transactions creating bigger outputs are reverted. The `Change` outputs
+    /// are not limited, because the revert returns the spent inputs to them anyway.
+    /// `None` means unlimited.
+    pub max_coin_output_amount: Option<Word>,
+}
+
+/// The behavior of the block production when crediting the fee of the block
+/// would overflow the balance of the coinbase recipient.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CoinbaseOverflowPolicy {
+    /// Execute the mint transaction as usual. It fails to increase the balance, so it is
+    /// skipped and the fee is lost, unless the `require_coinbase_success` fails
+    /// the block production. It is the default, because it is how the nodes handled
+    /// the overflow before the policy was introduced, so the upgraded nodes keep
+    /// producing the same blocks unless the operator opts into `Burn` or `Halt`.
+    #[default]
+    Skip,
+    /// Burn the fee of the block and log a warning. The mint transaction must credit
+    /// exactly the fee of the block, so the credit can't be capped by the remaining
+    /// capacity of the balance, and the whole fee is burned instead.
+    Burn,
+    /// Fail the block production with an error.
+    Halt,
+}
+
+impl FromStr for CoinbaseOverflowPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "skip" => Ok(Self::Skip),
+            "burn" => Ok(Self::Burn),
+            "halt" => Ok(Self::Halt),
+            _ => Err(format!("unknown coinbase overflow policy `{s}`")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coinbase_overflow_policy_is_parsed_ignoring_case() {
+        assert_eq!(
+            "SKIP".parse::<CoinbaseOverflowPolicy>(),
+            Ok(CoinbaseOverflowPolicy::Skip)
+        );
+        assert_eq!(
+            "burn".parse::<CoinbaseOverflowPolicy>(),
+            Ok(CoinbaseOverflowPolicy::Burn)
+        );
+        assert_eq!(
+            "Halt".parse::<CoinbaseOverflowPolicy>(),
+            Ok(CoinbaseOverflowPolicy::Halt)
+        );
+        assert!("cap".parse::<CoinbaseOverflowPolicy>().is_err());
+    }
 }
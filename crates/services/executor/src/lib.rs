@@ -9,7 +9,10 @@ pub mod refs;
 
 pub struct BlockExecutor {}
 
-pub use config::Config;
+pub use config::{
+    CoinbaseOverflowPolicy,
+    Config,
+};
 
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
@@ -2,6 +2,9 @@
 pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
+    /// The number of consecutive failed writes of the blocks after which the importer
+    /// switches into the read-only mode. `None` means the importer never switches.
+    pub max_consecutive_write_failures: Option<usize>,
 }
 
 impl Default for Config {
@@ -9,6 +12,7 @@ impl Default for Config {
         Self {
             max_block_notify_buffer: 1 << 10,
             metrics: false,
+            max_consecutive_write_failures: None,
         }
     }
 }
@@ -35,7 +35,15 @@ use fuel_core_types::{
 };
 use std::{
     ops::Deref,
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicBool,
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
     time::{
         Instant,
         SystemTime,
@@ -83,6 +91,10 @@ pub enum Error {
     ExecuteGenesis,
     #[display(fmt = "The database already contains the data at the height {_0}.")]
     NotUnique(BlockHeight),
+    #[display(
+        fmt = "The importer is in the read-only mode because of the repeated write failures."
+    )]
+    ReadOnly,
     #[from]
     StorageError(StorageError),
 }
@@ -106,6 +118,12 @@ pub struct Importer<D, E, V> {
     verifier: V,
     broadcast: broadcast::Sender<Arc<ImportResult>>,
     guard: tokio::sync::Semaphore,
+    /// Held while the changes are written into the database. Allows other components
+    /// to do maintenance of the database without racing with the block commits.
+    commit_lock: Mutex<()>,
+    max_consecutive_write_failures: Option<usize>,
+    consecutive_write_failures: AtomicUsize,
+    read_only: AtomicBool,
 }
 
 impl<D, E, V> Importer<D, E, V> {
@@ -118,6 +136,10 @@ impl<D, E, V> Importer<D, E, V> {
             verifier,
             broadcast,
             guard: tokio::sync::Semaphore::new(1),
+            commit_lock: Mutex::new(()),
+            max_consecutive_write_failures: config.max_consecutive_write_failures,
+            consecutive_write_failures: AtomicUsize::new(0),
+            read_only: AtomicBool::new(false),
         }
     }
 
@@ -125,6 +147,11 @@ impl<D, E, V> Importer<D, E, V> {
         self.broadcast.subscribe()
     }
 
+    /// Returns the number of the import results not yet received by the slowest subscriber.
+    pub fn queue_depth(&self) -> usize {
+        self.broadcast.len()
+    }
+
     pub(crate) fn lock(&self) -> Result<tokio::sync::SemaphorePermit, Error> {
         let guard = self.guard.try_acquire();
         match guard {
@@ -138,6 +165,67 @@ impl<D, E, V> Importer<D, E, V> {
             }
         }
     }
+
+    /// Runs `f` holding the lock used by the block commits, so no block is
+    /// committed into the database until `f` is finished.
+    pub fn with_commit_lock<R>(&self, f: impl FnOnce() -> R) -> R {
+        let _commit_lock = self
+            .commit_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        f()
+    }
+
+    /// Returns `true` if the importer stopped committing blocks
+    /// because of the repeated write failures.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::Relaxed)
+    }
+
+    fn ensure_writable(&self) -> Result<(), Error> {
+        if self.is_read_only() {
+            Err(Error::ReadOnly)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Counts the consecutive write failures and switches the importer into
+    /// the read-only mode when the limit from the [`Config`] is reached.
+    fn track_write_result(&self, result: &Result<(), StorageError>) {
+        let err = match result {
+            Ok(()) => {
+                self.consecutive_write_failures.store(0, Ordering::Relaxed);
+                return
+            }
+            Err(err) => err,
+        };
+
+        let failures = self
+            .consecutive_write_failures
+            .fetch_add(1, Ordering::Relaxed)
+            .saturating_add(1);
+        tracing::warn!("Failed to write the block {failures} time(s) in a row: {err}");
+
+        if let Some(max_failures) = self.max_consecutive_write_failures {
+            if failures >= max_failures {
+                tracing::error!(
+                    "Switching to the read-only mode after {failures} consecutive \
+                    write failures."
+                );
+                self.enter_read_only_mode();
+            }
+        }
+    }
+
+    /// Stops committing the blocks. The block production and the transactions
+    /// received from the peers are rejected too, and the node only serves the queries.
+    pub fn enter_read_only_mode(&self) {
+        if !self.read_only.swap(true, Ordering::Relaxed) {
+            tracing::error!("The node only serves the queries from now on.");
+            importer_metrics().read_only_mode.set(1);
+        }
+    }
 }
 
 impl<D, E, V> Importer<D, E, V>
@@ -165,6 +253,7 @@ where
         ExecutorDatabase: ports::ExecutorDatabase,
     {
         let _guard = self.lock()?;
+        self.ensure_writable()?;
         self._commit_result(result)
     }
 
@@ -246,7 +335,9 @@ where
             // Safety: casting len to u64 since it's impossible to execute a block with more than 2^64 txs
             .increase_tx_count(result.sealed_block.entity.transactions().len() as u64)?;
 
-        db_tx.commit()?;
+        let commit = self.with_commit_lock(|| db_tx.commit());
+        self.track_write_result(&commit);
+        commit?;
 
         // update the importer metrics after the block is successfully committed
         importer_metrics().total_txs_count.set(total_txs as i64);
@@ -337,6 +428,7 @@ where
                 block,
                 skipped_transactions,
                 tx_status,
+                contract_balance_changes,
             },
             db_tx,
         ) = self
@@ -361,7 +453,8 @@ where
             entity: block,
             consensus,
         };
-        let import_result = ImportResult::new_from_network(sealed_block, tx_status);
+        let import_result = ImportResult::new_from_network(sealed_block, tx_status)
+            .with_contract_balance_changes(contract_balance_changes);
 
         Ok(Uncommitted::new(import_result, db_tx))
     }
@@ -377,6 +470,7 @@ where
     /// It is a combination of the [`Importer::verify_and_execute_block`] and [`Importer::commit_result`].
     pub fn execute_and_commit(&self, sealed_block: SealedBlock) -> Result<(), Error> {
         let _guard = self.lock()?;
+        self.ensure_writable()?;
         let start = Instant::now();
         let result = self.verify_and_execute_block(sealed_block)?;
         let commit_result = self._commit_result(result);
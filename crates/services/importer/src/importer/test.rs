@@ -6,6 +6,7 @@ use crate::{
         MockBlockVerifier,
         MockExecutor,
     },
+    Config,
     Importer,
 };
 use anyhow::anyhow;
@@ -187,6 +188,7 @@ where
                     block: mock_result.block.entity,
                     skipped_transactions,
                     tx_status: vec![],
+                    contract_balance_changes: vec![],
                 },
                 StorageTransaction::new(database),
             ))
@@ -555,3 +557,75 @@ fn verify_and_execute_allowed_when_locked() {
     let _guard = importer.lock();
     assert!(importer.verify_and_execute_block(poa_block(13)).is_ok());
 }
+
+fn failing_commit_db(height: u32) -> MockDatabase {
+    let mut db = MockDatabase::default();
+    db.expect_latest_block_height()
+        .returning(move || Ok(height.into()));
+    db.expect_seal_block().returning(|_, _| Ok(None));
+    db.expect_increase_tx_count().returning(Ok);
+    db.expect_commit().times(1).returning(|| storage_failure());
+    db
+}
+
+fn poa_result(
+    height: u32,
+    executor_db: MockDatabase,
+) -> UncommittedResult<StorageTransaction<MockDatabase>> {
+    UncommittedResult::new(
+        ImportResult::new_from_local(poa_block(height), vec![]),
+        StorageTransaction::new(executor_db),
+    )
+}
+
+#[test]
+fn commit_result_switches_to_read_only_after_consecutive_write_failures() {
+    let config = Config {
+        max_consecutive_write_failures: Some(3),
+        ..Default::default()
+    };
+    let importer = Importer::new(config, underlying_db(ok(112))(), (), ());
+
+    // When
+    for _ in 0..3 {
+        assert!(!importer.is_read_only());
+        assert_eq!(
+            importer.commit_result(poa_result(113, failing_commit_db(113))),
+            Err(storage_failure_error())
+        );
+    }
+
+    // Then
+    assert!(importer.is_read_only());
+    // The database is not touched anymore during the commit.
+    assert_eq!(
+        importer.commit_result(poa_result(113, MockDatabase::default())),
+        Err(Error::ReadOnly)
+    );
+    // But the queries are still served.
+    assert_eq!(
+        importer.database.latest_block_height().unwrap(),
+        112u32.into()
+    );
+}
+
+#[test]
+fn successful_commit_resets_the_write_failures_counter() {
+    let config = Config {
+        max_consecutive_write_failures: Some(2),
+        ..Default::default()
+    };
+    let importer = Importer::new(config, underlying_db(ok(112))(), (), ());
+
+    assert!(importer
+        .commit_result(poa_result(113, failing_commit_db(113)))
+        .is_err());
+    assert!(importer
+        .commit_result(poa_result(113, executor_db(ok(113), ok(None), 1)()))
+        .is_ok());
+    assert!(importer
+        .commit_result(poa_result(113, failing_commit_db(113)))
+        .is_err());
+
+    assert!(!importer.is_read_only());
+}
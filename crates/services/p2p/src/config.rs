@@ -140,6 +140,14 @@ pub struct Config<State = Initialized> {
     /// Max time since a given peer has sent a heartbeat before getting reputation penalty
     pub heartbeat_max_time_since_last: Duration,
 
+    /// The interval at which the outbound transactions are gossiped to peers.
+    /// All transactions received during the interval are coalesced and gossiped at once.
+    /// If it's not set, each transaction is gossiped as soon as it is received.
+    pub tx_gossip_interval: Option<Duration>,
+    /// Max number of transactions gossiped per `tx_gossip_interval`.
+    /// The rest of the transactions waits for the next interval.
+    pub max_txs_per_gossip_interval: usize,
+
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
 
@@ -188,6 +196,8 @@ impl Config<NotInitialized> {
             heartbeat_check_interval: self.heartbeat_check_interval,
             heartbeat_max_avg_interval: self.heartbeat_max_time_since_last,
             heartbeat_max_time_since_last: self.heartbeat_max_time_since_last,
+            tx_gossip_interval: self.tx_gossip_interval,
+            max_txs_per_gossip_interval: self.max_txs_per_gossip_interval,
             metrics: self.metrics,
             state: Initialized(()),
         })
@@ -235,6 +245,8 @@ impl Config<NotInitialized> {
             heartbeat_max_time_since_last: Duration::from_secs(40),
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
+            tx_gossip_interval: None,
+            max_txs_per_gossip_interval: 100,
             metrics: false,
             state: NotInitialized,
         }
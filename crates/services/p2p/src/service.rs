@@ -38,6 +38,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UniqueIdentifier,
     },
     fuel_types::{
@@ -68,6 +69,10 @@ use libp2p::{
 };
 use libp2p_request_response::RequestId;
 use std::{
+    collections::{
+        HashSet,
+        VecDeque,
+    },
     fmt::Debug,
     ops::Range,
     sync::Arc,
@@ -81,10 +86,15 @@ use tokio::{
     time::{
         Duration,
         Instant,
+        Interval,
+        MissedTickBehavior,
     },
 };
 use tracing::warn;
 
+/// The maximum number of transactions waiting to be gossiped.
+const MAX_PENDING_GOSSIP_TXS: usize = 1024 * 10;
+
 pub type Service<D> = ServiceRunner<Task<FuelP2PService<PostcardCodec>, D, SharedState>>;
 
 enum TaskRequest {
@@ -313,6 +323,69 @@ pub struct Task<P, D, B> {
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    /// Limits the rate of the outbound transactions gossip, if configured.
+    tx_gossip_throttle: Option<TxGossipThrottle>,
+}
+
+/// Coalesces the outbound transactions and gossips them once per interval.
+struct TxGossipThrottle {
+    timer: Interval,
+    max_txs_per_interval: usize,
+    pending: VecDeque<(TxId, Arc<Transaction>)>,
+    pending_ids: HashSet<TxId>,
+}
+
+impl TxGossipThrottle {
+    fn new(interval: Duration, max_txs_per_interval: usize) -> Self {
+        let mut timer = tokio::time::interval(interval);
+        timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // The first tick completes immediately, skip it to coalesce the first interval too.
+        timer.reset();
+
+        Self {
+            timer,
+            max_txs_per_interval,
+            pending: VecDeque::new(),
+            pending_ids: HashSet::new(),
+        }
+    }
+
+    /// Queues the transaction for the next gossip. The transaction
+    /// is ignored if it is already queued or the queue is full.
+    fn queue(&mut self, tx_id: TxId, transaction: Arc<Transaction>) {
+        if self.pending.len() >= MAX_PENDING_GOSSIP_TXS {
+            tracing::warn!(
+                "The gossip queue is full, dropping the transaction {}",
+                tx_id
+            );
+            return
+        }
+
+        if self.pending_ids.insert(tx_id) {
+            self.pending.push_back((tx_id, transaction));
+        }
+    }
+
+    /// Takes the transactions that should be gossiped during the current interval.
+    fn next_batch(&mut self) -> Vec<(TxId, Arc<Transaction>)> {
+        let len = self.pending.len().min(self.max_txs_per_interval);
+        let batch: Vec<_> = self.pending.drain(..len).collect();
+        for (tx_id, _) in batch.iter() {
+            self.pending_ids.remove(tx_id);
+        }
+        batch
+    }
+}
+
+/// Resolves when the next batch of transactions should be gossiped.
+/// Never resolves if the throttling is disabled.
+async fn next_tx_gossip_tick(throttle: &mut Option<TxGossipThrottle>) {
+    match throttle {
+        Some(throttle) => {
+            throttle.timer.tick().await;
+        }
+        None => futures::future::pending().await,
+    }
 }
 
 #[derive(Clone)]
@@ -334,6 +407,8 @@ impl<D> Task<FuelP2PService<PostcardCodec>, D, SharedState> {
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
+            tx_gossip_interval,
+            max_txs_per_gossip_interval,
             ..
         } = config;
         let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
@@ -376,10 +451,25 @@ impl<D> Task<FuelP2PService<PostcardCodec>, D, SharedState> {
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            tx_gossip_throttle: tx_gossip_interval.map(|interval| {
+                TxGossipThrottle::new(interval, max_txs_per_gossip_interval)
+            }),
         }
     }
 }
 impl<P: TaskP2PService, D, B: Broadcast> Task<P, D, B> {
+    fn publish_transaction(&mut self, tx_id: TxId, transaction: Arc<Transaction>) {
+        let broadcast = GossipsubBroadcastRequest::NewTx(transaction);
+        let result = self.p2p_service.publish_message(broadcast);
+        if let Err(e) = result {
+            tracing::error!(
+                "Got an error during transaction {} broadcasting {}",
+                tx_id,
+                e
+            );
+        }
+    }
+
     fn peer_heartbeat_reputation_checks(&self) -> anyhow::Result<()> {
         for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
             if peer_info.heartbeat_data.duration_since_last_heartbeat()
@@ -471,15 +561,23 @@ where
                 should_continue = false;
             }
 
+            _ = next_tx_gossip_tick(&mut self.tx_gossip_throttle) => {
+                should_continue = true;
+                if let Some(throttle) = self.tx_gossip_throttle.as_mut() {
+                    for (tx_id, transaction) in throttle.next_batch() {
+                        self.publish_transaction(tx_id, transaction);
+                    }
+                }
+            }
+
             next_service_request = self.request_receiver.recv() => {
                 should_continue = true;
                 match next_service_request {
                     Some(TaskRequest::BroadcastTransaction(transaction)) => {
                         let tx_id = transaction.id(&self.chain_id);
-                        let broadcast = GossipsubBroadcastRequest::NewTx(transaction);
-                        let result = self.p2p_service.publish_message(broadcast);
-                        if let Err(e) = result {
-                            tracing::error!("Got an error during transaction {} broadcasting {}", tx_id, e);
+                        match self.tx_gossip_throttle.as_mut() {
+                            Some(throttle) => throttle.queue(tx_id, transaction),
+                            None => self.publish_transaction(tx_id, transaction),
                         }
                     }
                     Some(TaskRequest::GetPeerIds(channel)) => {
@@ -843,7 +941,10 @@ pub mod tests {
         State,
     };
     use fuel_core_storage::Result as StorageResult;
-    use fuel_core_types::fuel_types::BlockHeight;
+    use fuel_core_types::{
+        fuel_tx::TransactionBuilder,
+        fuel_types::BlockHeight,
+    };
     use futures::FutureExt;
     use std::collections::VecDeque;
 
@@ -903,6 +1004,7 @@ pub mod tests {
 
     struct FakeP2PService {
         peer_info: Vec<(PeerId, PeerInfo)>,
+        published_messages: Vec<GossipsubBroadcastRequest>,
     }
 
     impl TaskP2PService for FakeP2PService {
@@ -927,9 +1029,10 @@ pub mod tests {
 
         fn publish_message(
             &mut self,
-            _message: GossipsubBroadcastRequest,
+            message: GossipsubBroadcastRequest,
         ) -> anyhow::Result<()> {
-            todo!()
+            self.published_messages.push(message);
+            Ok(())
         }
 
         fn send_request_msg(
@@ -1059,7 +1162,10 @@ pub mod tests {
             score: 100.0,
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            published_messages: vec![],
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
@@ -1091,6 +1197,7 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            tx_gossip_throttle: None,
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1138,7 +1245,10 @@ pub mod tests {
             score: 100.0,
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            published_messages: vec![],
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
@@ -1170,6 +1280,7 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            tx_gossip_throttle: None,
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1193,4 +1304,92 @@ pub mod tests {
         );
         assert_eq!(reporting_service, "p2p");
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn run__throttled_tx_gossip_publishes_unique_transactions_in_batches() {
+        // given
+        let p2p_service = FakeP2PService {
+            peer_info: vec![],
+            published_messages: vec![],
+        };
+        let (request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, _report_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: report_sender,
+        };
+        let gossip_interval = Duration::from_secs(1);
+        let max_txs_per_interval = 2;
+
+        let mut task = Task {
+            chain_id: Default::default(),
+            p2p_service,
+            db: Arc::new(FakeDB),
+            next_block_height: FakeBlockImporter.next_block_height(),
+            request_receiver,
+            broadcast,
+            max_headers_per_request: 0,
+            heartbeat_check_interval: Duration::from_secs(3600),
+            heartbeat_max_avg_interval: Duration::from_secs(20),
+            heartbeat_max_time_since_last: Duration::from_secs(40),
+            next_check_time: Instant::now() + Duration::from_secs(3600),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: 5.6,
+                low_heartbeat_frequency_penalty: 20.45,
+            },
+            tx_gossip_throttle: Some(TxGossipThrottle::new(
+                gossip_interval,
+                max_txs_per_interval,
+            )),
+        };
+        let (_watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
+        let mut watcher = StateWatcher::from(watch_receiver);
+
+        let txs: Vec<_> = (0..3u8)
+            .map(|i| {
+                Arc::new(
+                    TransactionBuilder::script(vec![i], vec![]).finalize_as_transaction(),
+                )
+            })
+            .collect();
+        // The duplicate of the first transaction should be coalesced.
+        let requests = [&txs[0], &txs[1], &txs[0], &txs[2]];
+        for tx in requests.iter() {
+            request_sender
+                .try_send(TaskRequest::BroadcastTransaction((*tx).clone()))
+                .unwrap();
+        }
+
+        // when
+        for _ in 0..requests.len() {
+            task.run(&mut watcher).await.unwrap();
+        }
+
+        // then
+        assert!(task.p2p_service.published_messages.is_empty());
+
+        // when
+        let start = Instant::now();
+        task.run(&mut watcher).await.unwrap();
+
+        // then
+        assert!(start.elapsed() >= gossip_interval);
+        assert_eq!(
+            task.p2p_service.published_messages.len(),
+            max_txs_per_interval
+        );
+
+        // when
+        task.run(&mut watcher).await.unwrap();
+
+        // then
+        let published: Vec<_> = task
+            .p2p_service
+            .published_messages
+            .iter()
+            .map(|message| match message {
+                GossipsubBroadcastRequest::NewTx(tx) => tx.clone(),
+            })
+            .collect();
+        assert_eq!(published, txs);
+    }
 }
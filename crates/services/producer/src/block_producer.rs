@@ -18,6 +18,8 @@ use fuel_core_types::{
     },
     fuel_asm::Word,
     fuel_tx::{
+        field::ScriptGasLimit,
+        Mint,
         Receipt,
         Transaction,
     },
@@ -27,7 +29,10 @@ use fuel_core_types::{
     },
     services::{
         block_producer::Components,
-        executor::UncommittedResult,
+        executor::{
+            ExecutionResult,
+            UncommittedResult,
+        },
     },
     tai64::Tai64,
 };
@@ -53,6 +58,13 @@ pub enum Error {
         best: DaBlockHeight,
         previous_block: DaBlockHeight,
     },
+    #[display(
+        fmt = "The gas limit {gas_limit} of the transaction exceeds the maximum dry run gas {max_dry_run_gas}"
+    )]
+    DryRunGasLimitExceeded {
+        gas_limit: Word,
+        max_dry_run_gas: Word,
+    },
 }
 
 impl From<Error> for anyhow::Error {
@@ -120,6 +132,104 @@ where
         Ok(result)
     }
 
+    /// Produces and execute block for the specified height with the `transactions`
+    /// in the specified order. The transactions from the `TxPool` are not included.
+    pub async fn produce_and_execute_block_with_transactions(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        transactions: Vec<Transaction>,
+        max_gas: Word,
+    ) -> anyhow::Result<UncommittedResult<StorageTransaction<ExecutorDB>>> {
+        // prevent simultaneous block production calls, the guard will drop at the end of this fn.
+        let _production_guard = self.lock.lock().await;
+
+        let header = self.new_header(height, block_time).await?;
+
+        let component = Components {
+            header_to_produce: header,
+            transactions_source: transactions,
+            gas_limit: max_gas,
+        };
+
+        // Store the context string incase we error.
+        let context_string =
+            format!("Failed to produce block {height:?} due to execution failure");
+        let result = self
+            .executor
+            .execute_transactions_without_commit(component)
+            .map_err(Into::<anyhow::Error>::into)
+            .context(context_string)?;
+
+        debug!("Produced block with result: {:?}", result.result());
+        Ok(result)
+    }
+
+    /// Executes the next block with the transactions from the `TxPool` without committing
+    /// it, and returns the mint transaction that would credit the coinbase recipient.
+    /// The transactions stay in the `TxPool`. Like the `dry_run`, it doesn't acquire
+    /// the production lock, because it doesn't alter the state.
+    pub async fn pending_coinbase(&self, max_gas: Word) -> anyhow::Result<Mint>
+    where
+        TxSource: Send + 'static,
+    {
+        let height = self
+            .db
+            .current_block_height()?
+            .succ()
+            .expect("It is impossible to overflow the current block height");
+
+        // Like the `dry_run`, the preview uses the DA height of the last block
+        // to not wait for the relayer.
+        let header = self._new_header(height, Tai64::now())?;
+        let component = Components {
+            header_to_produce: header,
+            transactions_source: self.txpool.get_source(height),
+            gas_limit: max_gas,
+        };
+
+        let executor = self.executor.clone();
+        // use the blocking threadpool to avoid clogging up the main async runtime
+        tokio_rayon::spawn_fifo(move || -> anyhow::Result<Mint> {
+            // The database transaction is dropped without the commit.
+            let (ExecutionResult { block, .. }, _) =
+                executor.execute_without_commit(component)?.into();
+
+            // The mint transaction is always the last one in the block.
+            match block.transactions().last() {
+                Some(Transaction::Mint(mint)) => Ok(mint.clone()),
+                _ => Err(anyhow!(
+                    "The produced block doesn't have the mint transaction"
+                )),
+            }
+        })
+        .await
+    }
+
+    /// Executes the `transactions` in the specified order on top of the current state as
+    /// the next block with the `block_time` without committing it. The block includes
+    /// the mint transaction crediting the coinbase, like the produced block does.
+    pub async fn replay_transactions(
+        &self,
+        transactions: Vec<Transaction>,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<UncommittedResult<StorageTransaction<ExecutorDB>>> {
+        let height = self
+            .db
+            .current_block_height()?
+            .succ()
+            .expect("It is impossible to overflow the current block height");
+
+        self.produce_and_execute_block_with_transactions(
+            height,
+            block_time,
+            transactions,
+            max_gas,
+        )
+        .await
+    }
+
     // TODO: Support custom `block_time` for `dry_run`.
     /// Simulate a transaction without altering any state. Does not aquire the production lock
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
@@ -130,6 +240,21 @@ where
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<Receipt>> {
+        // The script can't consume more gas than its limit, so rejecting the limits above
+        // the maximum caps the gas of the dry run without spending it.
+        if let (Some(max_dry_run_gas), Some(script)) =
+            (self.config.max_dry_run_gas, transaction.as_script())
+        {
+            let gas_limit = *script.script_gas_limit();
+            if gas_limit > max_dry_run_gas {
+                return Err(Error::DryRunGasLimitExceeded {
+                    gas_limit,
+                    max_dry_run_gas,
+                }
+                .into())
+            }
+        }
+
         let height = match height {
             None => self
                 .db
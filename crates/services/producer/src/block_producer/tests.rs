@@ -23,6 +23,10 @@ use fuel_core_types::{
             PartialBlockHeader,
         },
     },
+    fuel_tx::{
+        Transaction,
+        TransactionBuilder,
+    },
     services::executor::Error as ExecutorError,
     tai64::Tai64,
 };
@@ -31,9 +35,12 @@ use rand::{
     Rng,
     SeedableRng,
 };
-use std::sync::{
-    Arc,
-    Mutex,
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
 };
 
 #[tokio::test]
@@ -64,6 +71,76 @@ async fn can_produce_initial_block() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn can_produce_block_with_specified_transactions() {
+    let ctx = TestContext::default();
+    let producer = ctx.producer();
+    let transactions = vec![
+        Transaction::default_test_tx(),
+        Transaction::default_test_tx(),
+    ];
+
+    let result = producer
+        .produce_and_execute_block_with_transactions(
+            1u32.into(),
+            Tai64::now(),
+            transactions.clone(),
+            1_000_000_000,
+        )
+        .await
+        .expect("expected success");
+
+    assert_eq!(
+        result.result().block.transactions(),
+        transactions.as_slice()
+    );
+}
+
+#[tokio::test]
+async fn dry_run_fails_if_gas_limit_exceeds_max_dry_run_gas() {
+    let mut ctx = TestContext::default();
+    ctx.config.max_dry_run_gas = Some(1_000);
+    let producer = ctx.producer();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_001)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let err = producer
+        .dry_run(tx, None, None)
+        .await
+        .expect_err("expected failure");
+
+    assert!(
+        matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::DryRunGasLimitExceeded {
+                gas_limit: 1_001,
+                max_dry_run_gas: 1_000,
+            })
+        ),
+        "unexpected err {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn pending_coinbase_does_not_wait_for_production_lock() {
+    let ctx = TestContext::default();
+    let producer = ctx.producer();
+    let _production_guard = producer.lock.lock().await;
+
+    // The mock executor doesn't add the mint transaction, so the preview fails,
+    // but it fails without waiting for the production lock.
+    let result =
+        tokio::time::timeout(Duration::from_secs(1), producer.pending_coinbase(1_000))
+            .await;
+
+    assert!(
+        result.is_ok(),
+        "The preview should not wait for the production lock"
+    );
+}
+
 #[tokio::test]
 async fn can_produce_next_block() {
     // simple happy path for producing atop pre-existing block
@@ -1,8 +1,165 @@
-use fuel_core_types::fuel_types::ContractId;
+use fuel_core_types::{
+    fuel_tx::TxParameters,
+    fuel_types::{
+        AssetId,
+        ContractId,
+        Word,
+    },
+};
 
-#[derive(Clone, Debug, Default)]
+pub use fuel_core_executor::CoinbaseOverflowPolicy;
+
+#[derive(Clone, Debug)]
 pub struct Config {
     pub utxo_validation: bool,
     pub coinbase_recipient: Option<ContractId>,
+    /// The assets in which the fees are credited to the `coinbase_recipient`.
+    /// The fees paid in other assets are burned. If not set, all fees are credited.
+    pub coinbase_asset_allowlist: Option<Vec<AssetId>>,
+    /// Fail the block production if the `coinbase_recipient` is not a deployed contract.
+    /// Otherwise, the fee is not minted and a warning is logged.
+    pub fail_on_missing_coinbase_recipient: bool,
+    /// Fail the block production if the mint transaction fails to credit the coinbase
+    /// to the `coinbase_recipient`. Otherwise, the fee of the block is lost. The missing
+    /// recipient is handled only by the `fail_on_missing_coinbase_recipient`.
+    pub require_coinbase_success: bool,
+    /// What to do if crediting the fee of the block would overflow the balance
+    /// of the `coinbase_recipient`.
+    pub coinbase_overflow_policy: CoinbaseOverflowPolicy,
     pub metrics: bool,
+    /// The maximum gas that the `dry_run` of a single transaction may consume.
+    /// If not set, the dry run is limited only by the gas limit of the transaction.
+    pub max_dry_run_gas: Option<Word>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            utxo_validation: false,
+            coinbase_recipient: None,
+            coinbase_asset_allowlist: None,
+            fail_on_missing_coinbase_recipient: false,
+            require_coinbase_success: false,
+            coinbase_overflow_policy: Default::default(),
+            metrics: false,
+            // The block gas limit of the default chain configuration.
+            max_dry_run_gas: Some(
+                TxParameters::DEFAULT.max_gas_per_tx.saturating_mul(10),
+            ),
+        }
+    }
+}
+
+impl Config {
+    /// Returns the recipient of the fees paid in the `asset_id`.
+    /// `None` means that the fees are burned.
+    pub fn coinbase_recipient_for(&self, asset_id: &AssetId) -> Option<ContractId> {
+        let allowed = self
+            .coinbase_asset_allowlist
+            .as_ref()
+            .map_or(true, |allowlist| allowlist.contains(asset_id));
+        if allowed {
+            self.coinbase_recipient
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the variable output receiving the fees in the `asset_id`
+    /// in the transaction withdrawing them from the `coinbase_recipient`.
+    ///
+    /// The coinbase mints only the `base_asset_id`, so the withdrawal has a single
+    /// variable output at index `1`, right after the output of the `coinbase_recipient`.
+    /// Returns `None` for other assets, or if the fees in the base asset are not credited.
+    pub fn variable_output_index_for(
+        &self,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> Option<Word> {
+        if asset_id != base_asset_id {
+            return None
+        }
+        self.coinbase_recipient_for(asset_id)?;
+        Some(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fees_in_not_allowlisted_assets_are_burned() {
+        let recipient = ContractId::from([1; 32]);
+        let allowed = AssetId::from([2; 32]);
+        let config = Config {
+            coinbase_recipient: Some(recipient),
+            coinbase_asset_allowlist: Some(vec![allowed]),
+            ..Default::default()
+        };
+
+        assert_eq!(config.coinbase_recipient_for(&allowed), Some(recipient));
+        assert_eq!(config.coinbase_recipient_for(&AssetId::BASE), None);
+    }
+
+    #[test]
+    fn fees_in_all_assets_are_credited_without_allowlist() {
+        let recipient = ContractId::from([1; 32]);
+        let config = Config {
+            coinbase_recipient: Some(recipient),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.coinbase_recipient_for(&AssetId::BASE),
+            Some(recipient)
+        );
+    }
+
+    #[test]
+    fn variable_output_is_only_for_the_credited_base_asset() {
+        let other = AssetId::from([2; 32]);
+        let config = Config {
+            coinbase_recipient: Some(ContractId::from([1; 32])),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.variable_output_index_for(&AssetId::BASE, &AssetId::BASE),
+            Some(1)
+        );
+        assert_eq!(
+            config.variable_output_index_for(&other, &AssetId::BASE),
+            None
+        );
+    }
+
+    #[test]
+    fn no_variable_output_if_base_asset_is_not_allowlisted() {
+        let allowed = AssetId::from([2; 32]);
+        let config = Config {
+            coinbase_recipient: Some(ContractId::from([1; 32])),
+            coinbase_asset_allowlist: Some(vec![allowed]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.variable_output_index_for(&AssetId::BASE, &AssetId::BASE),
+            None
+        );
+        assert_eq!(
+            config.variable_output_index_for(&allowed, &AssetId::BASE),
+            None
+        );
+    }
+
+    #[test]
+    fn no_variable_output_without_coinbase_recipient() {
+        let config = Config::default();
+
+        assert_eq!(
+            config.variable_output_index_for(&AssetId::BASE, &AssetId::BASE),
+            None
+        );
+    }
 }
@@ -9,7 +9,10 @@ pub mod config;
 pub mod ports;
 
 pub use block_producer::Producer;
-pub use config::Config;
+pub use config::{
+    CoinbaseOverflowPolicy,
+    Config,
+};
 
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod mocks;
@@ -154,6 +154,33 @@ impl Executor for MockExecutor {
                 block,
                 skipped_transactions: vec![],
                 tx_status: vec![],
+                contract_balance_changes: vec![],
+            },
+            StorageTransaction::new(self.0.clone()),
+        ))
+    }
+
+    fn execute_transactions_without_commit(
+        &self,
+        component: Components<Vec<fuel_tx::Transaction>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        let block = Block::new(
+            component.header_to_produce,
+            component.transactions_source,
+            &[],
+        );
+        // simulate executor inserting a block
+        let mut block_db = self.0.blocks.lock().unwrap();
+        block_db.insert(
+            *block.header().height(),
+            block.compress(&ChainId::default()),
+        );
+        Ok(UncommittedResult::new(
+            ExecutionResult {
+                block,
+                skipped_transactions: vec![],
+                tx_status: vec![],
+                contract_balance_changes: vec![],
             },
             StorageTransaction::new(self.0.clone()),
         ))
@@ -190,6 +217,33 @@ impl Executor for FailingMockExecutor {
                     block,
                     skipped_transactions: vec![],
                     tx_status: vec![],
+                    contract_balance_changes: vec![],
+                },
+                StorageTransaction::new(MockDb::default()),
+            ))
+        }
+    }
+
+    fn execute_transactions_without_commit(
+        &self,
+        component: Components<Vec<fuel_tx::Transaction>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        // simulate an execution failure
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            let block = Block::new(
+                component.header_to_produce,
+                component.transactions_source,
+                &[],
+            );
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: vec![],
+                    tx_status: vec![],
+                    contract_balance_changes: vec![],
                 },
                 StorageTransaction::new(MockDb::default()),
             ))
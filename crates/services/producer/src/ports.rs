@@ -71,6 +71,14 @@ pub trait Executor: Send + Sync {
         component: Components<Self::TxSource>,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>>;
 
+    /// Executes the block with the `transactions_source` in the specified order instead of
+    /// the transactions from the `TxPool`, and returns the result of execution with
+    /// uncommitted database transaction.
+    fn execute_transactions_without_commit(
+        &self,
+        component: Components<Vec<Transaction>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>>;
+
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `utxo_validation` field can be used to disable the validation
     /// of utxos during execution.
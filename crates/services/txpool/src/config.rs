@@ -19,6 +19,17 @@ pub struct Config {
     pub transaction_ttl: Duration,
     /// The number of allowed active transaction status subscriptions.
     pub number_of_active_subscription: usize,
+    /// Evict the transactions spending the inputs that were spent by the committed block.
+    pub evict_spent_inputs: bool,
+    /// The maximum number of distinct assets referenced by the inputs and outputs
+    /// of a single transaction.
+    pub max_assets_per_transaction: usize,
+    /// The minimum fee that the transaction must pay to the coinbase recipient
+    /// to be accepted. The fee is estimated as the max fee of the transaction.
+    pub min_coinbase_fee: u64,
+    /// Accept the transactions with zero gas price regardless of the `min_gas_price`
+    /// and the `min_coinbase_fee`. It is intended for the test and private networks.
+    pub allow_zero_gas_price: bool,
 }
 
 impl Default for Config {
@@ -31,6 +42,10 @@ impl Default for Config {
         // 5 minute TTL
         let transaction_ttl = Duration::from_secs(60 * 5);
         let number_of_active_subscription = max_tx;
+        let evict_spent_inputs = true;
+        let max_assets_per_transaction = 255;
+        let min_coinbase_fee = 0;
+        let allow_zero_gas_price = false;
         Self::new(
             max_tx,
             max_depth,
@@ -40,6 +55,10 @@ impl Default for Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            evict_spent_inputs,
+            max_assets_per_transaction,
+            min_coinbase_fee,
+            allow_zero_gas_price,
         )
     }
 }
@@ -55,6 +74,10 @@ impl Config {
         metrics: bool,
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
+        evict_spent_inputs: bool,
+        max_assets_per_transaction: usize,
+        min_coinbase_fee: u64,
+        allow_zero_gas_price: bool,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -67,6 +90,10 @@ impl Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            evict_spent_inputs,
+            max_assets_per_transaction,
+            min_coinbase_fee,
+            allow_zero_gas_price,
         }
     }
 }
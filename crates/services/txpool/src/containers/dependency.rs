@@ -102,6 +102,29 @@ impl Dependency {
         }
     }
 
+    /// Returns the id of the transaction from the `TxPool` that spends
+    /// the coin or the message of the `input`.
+    pub(crate) fn spent_by(&self, input: &Input) -> Option<TxId> {
+        match input {
+            Input::CoinSigned(CoinSigned { utxo_id, .. })
+            | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                self.coin_spent_by(utxo_id)
+            }
+            Input::MessageCoinSigned(MessageCoinSigned { nonce, .. })
+            | Input::MessageCoinPredicate(MessageCoinPredicate { nonce, .. })
+            | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
+            | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => {
+                self.messages.get(nonce).map(|state| state.spent_by)
+            }
+            Input::Contract(_) => None,
+        }
+    }
+
+    /// Returns the id of the transaction from the `TxPool` that spends the coin.
+    pub(crate) fn coin_spent_by(&self, utxo_id: &UtxoId) -> Option<TxId> {
+        self.coins.get(utxo_id).and_then(|state| state.is_spend_by)
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -6,6 +6,7 @@
 use fuel_core_types::{
     services::txpool::{
         ArcPoolTx,
+        TransactionSource,
         TransactionStatus,
     },
     tai64::Tai64,
@@ -48,11 +49,12 @@ pub struct TxInfo {
     tx: ArcPoolTx,
     submitted_time: Duration,
     creation_instant: tokio::time::Instant,
+    source: TransactionSource,
 }
 
 #[allow(missing_docs)]
 impl TxInfo {
-    pub fn new(tx: ArcPoolTx) -> Self {
+    pub fn new(tx: ArcPoolTx, source: TransactionSource) -> Self {
         let since_epoch = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .expect("Now is bellow of the `UNIX_EPOCH`");
@@ -61,6 +63,7 @@ impl TxInfo {
             tx,
             submitted_time: since_epoch,
             creation_instant: tokio::time::Instant::now(),
+            source,
         }
     }
 
@@ -75,6 +78,10 @@ impl TxInfo {
     pub fn created(&self) -> tokio::time::Instant {
         self.creation_instant
     }
+
+    pub fn source(&self) -> &TransactionSource {
+        &self.source
+    }
 }
 
 impl Deref for TxInfo {
@@ -100,6 +100,6 @@ impl TxPoolDb for MockDb {
         &self,
         _tx_id: &fuel_core_types::fuel_types::Bytes32,
     ) -> StorageResult<fuel_core_types::services::txpool::TransactionStatus> {
-        unimplemented!()
+        Err(fuel_core_storage::not_found!("TransactionStatus"))
     }
 }
@@ -47,6 +47,10 @@ pub trait PeerToPeer: Send + Sync {
 pub trait BlockImporter: Send + Sync {
     /// Wait until the next block is available
     fn block_events(&self) -> BoxStream<Arc<ImportResult>>;
+
+    /// Returns `true` if the importer doesn't commit the blocks anymore
+    /// because of the repeated write failures.
+    fn is_read_only(&self) -> bool;
 }
 
 pub trait TxPoolDb: Send + Sync {
@@ -28,6 +28,7 @@ use fuel_core_types::{
         Transaction,
         TxId,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::{
         BlockHeight,
@@ -45,6 +46,8 @@ use fuel_core_types::{
             ArcPoolTx,
             Error,
             InsertionResult,
+            SqueezedOutKind,
+            TransactionSource,
             TransactionStatus,
         },
     },
@@ -99,6 +102,11 @@ impl TxStatusChange {
         self.update_sender.send(TxUpdate::new(id, message.into()));
     }
 
+    #[cfg(test)]
+    pub(crate) fn subscribe(&self, id: Bytes32) -> Option<TxStatusStream> {
+        self.update_sender.try_subscribe::<MpscChannel>(id)
+    }
+
     pub fn send_submitted(&self, id: Bytes32, time: Tai64) {
         tracing::info!("Transaction {id} successfully submitted to the tx pool");
         let _ = self.new_tx_notification_sender.send(id);
@@ -108,11 +116,20 @@ impl TxStatusChange {
         ));
     }
 
+    pub fn send_expired(&self, id: Bytes32, expire_at: BlockHeight) {
+        tracing::info!("Transaction {id} expired at the height {expire_at}");
+        self.update_sender.send(TxUpdate::new(
+            id,
+            TxStatusMessage::Status(TransactionStatus::Expired { expire_at }),
+        ));
+    }
+
     pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
         tracing::info!("Transaction {id} squeezed out because {reason}");
         self.update_sender.send(TxUpdate::new(
             id,
             TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+                kind: SqueezedOutKind::from(&reason),
                 reason: reason.to_string(),
             }),
         ));
@@ -144,6 +161,7 @@ impl<P2P, DB: Clone> Clone for SharedState<P2P, DB> {
 pub struct Task<P2P, DB> {
     gossiped_tx_stream: BoxStream<TransactionGossipData>,
     committed_block_stream: BoxStream<Arc<ImportResult>>,
+    importer: Box<dyn BlockImporter>,
     shared: SharedState<P2P, DB>,
     ttl_timer: tokio::time::Interval,
 }
@@ -201,14 +219,9 @@ where
 
             result = self.committed_block_stream.next() => {
                 if let Some(result) = result {
-                    let block = result
-                        .sealed_block
-                        .entity
-                        .compress(&self.shared.consensus_params.chain_id);
                     self.shared.txpool.lock().block_update(
                         &self.shared.tx_status_sender,
-                        block.header().height(),
-                        block.transactions()
+                        &result.sealed_block.entity,
                     );
                     should_continue = true;
                 } else {
@@ -225,15 +238,21 @@ where
                     let checked_tx = check_single_tx(tx, current_height, &self.shared.config).await;
 
                     let acceptance = match checked_tx {
+                        // The node in the read-only mode can't include the transaction
+                        // into a block, so it is not inserted, but the peer isn't penalized.
+                        Ok(_) if self.importer.is_read_only() => {
+                            GossipsubMessageAcceptance::Ignore
+                        }
                         Ok(tx) => {
                             let txs = vec![tx];
 
                             // insert tx
                             let mut result = tracing::info_span!("Received tx via gossip", %id)
                                 .in_scope(|| {
-                                    self.shared.txpool.lock().insert(
+                                    self.shared.txpool.lock().insert_gossiped(
                                         &self.shared.tx_status_sender,
-                                        txs
+                                        txs,
+                                        peer_id.clone(),
                                     )
                                 });
 
@@ -307,10 +326,25 @@ where
         self.txpool.lock().find_one(&id)
     }
 
+    pub fn find_source(&self, id: TxId) -> Option<TransactionSource> {
+        self.txpool
+            .lock()
+            .find_one(&id)
+            .map(|info| info.source().clone())
+    }
+
     pub fn find_dependent(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().find_dependent(&ids)
     }
 
+    pub fn find_spenders(&self, utxo_id: UtxoId) -> Vec<ArcPoolTx> {
+        self.txpool.lock().find_spenders(&utxo_id)
+    }
+
+    pub fn stop_accepting_transactions(&self) {
+        self.txpool.lock().stop_accepting_transactions()
+    }
+
     pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
         let mut guard = self.txpool.lock();
         let txs = guard.includable();
@@ -326,6 +360,12 @@ where
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
 
+    /// Drops the pending transaction with `id` if it is not included in a block by
+    /// the `expire_at` height. Returns `false` if the transaction is not in the pool.
+    pub fn set_expiration(&self, id: TxId, expire_at: BlockHeight) -> bool {
+        self.txpool.lock().set_expiration(&id, expire_at)
+    }
+
     pub fn new_tx_notification_subscribe(&self) -> broadcast::Receiver<TxId> {
         self.tx_status_sender.new_tx_notification_sender.subscribe()
     }
@@ -438,7 +478,7 @@ pub fn new_service<P2P, Importer, DB>(
     p2p: P2P,
 ) -> Service<P2P, DB>
 where
-    Importer: BlockImporter,
+    Importer: BlockImporter + 'static,
     P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
     DB: TxPoolDb + Clone + 'static,
 {
@@ -453,6 +493,7 @@ where
     let task = Task {
         gossiped_tx_stream,
         committed_block_stream,
+        importer: Box::new(importer),
         shared: SharedState {
             tx_status_sender: TxStatusChange::new(
                 number_of_active_subscription,
@@ -104,6 +104,8 @@ mockall::mock! {
 
     impl BlockImporter for Importer {
         fn block_events(&self) -> BoxStream<Arc<ImportResult>>;
+
+        fn is_read_only(&self) -> bool;
     }
 }
 
@@ -125,6 +127,16 @@ impl MockImporter {
             });
             Box::pin(stream)
         });
+        importer.expect_is_read_only().returning(|| false);
+        importer
+    }
+
+    pub fn read_only() -> Self {
+        let mut importer = MockImporter::default();
+        importer
+            .expect_block_events()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
+        importer.expect_is_read_only().returning(|| true);
         importer
     }
 }
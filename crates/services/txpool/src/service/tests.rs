@@ -251,7 +251,8 @@ async fn simple_insert_removal_subscription() {
     assert_eq!(
         update,
         TxStatusMessage::Status(TransactionStatus::SqueezedOut {
-            reason: "Transaction removed.".to_string()
+            reason: "Transaction removed.".to_string(),
+            kind: SqueezedOutKind::Removed,
         }),
         "Second message in tx1 stream should be squeezed out"
     );
@@ -260,7 +261,8 @@ async fn simple_insert_removal_subscription() {
     assert_eq!(
         update,
         TxStatusMessage::Status(TransactionStatus::SqueezedOut {
-            reason: "Transaction removed.".to_string()
+            reason: "Transaction removed.".to_string(),
+            kind: SqueezedOutKind::Removed,
         }),
         "Second message in tx2 stream should be squeezed out"
     );
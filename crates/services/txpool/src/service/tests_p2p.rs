@@ -1,5 +1,6 @@
 use super::*;
 use crate::service::test_helpers::{
+    MockImporter,
     MockP2P,
     TestContextBuilder,
 };
@@ -43,6 +44,32 @@ async fn can_insert_from_p2p() {
     assert_eq!(tx1, got_tx);
 }
 
+#[tokio::test]
+async fn does_not_insert_from_p2p_in_read_only_mode() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+
+    let mut p2p = MockP2P::new_with_txs(vec![tx1.clone()]);
+    // The ignored transactions are not reported to the peers.
+    p2p.expect_notify_gossip_transaction_validity().times(0);
+    ctx_builder.with_p2p(p2p);
+    ctx_builder.with_importer(MockImporter::read_only());
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let mut receiver = service
+        .shared
+        .tx_update_subscribe(tx1.id(&Default::default()))
+        .unwrap();
+
+    service.start_and_await().await.unwrap();
+
+    let res = tokio::time::timeout(Duration::from_millis(100), receiver.next()).await;
+    assert!(res.is_err(), "{res:?}");
+    let out = service.shared.find(vec![tx1.id(&Default::default())]);
+    assert!(out[0].is_none());
+}
+
 #[tokio::test]
 async fn insert_from_local_broadcasts_to_p2p() {
     // setup initial state
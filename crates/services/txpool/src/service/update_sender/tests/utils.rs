@@ -16,6 +16,7 @@ pub fn transaction_status_strategy() -> impl Strategy<Value = TransactionStatus>
         }),
         Just(TransactionStatus::SqueezedOut {
             reason: Default::default(),
+            kind: SqueezedOutKind::Removed,
         }),
     ]
 }
@@ -12,9 +12,18 @@ use crate::{
     TxInfo,
 };
 use fuel_core_types::{
+    blockchain::block::Block,
     fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
         Chargeable,
+        Input,
         Transaction,
+        TransactionFee,
+        UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::BlockHeight,
     fuel_vm::{
@@ -28,9 +37,13 @@ use fuel_core_types::{
         },
         PredicateVerificationFailed,
     },
-    services::txpool::{
-        ArcPoolTx,
-        InsertionResult,
+    services::{
+        p2p::PeerId,
+        txpool::{
+            ArcPoolTx,
+            InsertionResult,
+            TransactionSource,
+        },
     },
     tai64::Tai64,
 };
@@ -39,7 +52,10 @@ use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_types::fuel_vm::checked_transaction::CheckPredicateParams;
 use std::{
     cmp::Reverse,
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     ops::Deref,
     sync::Arc,
 };
@@ -51,8 +67,10 @@ pub struct TxPool<DB> {
     by_gas_price: PriceSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    expirations: HashMap<TxId, BlockHeight>,
     config: Config,
     database: DB,
+    accepts_transactions: bool,
 }
 
 impl<DB> TxPool<DB>
@@ -67,11 +85,19 @@ where
             by_gas_price: PriceSort::default(),
             by_time: TimeSort::default(),
             by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            expirations: HashMap::new(),
             config,
             database,
+            accepts_transactions: true,
         }
     }
 
+    /// Rejects all new transactions from now on. The pending transactions stay
+    /// in the pool until they are committed or pruned.
+    pub fn stop_accepting_transactions(&mut self) {
+        self.accepts_transactions = false;
+    }
+
     #[cfg(test)]
     pub fn config(&self) -> &Config {
         &self.config
@@ -85,12 +111,25 @@ where
         &self.by_dependency
     }
 
+    #[cfg(test)]
+    fn insert_inner(
+        &mut self,
+        tx: Checked<Transaction>,
+    ) -> anyhow::Result<InsertionResult> {
+        self.insert_inner_with_source(tx, TransactionSource::Local)
+    }
+
     #[tracing::instrument(level = "info", skip_all, fields(tx_id = %tx.id()), ret, err)]
     // this is atomic operation. Return removed(pushed out/replaced) transactions
-    fn insert_inner(
+    fn insert_inner_with_source(
         &mut self,
         tx: Checked<Transaction>,
+        source: TransactionSource,
     ) -> anyhow::Result<InsertionResult> {
+        if !self.accepts_transactions {
+            return Err(Error::NotInsertedShuttingDown.into())
+        }
+
         let tx: CheckedTransaction = tx.into();
 
         let tx = Arc::new(match tx {
@@ -141,13 +180,14 @@ where
         let rem = self
             .by_dependency
             .insert(&self.by_hash, &self.database, &tx)?;
-        let info = TxInfo::new(tx.clone());
+        let info = TxInfo::new(tx.clone(), source);
         let submitted_time = info.submitted_time();
         self.by_gas_price.insert(&info);
         self.by_time.insert(&info);
         self.by_hash.insert(tx.id(), info);
 
         // if some transaction were removed so we don't need to check limit
+        let evicted = rem.is_empty() && max_limit_hit;
         let removed = if rem.is_empty() {
             if max_limit_hit {
                 // remove last tx from sort
@@ -170,6 +210,7 @@ where
             inserted: tx,
             submitted_time,
             removed,
+            evicted,
         })
     }
 
@@ -206,6 +247,7 @@ where
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_gas_price.remove(info);
+            self.expirations.remove(tx_id);
         }
 
         info
@@ -221,18 +263,38 @@ where
     }
 
     #[tracing::instrument(level = "info", skip_all)]
-    /// Import a set of transactions from network gossip or GraphQL endpoints.
+    /// Import a set of transactions submitted locally via GraphQL endpoints.
     pub fn insert(
         &mut self,
         tx_status_sender: &TxStatusChange,
         txs: Vec<Checked<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_from(tx_status_sender, txs, TransactionSource::Local)
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    /// Import a set of transactions gossiped by the `peer_id`.
+    pub fn insert_gossiped(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<Checked<Transaction>>,
+        peer_id: PeerId,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_from(tx_status_sender, txs, TransactionSource::Peer(peer_id))
+    }
+
+    fn insert_from(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<Checked<Transaction>>,
+        source: TransactionSource,
     ) -> Vec<anyhow::Result<InsertionResult>> {
         // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
         // should be done before transaction comes to txpool, or before it enters RwLocked region.
         let mut res = Vec::new();
 
         for tx in txs.into_iter() {
-            res.push(self.insert_inner(tx));
+            res.push(self.insert_inner_with_source(tx, source.clone()));
         }
 
         // announce to subscribers
@@ -242,11 +304,17 @@ where
                     removed,
                     inserted,
                     submitted_time,
+                    evicted,
                 }) => {
                     for removed in removed {
                         // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
                         // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                        let reason = if *evicted {
+                            Error::EvictedByHigherGasPrice
+                        } else {
+                            Error::Removed
+                        };
+                        tx_status_sender.send_squeezed_out(removed.id(), reason);
                     }
                     tx_status_sender.send_submitted(
                         inserted.id(),
@@ -295,6 +363,16 @@ where
         list
     }
 
+    /// Returns the pending transactions that use the coin with `utxo_id` as an input.
+    pub fn find_spenders(&self, utxo_id: &UtxoId) -> Vec<ArcPoolTx> {
+        self.by_dependency
+            .coin_spent_by(utxo_id)
+            .and_then(|tx_id| self.txs().get(&tx_id))
+            .map(|info| info.tx().clone())
+            .into_iter()
+            .collect()
+    }
+
     /// The number of pending transaction in the pool.
     pub fn pending_number(&self) -> usize {
         self.by_hash.len()
@@ -312,19 +390,53 @@ where
     }
 
     /// When block is updated we need to receive all spend outputs and remove them from txpool.
-    pub fn block_update(
-        &mut self,
-        tx_status_sender: &TxStatusChange,
-        height: &BlockHeight,
-        transactions: &[TxId],
-        // spend_outputs: [Input], added_outputs: [AddedOutputs]
-    ) {
-        for tx_id in transactions {
+    pub fn block_update(&mut self, tx_status_sender: &TxStatusChange, block: &Block) {
+        let chain_id = self.config.chain_config.consensus_parameters.chain_id;
+        let height = block.header().height();
+        let committed: Vec<_> = block
+            .transactions()
+            .iter()
+            .map(|tx| (tx.id(&chain_id), tx))
+            .collect();
+
+        for (tx_id, _) in committed.iter() {
             let tx_id = *tx_id;
             let result = self.database.transaction_status(&tx_id);
             tx_status_sender.send_complete(tx_id, height, result);
             self.remove_committed_tx(&tx_id);
         }
+        self.prune_expired_txs(tx_status_sender, height);
+
+        if self.config.evict_spent_inputs {
+            for (tx_id, tx) in committed {
+                let inputs = match tx {
+                    Transaction::Script(script) => script.inputs(),
+                    Transaction::Create(create) => create.inputs(),
+                    Transaction::Mint(_) => continue,
+                };
+                self.evict_spent_inputs(tx_status_sender, tx_id, inputs);
+            }
+        }
+    }
+
+    /// Removes the transactions that spend the `inputs` of the committed transaction
+    /// with all their dependents, because they can't be included anymore.
+    fn evict_spent_inputs(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        committed_tx_id: TxId,
+        inputs: &[Input],
+    ) {
+        for input in inputs {
+            if let Some(spender) = self.by_dependency.spent_by(input) {
+                for removed in self.remove_by_tx_id(&spender) {
+                    tx_status_sender.send_squeezed_out(
+                        removed.id(),
+                        Error::DependencyInvalidated(committed_tx_id),
+                    );
+                }
+            }
+        }
     }
 
     /// remove transaction from pool needed on user demand. Low priority
@@ -347,6 +459,41 @@ where
         removed
     }
 
+    /// Sets the last height at which the pending transaction can be included.
+    /// Returns `false` if the transaction is not in the pool.
+    pub fn set_expiration(&mut self, tx_id: &TxId, expire_at: BlockHeight) -> bool {
+        if !self.by_hash.contains_key(tx_id) {
+            return false
+        }
+        self.expirations.insert(*tx_id, expire_at);
+        true
+    }
+
+    /// Removes the transactions that weren't included by their expiry height
+    /// with all their dependents.
+    fn prune_expired_txs(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        height: &BlockHeight,
+    ) {
+        let expired: Vec<_> = self
+            .expirations
+            .iter()
+            .filter(|(_, expire_at)| *expire_at <= height)
+            .map(|(tx_id, expire_at)| (*tx_id, *expire_at))
+            .collect();
+
+        for (tx_id, expire_at) in expired {
+            for removed in self.remove_by_tx_id(&tx_id) {
+                if removed.id() == tx_id {
+                    tx_status_sender.send_expired(tx_id, expire_at);
+                } else {
+                    tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                }
+            }
+        }
+    }
+
     /// Remove all old transactions from the pool.
     pub fn prune_old_txs(&mut self) -> Vec<ArcPoolTx> {
         let Some(deadline) =
@@ -396,7 +543,15 @@ pub async fn check_single_tx(
         return Err(Error::NotSupportedTransactionType.into())
     }
 
-    verify_tx_min_gas_price(&tx, config)?;
+    let free_tx_allowed = config.allow_zero_gas_price && tx_gas_price(&tx)? == 0;
+    if !free_tx_allowed {
+        verify_tx_min_gas_price(&tx, config)?;
+    }
+    verify_tx_predicates_allowed(&tx, config)?;
+    verify_tx_max_assets(&tx, config)?;
+    if !free_tx_allowed {
+        verify_tx_min_coinbase_fee(&tx, config)?;
+    }
 
     let tx: Checked<Transaction> = if config.utxo_validation {
         let consensus_params = &config.chain_config.consensus_parameters;
@@ -425,12 +580,16 @@ pub async fn check_single_tx(
     Ok(tx)
 }
 
+fn tx_gas_price(tx: &Transaction) -> Result<Word, Error> {
+    match tx {
+        Transaction::Script(script) => Ok(script.price()),
+        Transaction::Create(create) => Ok(create.price()),
+        Transaction::Mint(_) => Err(Error::NotSupportedTransactionType),
+    }
+}
+
 fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Error> {
-    let price = match tx {
-        Transaction::Script(script) => script.price(),
-        Transaction::Create(create) => create.price(),
-        Transaction::Mint(_) => return Err(Error::NotSupportedTransactionType),
-    };
+    let price = tx_gas_price(tx)?;
     if config.metrics {
         // Gas Price metrics are recorded here to avoid double matching for
         // every single transaction, but also means metrics aren't collected on gas
@@ -443,6 +602,74 @@ fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Erro
     Ok(())
 }
 
+fn verify_tx_predicates_allowed(tx: &Transaction, config: &Config) -> Result<(), Error> {
+    let inputs = match tx {
+        Transaction::Script(script) => script.inputs(),
+        Transaction::Create(create) => create.inputs(),
+        Transaction::Mint(_) => return Err(Error::NotSupportedTransactionType),
+    };
+    for predicate in inputs.iter().filter_map(|input| input.predicate()) {
+        if !config.chain_config.is_predicate_allowed(predicate) {
+            let root = Input::predicate_owner(predicate);
+            return Err(Error::NotInsertedPredicateNotAllowed((*root).into()))
+        }
+    }
+    Ok(())
+}
+
+fn verify_tx_max_assets(tx: &Transaction, config: &Config) -> Result<(), Error> {
+    let (inputs, outputs) = match tx {
+        Transaction::Script(script) => (script.inputs(), script.outputs()),
+        Transaction::Create(create) => (create.inputs(), create.outputs()),
+        Transaction::Mint(_) => return Err(Error::NotSupportedTransactionType),
+    };
+    let base_asset_id = config.chain_config.consensus_parameters.base_asset_id();
+    let assets: HashSet<_> = inputs
+        .iter()
+        .filter_map(|input| input.asset_id(base_asset_id))
+        .chain(outputs.iter().filter_map(|output| output.asset_id()))
+        .collect();
+    let limit = config.max_assets_per_transaction;
+    if assets.len() > limit {
+        return Err(Error::NotInsertedTooManyAssets {
+            assets: assets.len(),
+            limit,
+        })
+    }
+    Ok(())
+}
+
+fn verify_tx_min_coinbase_fee(tx: &Transaction, config: &Config) -> Result<(), Error> {
+    if config.min_coinbase_fee == 0 {
+        return Ok(())
+    }
+
+    let params = &config.chain_config.consensus_parameters;
+    let gas_costs = params.gas_costs();
+    let fee_params = params.fee_params();
+    let fee = match tx {
+        Transaction::Script(script) => {
+            TransactionFee::checked_from_tx(gas_costs, fee_params, script)
+        }
+        Transaction::Create(create) => {
+            TransactionFee::checked_from_tx(gas_costs, fee_params, create)
+        }
+        Transaction::Mint(_) => return Err(Error::NotSupportedTransactionType),
+    };
+    // The overflow of the fee is reported later by the check of the transaction.
+    let fee = match fee {
+        Some(fee) => fee.max_fee(),
+        None => return Ok(()),
+    };
+    if fee < config.min_coinbase_fee {
+        return Err(Error::NotInsertedInsufficientCoinbaseFee {
+            fee,
+            min_fee: config.min_coinbase_fee,
+        })
+    }
+    Ok(())
+}
+
 pub struct TokioWithRayon;
 
 #[async_trait::async_trait]
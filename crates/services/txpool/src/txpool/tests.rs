@@ -1,5 +1,9 @@
 use crate::{
     ports::TxPoolDb,
+    service::{
+        TxStatusChange,
+        TxStatusMessage,
+    },
     test_helpers::{
         add_coin_to_state,
         create_output_and_input,
@@ -21,6 +25,10 @@ use crate::{
     TxPool,
 };
 use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        header::PartialBlockHeader,
+    },
     fuel_asm::{
         op,
         RegId,
@@ -28,6 +36,7 @@ use fuel_core_types::{
     },
     fuel_crypto::rand::{
         rngs::StdRng,
+        Rng,
         SeedableRng,
     },
     fuel_tx::{
@@ -44,11 +53,21 @@ use fuel_core_types::{
     },
     fuel_types::ChainId,
     fuel_vm::checked_transaction::Checked,
+    services::{
+        p2p::PeerId,
+        txpool::{
+            SqueezedOutKind,
+            TransactionSource,
+            TransactionStatus,
+        },
+    },
 };
+use tokio_stream::StreamExt;
 
 use std::{
     cmp::Reverse,
     collections::HashMap,
+    time::Duration,
     vec,
 };
 
@@ -93,6 +112,76 @@ async fn insert_simple_tx_succeeds() {
         .expect("Transaction should be OK, got Err");
 }
 
+#[tokio::test]
+async fn insert_tx_fails_after_stop_accepting_transactions() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+
+    txpool.stop_accepting_transactions();
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("Transaction should be rejected, got Ok");
+
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedShuttingDown)
+    ));
+}
+
+#[tokio::test]
+async fn insert_gossiped_tx_records_source_peer() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx_status_sender = TxStatusChange::new(10, Duration::from_secs(10));
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let gossiped_tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let local_tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let gossiped_id = gossiped_tx.id(&ChainId::default());
+    let local_id = local_tx.id(&ChainId::default());
+
+    let gossiped_tx = check_unwrap_tx(gossiped_tx, db.clone(), &txpool.config).await;
+    let local_tx = check_unwrap_tx(local_tx, db.clone(), &txpool.config).await;
+    let peer_id = PeerId::from(vec![1, 2, 3]);
+
+    txpool
+        .insert_gossiped(&tx_status_sender, vec![gossiped_tx], peer_id.clone())
+        .pop()
+        .unwrap()
+        .expect("Gossiped transaction should be OK, got Err");
+    txpool
+        .insert(&tx_status_sender, vec![local_tx])
+        .pop()
+        .unwrap()
+        .expect("Local transaction should be OK, got Err");
+
+    assert_eq!(
+        txpool.find_one(&gossiped_id).unwrap().source(),
+        &TransactionSource::Peer(peer_id)
+    );
+    assert_eq!(
+        txpool.find_one(&local_id).unwrap().source(),
+        &TransactionSource::Local
+    );
+}
+
 #[tokio::test]
 async fn insert_simple_tx_dependency_chain_succeeds() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -306,6 +395,136 @@ async fn higher_priced_tx_removes_lower_priced_tx() {
 
     let vec = txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
     assert_eq!(vec.removed[0].id(), tx1_id, "Tx1 id should be removed");
+    assert!(!vec.evicted, "Tx1 should be replaced, not evicted");
+}
+
+#[tokio::test]
+async fn block_update_evicts_txs_with_inputs_spent_by_committed_tx() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx_status_sender = TxStatusChange::new(10, Duration::from_secs(10));
+
+    let (_, coin_input) = setup_coin(&mut rng, Some(&txpool.database));
+    let (output, unset_input) = create_output_and_input(&mut rng, 1);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin_input.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    // The transaction is included in the block bypassing the `TxPool`.
+    let committed_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin_input)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let committed_tx_id = committed_tx.id(&ChainId::default());
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx1).expect("Tx1 should be OK, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be OK, got Err");
+    let mut tx1_updates = tx_status_sender.subscribe(tx1_id).unwrap();
+    let mut tx2_updates = tx_status_sender.subscribe(tx2_id).unwrap();
+
+    let block = Block::new(PartialBlockHeader::default(), vec![committed_tx], &[]);
+    txpool.block_update(&tx_status_sender, &block);
+
+    assert!(txpool.find_one(&tx1_id).is_none(), "Tx1 should be evicted");
+    assert!(
+        txpool.find_one(&tx2_id).is_none(),
+        "Dependent Tx2 should be evicted"
+    );
+    assert_eq!(txpool.pending_number(), 0);
+
+    // Both transactions are squeezed out because of the committed transaction.
+    let expected = TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+        reason: Error::DependencyInvalidated(committed_tx_id).to_string(),
+        kind: SqueezedOutKind::Removed,
+    });
+    assert_eq!(tx1_updates.next().await, Some(expected.clone()));
+    assert_eq!(tx2_updates.next().await, Some(expected));
+}
+
+#[tokio::test]
+async fn block_update_keeps_txs_with_spent_inputs_if_eviction_is_disabled() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            evict_spent_inputs: false,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+    let tx_status_sender = TxStatusChange::new(10, Duration::from_secs(10));
+
+    let (_, coin_input) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin_input.clone())
+        .finalize_as_transaction();
+    let committed_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin_input)
+        .finalize_as_transaction();
+
+    let tx_id = tx.id(&ChainId::default());
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx).expect("Tx should be OK, got Err");
+
+    let block = Block::new(PartialBlockHeader::default(), vec![committed_tx], &[]);
+    txpool.block_update(&tx_status_sender, &block);
+
+    assert!(txpool.find_one(&tx_id).is_some());
+}
+
+#[tokio::test]
+async fn block_update_prunes_txs_not_included_by_expiry_height() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx_status_sender = TxStatusChange::new(10, Duration::from_secs(10));
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    assert!(!txpool.set_expiration(&tx_id, 2.into()));
+
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx).expect("Tx should be OK, got Err");
+    assert!(txpool.set_expiration(&tx_id, 2.into()));
+
+    let block_at = |height: u32| {
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height.into();
+        Block::new(header, vec![], &[])
+    };
+    txpool.block_update(&tx_status_sender, &block_at(1));
+    assert!(txpool.find_one(&tx_id).is_some());
+
+    txpool.block_update(&tx_status_sender, &block_at(2));
+    assert!(txpool.find_one(&tx_id).is_none(), "Tx should expire");
+    assert_eq!(txpool.pending_number(), 0);
 }
 
 #[tokio::test]
@@ -572,6 +791,43 @@ async fn tx_limit_hit() {
     ));
 }
 
+#[tokio::test]
+async fn higher_priced_tx_evicts_lowest_priced_tx_if_limit_is_hit() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_tx: 1,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(20)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+
+    let vec = txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
+    assert_eq!(vec.removed.len(), 1);
+    assert_eq!(vec.removed[0].id(), tx1_id, "Tx1 id should be removed");
+    assert!(vec.evicted, "Tx1 should be evicted");
+}
+
 #[tokio::test]
 async fn tx_depth_hit() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -778,6 +1034,74 @@ async fn tx_below_min_gas_price_is_not_insertable() {
     ));
 }
 
+#[tokio::test]
+async fn tx_with_more_assets_than_limit_is_not_insertable() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(Output::coin(rng.gen(), 0, AssetId::new([1; 32])))
+        .add_output(Output::coin(rng.gen(), 0, AssetId::new([2; 32])))
+        .finalize_as_transaction();
+
+    let err = check_tx(
+        tx,
+        db,
+        &Config {
+            max_assets_per_transaction: 2,
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("expected insertion failure");
+
+    assert!(matches!(
+        err.root_cause().downcast_ref::<Error>().unwrap(),
+        Error::NotInsertedTooManyAssets {
+            assets: 3,
+            limit: 2
+        }
+    ));
+}
+
+#[tokio::test]
+async fn tx_rejected_when_coinbase_fee_is_below_minimum() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        min_coinbase_fee: 1_000,
+        ..Default::default()
+    };
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let cheap_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(0)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .finalize_as_transaction();
+    // The gas price equals the gas price factor, so the fee is the max gas.
+    let expensive_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1_000_000_000)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let err = check_tx(cheap_tx, db.clone(), &config)
+        .await
+        .expect_err("expected insertion failure");
+    assert!(matches!(
+        err.root_cause().downcast_ref::<Error>().unwrap(),
+        Error::NotInsertedInsufficientCoinbaseFee { min_fee: 1_000, .. }
+    ));
+    check_tx(expensive_tx, db, &config)
+        .await
+        .expect("the fee covers the minimum");
+}
+
 #[tokio::test]
 async fn tx_inserted_into_pool_when_input_message_id_exists_in_db() {
     let (message, input) = create_message_predicate_from_message(5000, 0);
@@ -1047,6 +1371,52 @@ async fn predicate_without_enough_gas_returns_out_of_gas() {
     )
 }
 
+#[tokio::test]
+async fn predicate_not_in_allow_list_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let allowed_code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    let mut config = Config::default();
+    config.chain_config.allowed_predicate_roots =
+        vec![(*Input::predicate_owner(&allowed_code)).into()];
+
+    let coin = random_predicate(&mut rng, AssetId::BASE, TEST_COIN_AMOUNT, None);
+    let (_, gas_coin) = add_coin_to_state(coin, Some(&db.clone()));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let err = check_tx(tx, db.clone(), &config)
+        .await
+        .expect_err("Transaction should be err, got ok");
+
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedPredicateNotAllowed(_))
+    ));
+}
+
+#[tokio::test]
+async fn predicate_in_allow_list_is_accepted() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    let mut config = Config::default();
+    config.chain_config.allowed_predicate_roots =
+        vec![(*Input::predicate_owner(&code)).into()];
+
+    let coin = custom_predicate(&mut rng, AssetId::BASE, TEST_COIN_AMOUNT, code, None)
+        .into_default_estimated();
+    let (_, gas_coin) = add_coin_to_state(coin, Some(&db.clone()));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    check_unwrap_tx(tx, db.clone(), &config).await;
+}
+
 #[tokio::test]
 async fn predicate_that_returns_false_is_invalid() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -1076,3 +1446,38 @@ async fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+#[tokio::test]
+async fn zero_gas_price_tx_accepted_when_allowed() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        min_gas_price: 10,
+        min_coinbase_fee: 1_000,
+        allow_zero_gas_price: true,
+        ..Default::default()
+    };
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let free_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(0)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .finalize_as_transaction();
+    let cheap_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    check_tx(free_tx, db.clone(), &config)
+        .await
+        .expect("zero gas price is allowed");
+    let err = check_tx(cheap_tx, db, &config)
+        .await
+        .expect_err("expected insertion failure");
+    assert!(matches!(
+        err.root_cause().downcast_ref::<Error>().unwrap(),
+        Error::NotInsertedGasPriceTooLow
+    ));
+}
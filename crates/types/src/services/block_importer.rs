@@ -6,7 +6,10 @@ use crate::{
         SealedBlock,
     },
     services::{
-        executor::TransactionExecutionStatus,
+        executor::{
+            ContractBalanceChange,
+            TransactionExecutionStatus,
+        },
         Uncommitted,
     },
 };
@@ -25,6 +28,8 @@ pub struct ImportResult {
     pub tx_status: Vec<TransactionExecutionStatus>,
     /// The source producer of the block.
     pub source: Source,
+    /// The balances of the contracts changed by the block.
+    pub contract_balance_changes: Vec<ContractBalanceChange>,
 }
 
 /// The source producer of the block.
@@ -47,6 +52,7 @@ impl ImportResult {
             sealed_block,
             tx_status,
             source: Source::Local,
+            contract_balance_changes: vec![],
         }
     }
 
@@ -59,8 +65,18 @@ impl ImportResult {
             sealed_block,
             tx_status,
             source: Source::Network,
+            contract_balance_changes: vec![],
         }
     }
+
+    /// Sets the balances of the contracts changed by the block.
+    pub fn with_contract_balance_changes(
+        mut self,
+        contract_balance_changes: Vec<ContractBalanceChange>,
+    ) -> Self {
+        self.contract_balance_changes = contract_balance_changes;
+        self
+    }
 }
 
 /// The block import info.
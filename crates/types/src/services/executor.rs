@@ -14,9 +14,12 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        AssetId,
+        BlockHeight,
         Bytes32,
         ContractId,
         Nonce,
+        Word,
     },
     fuel_vm::{
         checked_transaction::CheckError,
@@ -44,6 +47,23 @@ pub struct ExecutionResult {
     pub skipped_transactions: Vec<(TxId, Error)>,
     /// The status of the transactions execution included into the block.
     pub tx_status: Vec<TransactionExecutionStatus>,
+    /// The balances of the contracts changed by the block.
+    pub contract_balance_changes: Vec<ContractBalanceChange>,
+}
+
+/// The change of the contract's balance of the asset caused by the block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractBalanceChange {
+    /// The height of the block that changed the balance.
+    pub block_height: BlockHeight,
+    /// The contract that owns the balance.
+    pub contract_id: ContractId,
+    /// The asset of the balance.
+    pub asset_id: AssetId,
+    /// The balance before the block.
+    pub old: Word,
+    /// The balance after the block.
+    pub new: Word,
 }
 
 /// The status of a transaction after it is executed.
@@ -268,6 +288,10 @@ pub enum Error {
     CoinbaseCannotIncreaseBalance(anyhow::Error),
     #[display(fmt = "Coinbase amount mismatches with expected.")]
     CoinbaseAmountMismatch,
+    #[display(fmt = "The coinbase recipient {_0:#x} is not a deployed contract.")]
+    CoinbaseRecipientNotFound(ContractId),
+    #[display(fmt = "Crediting the coinbase would overflow the balance of {_0:#x}.")]
+    CoinbaseBalanceOverflow(ContractId),
     #[from]
     TransactionValidity(TransactionValidityError),
     // TODO: Replace with `fuel_core_storage::Error` when execution error will live in the
@@ -357,6 +381,15 @@ pub enum TransactionValidityError {
         "The transaction contains a predicate which failed to validate: TransactionId({0:#x})"
     )]
     InvalidPredicate(TxId),
+    #[error(
+        "The transaction({tx_id:#x}) creates a coin output with {amount} of the base asset, \
+        but the maximum allowed amount is {max_amount}"
+    )]
+    CoinOutputAmountExceedsLimit {
+        tx_id: TxId,
+        amount: Word,
+        max_amount: Word,
+    },
     #[error("Transaction validity: {0:#?}")]
     Validation(CheckError),
 }
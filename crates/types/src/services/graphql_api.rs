@@ -21,3 +21,35 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The change of the supply of the asset within one block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetSupplyDelta {
+    /// The amount of the asset minted in the block.
+    pub minted: u64,
+    /// The amount of the asset burned in the block.
+    pub burned: u64,
+}
+
+/// The balance of the contract before and after the block that changed it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractBalanceUpdate {
+    /// The balance before the block.
+    pub old: u64,
+    /// The balance after the block.
+    pub new: u64,
+}
+
+/// The coinbase credited to the recipient by one block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoinbaseCredit {
+    /// The asset of the fee.
+    pub asset_id: AssetId,
+    /// The fee amount credited by the block.
+    pub amount: u64,
+    /// The sum of the amounts credited to the recipient by this and all previous blocks.
+    pub accrued: u64,
+}
@@ -20,6 +20,8 @@ use crate::{
         UtxoId,
     },
     fuel_types::{
+        BlockHeight,
+        Bytes32,
         ContractId,
         Nonce,
     },
@@ -27,6 +29,7 @@ use crate::{
         checked_transaction::Checked,
         ProgramState,
     },
+    services::p2p::PeerId,
 };
 use fuel_vm_private::checked_transaction::CheckedTransaction;
 use std::{
@@ -161,6 +164,38 @@ pub struct InsertionResult {
     pub submitted_time: Duration,
     /// These were removed during the insertion
     pub removed: Vec<ArcPoolTx>,
+    /// The `removed` transaction was evicted from the full pool as the lowest priced one,
+    /// rather than replaced by the inserted transaction spending the same inputs.
+    pub evicted: bool,
+}
+
+/// The origin of the transaction in the tx pool.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionSource {
+    /// Transaction was submitted to the node directly
+    Local,
+    /// Transaction was gossiped by the peer
+    Peer(PeerId),
+}
+
+/// The kind of the reason why the transaction was squeezed out of the tx pool.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqueezedOutKind {
+    /// Evicted from the full tx pool by the transaction paying the higher gas price
+    Evicted,
+    /// Removed for another reason, like the replacement by the transaction spending
+    /// the same inputs or the invalidated dependency
+    Removed,
+}
+
+impl From<&Error> for SqueezedOutKind {
+    fn from(reason: &Error) -> Self {
+        match reason {
+            Error::EvictedByHigherGasPrice => SqueezedOutKind::Evicted,
+            _ => SqueezedOutKind::Removed,
+        }
+    }
 }
 
 /// The status of the transaction during its life from the tx pool until the block.
@@ -185,6 +220,8 @@ pub enum TransactionStatus {
     SqueezedOut {
         /// Why this happened
         reason: String,
+        /// The machine-readable kind of the `reason`
+        kind: SqueezedOutKind,
     },
     /// Transaction was included in a block, but the exection was reverted
     Failed {
@@ -197,6 +234,12 @@ pub enum TransactionStatus {
         /// Result of executing the transaction for scripts
         result: Option<ProgramState>,
     },
+    /// Transaction was dropped from the txpool, because it wasn't included
+    /// in a block by the expiry height
+    Expired {
+        /// The last height the transaction could be included at
+        expire_at: BlockHeight,
+    },
 }
 
 #[allow(missing_docs)]
@@ -263,13 +306,27 @@ pub enum Error {
     NotInsertedIoContractOutput,
     #[error("Transaction is not inserted. Maximum depth of dependent transaction chain reached")]
     NotInsertedMaxDepth,
+    #[error(
+        "Transaction is not inserted. Predicate with code root {0:#x} is not allowed"
+    )]
+    NotInsertedPredicateNotAllowed(Bytes32),
     #[error("Transaction exceeds the max gas per block limit. Tx gas: {tx_gas}, block limit {block_limit}")]
     NotInsertedMaxGasLimit { tx_gas: Word, block_limit: Word },
+    #[error("Transaction is not inserted. It references {assets} distinct assets, but the limit is {limit}")]
+    NotInsertedTooManyAssets { assets: usize, limit: usize },
+    #[error("Transaction is not inserted. It generates the coinbase fee {fee}, but the minimum is {min_fee}")]
+    NotInsertedInsufficientCoinbaseFee { fee: Word, min_fee: Word },
+    #[error("Transaction is not inserted. The node is shutting down and doesn't accept new transactions")]
+    NotInsertedShuttingDown,
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
     #[error("Transaction expired because it exceeded the configured time to live `tx-pool-ttl`.")]
     TTLReason,
+    #[error("Transaction removed. The pool limit is hit, and it was evicted by the transaction paying the higher gas price.")]
+    EvictedByHigherGasPrice,
+    #[error("Transaction removed because its dependency was invalidated: the input was spent by the committed transaction {0:#x}.")]
+    DependencyInvalidated(TxId),
     #[error("Transaction squeezed out because {0}")]
     SqueezedOut(String),
     // TODO: We need it for now until channels are removed from TxPool.
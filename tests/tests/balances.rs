@@ -1,5 +1,6 @@
 use fuel_core::{
     chain_config::{
+        ChainConfig,
         CoinConfig,
         MessageConfig,
         StateConfig,
@@ -19,6 +20,7 @@ use fuel_core_client::client::{
             Address,
             AssetId,
         },
+        BalanceQuery,
         CoinType,
     },
     FuelClient,
@@ -26,11 +28,186 @@ use fuel_core_client::client::{
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     fuel_tx::{
+        Contract,
         Input,
         Output,
         TransactionBuilder,
     },
 };
+use futures::StreamExt;
+use std::time::Duration;
+
+#[tokio::test]
+async fn balances_at_height_resolves_contract_and_address_balances() {
+    let owner = Address::from([1; 32]);
+    let asset_id = AssetId::BASE;
+    let bytecode = vec![];
+    let salt = Default::default();
+    let contract = Contract::from(bytecode.clone());
+    let contract_id =
+        contract.id(&salt, &contract.root(), &Contract::default_state_root());
+    let mut config = Config::local_node();
+    config.chain_conf =
+        ChainConfig::local_testnet().with_contract(bytecode, salt, vec![]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    client
+        .credit_coinbase(contract_id, asset_id, 100)
+        .await
+        .unwrap();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(100)
+        .add_random_fee_input()
+        .add_output(Output::coin(owner, 10, asset_id))
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    client
+        .credit_coinbase(contract_id, asset_id, 50)
+        .await
+        .unwrap();
+    let latest_height = client.chain_info().await.unwrap().latest_block_height;
+    assert_eq!(latest_height, 3);
+    let contract_query = BalanceQuery::Contract {
+        contract_id,
+        asset_id,
+    };
+    let address_query = BalanceQuery::Address { owner, asset_id };
+
+    // When
+    let past = client
+        .balances_at_height(&[contract_query], 1.into())
+        .await
+        .unwrap();
+    let latest = client
+        .balances_at_height(&[address_query, contract_query], 3.into())
+        .await
+        .unwrap();
+    let past_address = client.balances_at_height(&[address_query], 2.into()).await;
+    let future = client.balances_at_height(&[contract_query], 4.into()).await;
+
+    // Then
+    assert_eq!(past, vec![100]);
+    assert_eq!(latest, vec![10, 150]);
+    assert!(past_address.is_err());
+    assert!(future.is_err());
+}
+
+#[tokio::test]
+async fn concurrent_credit_coinbase_calls_use_distinct_coins() {
+    let asset_id = AssetId::BASE;
+    let bytecode = vec![];
+    let salt = Default::default();
+    let contract = Contract::from(bytecode.clone());
+    let contract_id =
+        contract.id(&salt, &contract.root(), &Contract::default_state_root());
+    let mut config = Config::local_node();
+    config.chain_conf =
+        ChainConfig::local_testnet().with_contract(bytecode, salt, vec![]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let (first, second) = futures::join!(
+        client.credit_coinbase(contract_id, asset_id, 100),
+        client.credit_coinbase(contract_id, asset_id, 100),
+    );
+
+    // Then
+    first.unwrap();
+    second.unwrap();
+    assert_eq!(
+        client
+            .contract_balance(&contract_id, Some(&asset_id))
+            .await
+            .unwrap(),
+        200
+    );
+}
+
+#[tokio::test]
+async fn finalized_contract_balance_lags_behind_by_finalization_depth() {
+    let asset_id = AssetId::BASE;
+    let bytecode = vec![];
+    let salt = Default::default();
+    let contract = Contract::from(bytecode.clone());
+    let contract_id =
+        contract.id(&salt, &contract.root(), &Contract::default_state_root());
+    let mut config = Config::local_node();
+    config.chain_conf =
+        ChainConfig::local_testnet().with_contract(bytecode, salt, vec![]);
+    config.finalization_depth = 1;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    client
+        .credit_coinbase(contract_id, asset_id, 100)
+        .await
+        .unwrap();
+    client
+        .credit_coinbase(contract_id, asset_id, 50)
+        .await
+        .unwrap();
+
+    // When
+    let finalized = client
+        .finalized_contract_balance(&contract_id, &asset_id)
+        .await
+        .unwrap();
+    let latest = client
+        .contract_balance(&contract_id, Some(&asset_id))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(finalized, 100);
+    assert_eq!(latest, 150);
+}
+
+#[tokio::test]
+async fn subscribe_balance_yields_new_balance_after_each_change() {
+    let owner = Address::from([1; 32]);
+    let asset_id = AssetId::BASE;
+    let amount = 100;
+
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The subscription is established lazily on the first poll of the stream,
+    // so keep crediting the owner in the background until we receive the changes.
+    let sender = client.clone();
+    let transfers = tokio::spawn(async move {
+        loop {
+            let tx = TransactionBuilder::script(vec![], vec![])
+                .script_gas_limit(100)
+                .add_random_fee_input()
+                .add_output(Output::coin(owner, amount, asset_id))
+                .finalize_as_transaction();
+            sender.submit_and_await_commit(&tx).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    let mut balances = client.subscribe_balance(owner, asset_id).await.unwrap();
+    let mut received = vec![];
+    for _ in 0..2 {
+        let balance = tokio::time::timeout(Duration::from_secs(5), balances.next())
+            .await
+            .expect("should receive the balance before the timeout")
+            .expect("the stream should not end")
+            .unwrap();
+        received.push(balance);
+    }
+    transfers.abort();
+
+    let (first, second) = (received[0], received[1]);
+    assert!(first > 0);
+    assert_eq!(first % amount, 0);
+    assert!(second > first);
+    assert_eq!(second % amount, 0);
+}
 
 #[tokio::test]
 async fn balance() {
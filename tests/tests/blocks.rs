@@ -10,7 +10,10 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        CoinbaseCredit,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::Trigger;
@@ -26,15 +29,29 @@ use fuel_core_types::{
         block::CompressedBlock,
         consensus::Consensus,
     },
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_crypto::SecretKey,
     fuel_tx::*,
-    fuel_types::ChainId,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
+use futures::StreamExt;
 use itertools::{
     rev,
     Itertools,
 };
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
 use rstest::rstest;
 use std::{
     ops::Deref,
@@ -149,6 +166,990 @@ async fn produce_block_manually() {
     assert_eq!(*actual_pub_key, expected_pub_key);
 }
 
+#[tokio::test]
+async fn produce_blocks_detailed_returns_summary_of_each_block() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_price(1)
+    .script_gas_limit(10_000)
+    .add_random_fee_input()
+    .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+
+    let start_time = Tai64::now().0;
+    let summaries = client
+        .produce_blocks_detailed(3, Some(start_time))
+        .await
+        .unwrap();
+
+    let heights: Vec<_> = summaries.iter().map(|summary| summary.height).collect();
+    assert_eq!(heights, vec![1, 2, 3]);
+    // The first block contains the script and the mint transactions.
+    assert_eq!(summaries[0].tx_count, 2);
+    assert_eq!(summaries[1].tx_count, 1);
+    assert_eq!(summaries[0].timestamp, Tai64(start_time));
+    for summary in &summaries {
+        let block = client
+            .block_by_height(summary.height)
+            .await
+            .unwrap()
+            .unwrap();
+        let coinbase = client
+            .block_coinbase(summary.height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(summary.timestamp, block.header.time);
+        assert_eq!(summary.coinbase, coinbase.amounts);
+    }
+}
+
+#[tokio::test]
+async fn deterministic_time_assigns_timestamps_from_height() {
+    let start = Tai64::UNIX_EPOCH.0 + 1_000;
+    let config =
+        Config::local_node().deterministic_time(Tai64(start), Duration::from_secs(10));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(2, None).await.unwrap();
+    client
+        .submit_and_await_commit(&Transaction::default_test_tx())
+        .await
+        .unwrap();
+
+    for height in 1..=3u32 {
+        let block = client.block_by_height(height).await.unwrap().unwrap();
+        assert_eq!(block.header.time, Tai64(start + 10 * u64::from(height)));
+    }
+}
+
+#[tokio::test]
+async fn deterministic_time_does_not_go_below_manual_start_time() {
+    let start = Tai64::UNIX_EPOCH.0 + 1_000;
+    let config =
+        Config::local_node().deterministic_time(Tai64(start), Duration::from_secs(10));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The manual start time is ahead of the deterministic time of the blocks.
+    let manual_start = start + 1_000;
+    client.produce_blocks(2, Some(manual_start)).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    for height in 1..=3u32 {
+        let block = client.block_by_height(height).await.unwrap().unwrap();
+        assert_eq!(block.header.time, Tai64(manual_start));
+    }
+}
+
+#[tokio::test]
+async fn produce_block_with_txs_bypasses_txpool() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let [pending_tx, first_tx, second_tx] = [0, 1, 2].map(|i| {
+        TransactionBuilder::script(vec![op::movi(0x10, i)].into_iter().collect(), vec![])
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    });
+    client.submit(&pending_tx).await.unwrap();
+
+    let new_height = client
+        .produce_block_with_txs(vec![second_tx.clone(), first_tx.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(1, *new_height);
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    let chain_id = ChainId::default();
+    // The mint transaction is the last one.
+    assert_eq!(block.transactions.len(), 3);
+    assert_eq!(block.transactions[0], second_tx.id(&chain_id));
+    assert_eq!(block.transactions[1], first_tx.id(&chain_id));
+    let status = client
+        .transaction_status(&pending_tx.id(&chain_id))
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Submitted { .. }));
+}
+
+#[tokio::test]
+async fn estimate_commit_time_is_zero_for_instant_block_production() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default_test_tx();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let estimation = client.estimate_commit_time().await.unwrap();
+    assert_eq!(estimation, Duration::ZERO);
+}
+
+#[tokio::test]
+async fn estimate_commit_time_reflects_block_time_of_interval_block_production() {
+    let block_time = Duration::from_secs(10);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Interval { block_time };
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let estimation = client.estimate_commit_time().await.unwrap();
+    assert_eq!(estimation, block_time);
+}
+
+#[tokio::test]
+async fn estimate_commit_time_fails_for_manual_block_production() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client
+        .estimate_commit_time()
+        .await
+        .expect_err("The commit time of the manual block production is unknown");
+}
+
+/// Starts the node producing the blocks on demand, which credits the fees to the
+/// deployed contract. Returns the node, its client, and the id of the contract.
+async fn node_with_coinbase_recipient(
+    rng: &mut StdRng,
+) -> (FuelService, FuelClient, ContractId) {
+    node_with_coinbase_recipient_and_config(rng, Config::local_node()).await
+}
+
+/// Same as [`node_with_coinbase_recipient`], but starts the node from the `config`.
+async fn node_with_coinbase_recipient_and_config(
+    rng: &mut StdRng,
+    mut config: Config,
+) -> (FuelService, FuelClient, ContractId) {
+    let contract_code: Vec<u8> = vec![];
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(contract_code.clone());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+
+    config.block_production = Trigger::Never;
+    config.block_producer.coinbase_recipient = Some(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Deploy the coinbase recipient.
+    let create_tx = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    client.submit(&create_tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    (srv, client, contract_id)
+}
+
+/// Produces the block with the script paying the fee with the `gas_price`.
+async fn produce_block_with_fee(
+    client: &FuelClient,
+    rng: &mut StdRng,
+    gas_price: Word,
+) -> BlockHeight {
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_price(gas_price)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(rng),
+        rng.gen(),
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap()
+}
+
+#[tokio::test]
+async fn block_coinbase_reports_fee_credited_to_recipient() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let (_srv, client, contract_id) = node_with_coinbase_recipient(&mut rng).await;
+    let old_balance = client.contract_balance(&contract_id, None).await.unwrap();
+
+    let height = produce_block_with_fee(&client, &mut rng, 2).await;
+
+    let coinbase = client
+        .block_coinbase(height)
+        .await
+        .unwrap()
+        .expect("The block should have the mint transaction");
+    let new_balance = client.contract_balance(&contract_id, None).await.unwrap();
+    assert_eq!(coinbase.recipient, Some(contract_id));
+    assert_eq!(
+        coinbase.amounts,
+        vec![(AssetId::BASE, new_balance - old_balance)]
+    );
+    assert!(coinbase.amounts[0].1 > 0);
+}
+
+#[tokio::test]
+async fn coinbase_credits_fee_in_allowlisted_asset() {
+    let mut rng = StdRng::seed_from_u64(2324);
+    let mut config = Config::local_node();
+    config.block_producer.coinbase_asset_allowlist = Some(vec![AssetId::BASE]);
+    let (_srv, client, contract_id) =
+        node_with_coinbase_recipient_and_config(&mut rng, config).await;
+    let old_balance = client.contract_balance(&contract_id, None).await.unwrap();
+
+    let height = produce_block_with_fee(&client, &mut rng, 2).await;
+
+    let coinbase = client.block_coinbase(height).await.unwrap().unwrap();
+    let new_balance = client.contract_balance(&contract_id, None).await.unwrap();
+    assert_eq!(coinbase.recipient, Some(contract_id));
+    assert!(coinbase.amounts[0].1 > 0);
+    assert_eq!(new_balance - old_balance, coinbase.amounts[0].1);
+}
+
+#[tokio::test]
+async fn coinbase_burns_fee_in_not_allowlisted_asset() {
+    let mut rng = StdRng::seed_from_u64(2325);
+    let mut config = Config::local_node();
+    config.block_producer.coinbase_asset_allowlist = Some(vec![AssetId::new([1; 32])]);
+    let (_srv, client, contract_id) =
+        node_with_coinbase_recipient_and_config(&mut rng, config).await;
+    let old_balance = client.contract_balance(&contract_id, None).await.unwrap();
+
+    let height = produce_block_with_fee(&client, &mut rng, 2).await;
+
+    // The fee is paid in the base asset, which is not allowlisted.
+    let coinbase = client.block_coinbase(height).await.unwrap().unwrap();
+    let new_balance = client.contract_balance(&contract_id, None).await.unwrap();
+    assert_eq!(coinbase.recipient, None);
+    assert_eq!(coinbase.amounts, vec![(AssetId::BASE, 0)]);
+    assert_eq!(new_balance, old_balance);
+}
+
+#[tokio::test]
+async fn block_coinbase_reports_burned_fee_without_recipient() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The genesis block doesn't have the mint transaction.
+    assert_eq!(client.block_coinbase(0u32.into()).await.unwrap(), None);
+
+    let tx = Transaction::default_test_tx();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let coinbase = client
+        .block_coinbase(1u32.into())
+        .await
+        .unwrap()
+        .expect("The block should have the mint transaction");
+    assert_eq!(coinbase.recipient, None);
+    assert_eq!(coinbase.amounts, vec![(AssetId::BASE, 0)]);
+    assert_eq!(client.block_coinbase(2u32.into()).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn block_reports_coinbase_of_empty_block() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(1, None).await.unwrap();
+
+    // The genesis block doesn't have the mint transaction.
+    let genesis = client
+        .block_with_coinbase_by_height(0u32.into())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(genesis.coinbase, None);
+
+    // The empty block has the mint transaction without fees.
+    let block = client
+        .block_with_coinbase_by_height(1u32.into())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(block.block.header.height, 1);
+    let coinbase = block
+        .coinbase
+        .expect("The block should have the mint transaction");
+    assert_eq!(coinbase.recipient, None);
+    assert_eq!(coinbase.amounts, vec![(AssetId::BASE, 0)]);
+}
+
+#[tokio::test]
+async fn pending_coinbase_matches_coinbase_of_produced_block() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let contract_code: Vec<u8> = vec![];
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(contract_code.clone());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.block_producer.coinbase_recipient = Some(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Deploy the coinbase recipient.
+    let create_tx = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    client.submit(&create_tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_price(1)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+
+    let pending = client.pending_coinbase().await.unwrap();
+    assert_eq!(pending.len(), 1);
+    assert!(pending[0].1 > 0);
+
+    // The preview doesn't produce the block or remove the transaction from the `TxPool`.
+    let status = client
+        .transaction_status(&tx.id(&ChainId::default()))
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Submitted { .. }));
+
+    let height = client.produce_blocks(1, None).await.unwrap();
+    let coinbase = client.block_coinbase(height).await.unwrap().unwrap();
+    assert_eq!(coinbase.recipient, Some(contract_id));
+    assert_eq!(coinbase.amounts, pending);
+}
+
+#[tokio::test]
+async fn dry_run_coinbase_effect_matches_credit_to_coinbase_recipient() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let contract_code: Vec<u8> = vec![];
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(contract_code.clone());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.block_producer.coinbase_recipient = Some(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Deploy the coinbase recipient.
+    let create_tx = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    client.submit(&create_tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+    let old_balance = client.contract_balance(&contract_id, None).await.unwrap();
+
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_price(1)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+
+    let (asset_id, amount) = client.dry_run_coinbase_effect(&tx).await.unwrap();
+    assert_eq!(asset_id, AssetId::BASE);
+    assert!(amount > 0);
+
+    client.submit(&tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    let new_balance = client.contract_balance(&contract_id, None).await.unwrap();
+    assert_eq!(new_balance, old_balance + amount);
+}
+
+#[tokio::test]
+async fn replay_block_returns_receipts_without_committing() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let contract_code: Vec<u8> = vec![];
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(contract_code.clone());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+
+    let mut config = Config::local_node();
+    config.block_producer.coinbase_recipient = Some(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Deploy the coinbase recipient.
+    let create_tx = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&create_tx).await.unwrap();
+
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_price(1)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let height = client.chain_info().await.unwrap().latest_block_height;
+    let balance = client.contract_balance(&contract_id, None).await.unwrap();
+    assert!(balance > 0);
+
+    // When
+    let replayed = client.replay_block(height.into()).await.unwrap();
+
+    // Then
+    assert_eq!(replayed.len(), 2);
+    assert_eq!(
+        Some(replayed[0].clone()),
+        client.receipts(&tx_id).await.unwrap()
+    );
+    assert_eq!(
+        client.chain_info().await.unwrap().latest_block_height,
+        height
+    );
+    assert_eq!(
+        client.contract_balance(&contract_id, None).await.unwrap(),
+        balance
+    );
+}
+
+#[tokio::test]
+async fn replay_block_uses_the_timestamp_of_the_block() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The script logs the timestamp of the block executing it.
+    let tx = TransactionBuilder::script(
+        vec![
+            op::bhei(0x10),
+            op::time(0x11, 0x10),
+            op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect(),
+        vec![],
+    )
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit(&tx).await.unwrap();
+
+    // The block time is far ahead of the time of the replay.
+    let block_time = Tai64::now().0 + 1_000_000;
+    let height = client.produce_blocks(1, Some(block_time)).await.unwrap();
+
+    // When
+    let replayed = client.replay_block(height).await.unwrap();
+
+    // Then
+    let receipts = client.receipts(&tx_id).await.unwrap().unwrap();
+    assert!(matches!(receipts[0], Receipt::Log { ra, .. } if ra == block_time));
+    assert_eq!(replayed[0], receipts);
+}
+
+#[tokio::test]
+async fn produce_block_if_coinbase_over_skips_blocks_with_small_coinbase() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let contract_code: Vec<u8> = vec![];
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(contract_code.clone());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.block_producer.coinbase_recipient = Some(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Deploy the coinbase recipient.
+    let create_tx = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    client.submit(&create_tx).await.unwrap();
+    let height = client.produce_blocks(1, None).await.unwrap();
+
+    // The empty `TxPool` doesn't generate any coinbase.
+    assert_eq!(
+        client.produce_block_if_coinbase_over(0).await.unwrap(),
+        None
+    );
+
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_price(1)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+    let pending = client.pending_coinbase().await.unwrap();
+    let (_, coinbase) = pending[0];
+
+    assert_eq!(
+        client
+            .produce_block_if_coinbase_over(coinbase)
+            .await
+            .unwrap(),
+        None
+    );
+    let produced = client
+        .produce_block_if_coinbase_over(coinbase - 1)
+        .await
+        .unwrap();
+    assert_eq!(produced, Some((*height + 1).into()));
+    let status = client
+        .transaction_status(&tx.id(&ChainId::default()))
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    // The transaction doesn't fit into the block with the runtime gas limit,
+    // so the block doesn't have any coinbase.
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_price(1)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+    client.set_block_gas_limit(1).await.unwrap();
+    assert_eq!(
+        client.produce_block_if_coinbase_over(0).await.unwrap(),
+        None
+    );
+    let status = client
+        .transaction_status(&tx.id(&ChainId::default()))
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Submitted { .. }));
+}
+
+#[tokio::test]
+async fn set_block_gas_limit_limits_gas_of_produced_blocks() {
+    let mut rng = StdRng::seed_from_u64(2324);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let chain_block_gas_limit = config.chain_conf.block_gas_limit;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.set_block_gas_limit(1_500_000).await.unwrap();
+
+    let mut tx_ids = vec![];
+    for _ in 0..2 {
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            1_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize_as_transaction();
+        tx_ids.push(tx.id(&ChainId::default()));
+        client.submit(&tx).await.unwrap();
+    }
+
+    let height = client.produce_blocks(1, None).await.unwrap();
+    let block = client.block_by_height(*height).await.unwrap().unwrap();
+    assert_eq!(block.transactions.len(), 2 /* mint + one of our txs */);
+
+    // The limit can't exceed the block gas limit of the chain.
+    client
+        .set_block_gas_limit(chain_block_gas_limit + 1)
+        .await
+        .expect_err("The limit above the chain's limit should be rejected");
+}
+
+#[tokio::test]
+async fn accrued_coinbase_sums_coinbase_of_blocks_up_to_height() {
+    let mut rng = StdRng::seed_from_u64(2323);
+    let contract_code: Vec<u8> = vec![];
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(contract_code.clone());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.block_producer.coinbase_recipient = Some(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Deploy the coinbase recipient.
+    let create_tx = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    client.submit(&create_tx).await.unwrap();
+    let deployed_at = client.produce_blocks(1, None).await.unwrap();
+
+    // Produce blocks with the fee paying transactions.
+    let mut expected = vec![];
+    for gas_price in 1..=3 {
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_price(gas_price)
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize_as_transaction();
+        client.submit(&tx).await.unwrap();
+        let height = client.produce_blocks(1, None).await.unwrap();
+        let coinbase = client.block_coinbase(height).await.unwrap().unwrap();
+        assert!(coinbase.amounts[0].1 > 0);
+        expected.push((height, coinbase.amounts[0].1));
+    }
+
+    let mut accrued = client
+        .accrued_coinbase(&contract_id, deployed_at)
+        .await
+        .unwrap();
+    for (height, amount) in expected {
+        let next_accrued = client.accrued_coinbase(&contract_id, height).await.unwrap();
+        assert_eq!(next_accrued, accrued + amount);
+        accrued = next_accrued;
+    }
+
+    // The other contracts didn't receive anything.
+    let latest_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    let other = client
+        .accrued_coinbase(&rng.gen(), latest_height.into())
+        .await
+        .unwrap();
+    assert_eq!(other, 0);
+
+    // The future blocks are not accrued yet.
+    let result = client
+        .accrued_coinbase(&contract_id, (latest_height + 1).into())
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn blocks_crediting_returns_only_blocks_that_credited_recipient() {
+    let mut rng = StdRng::seed_from_u64(2323);
+    // The credits are served by the index, so the endpoint doesn't require `debug`.
+    let mut config = Config::local_node();
+    config.debug = false;
+    let (_srv, client, contract_id) =
+        node_with_coinbase_recipient_and_config(&mut rng, config).await;
+
+    // Every other block has the fee paying transaction.
+    let mut expected = vec![];
+    for gas_price in 1..=3 {
+        let height = produce_block_with_fee(&client, &mut rng, gas_price).await;
+        let coinbase = client.block_coinbase(height).await.unwrap().unwrap();
+        expected.push((height, AssetId::BASE, coinbase.amounts[0].1));
+        client.produce_blocks(1, None).await.unwrap();
+    }
+    let latest_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    let credits = client
+        .blocks_crediting(contract_id, 0.into()..(latest_height + 10).into())
+        .await
+        .unwrap();
+    assert_eq!(credits, expected);
+
+    // The range excludes its end.
+    let first_credit = expected[0].0;
+    let credits = client
+        .blocks_crediting(contract_id, 0.into()..first_credit)
+        .await
+        .unwrap();
+    assert!(credits.is_empty());
+
+    // The other contracts didn't receive anything.
+    let credits = client
+        .blocks_crediting(rng.gen(), 0.into()..latest_height.into())
+        .await
+        .unwrap();
+    assert!(credits.is_empty());
+}
+
+#[tokio::test]
+async fn recent_coinbase_credits_returns_latest_credits_newest_first() {
+    let mut rng = StdRng::seed_from_u64(2323);
+    // The credits are served by the index, so the endpoint doesn't require `debug`.
+    let mut config = Config::local_node();
+    config.debug = false;
+    let (_srv, client, contract_id) =
+        node_with_coinbase_recipient_and_config(&mut rng, config).await;
+
+    let mut expected = vec![];
+    for gas_price in 1..=3 {
+        let height = produce_block_with_fee(&client, &mut rng, gas_price).await;
+        let coinbase = client.block_coinbase(height).await.unwrap().unwrap();
+        expected.push(CoinbaseCredit {
+            block_height: height,
+            asset_id: AssetId::BASE,
+            amount: coinbase.amounts[0].1,
+        });
+        // The empty block doesn't credit the recipient.
+        client.produce_blocks(1, None).await.unwrap();
+    }
+    expected.reverse();
+
+    let credits = client
+        .recent_coinbase_credits(contract_id, 2)
+        .await
+        .unwrap();
+    assert_eq!(credits, expected[..2]);
+
+    let credits = client
+        .recent_coinbase_credits(contract_id, 10)
+        .await
+        .unwrap();
+    assert_eq!(credits, expected);
+
+    let credits = client.recent_coinbase_credits(rng.gen(), 10).await.unwrap();
+    assert!(credits.is_empty());
+}
+
+#[tokio::test]
+async fn blocks_until_coinbase_projects_from_recent_credits() {
+    let mut rng = StdRng::seed_from_u64(2323);
+    // The estimation doesn't require the `debug` mode of the node.
+    let mut config = Config::local_node();
+    config.debug = false;
+    let (_srv, client, contract_id) =
+        node_with_coinbase_recipient_and_config(&mut rng, config).await;
+
+    // No blocks credited the recipient yet.
+    assert_eq!(
+        client
+            .blocks_until_coinbase(contract_id, AssetId::BASE, 1)
+            .await
+            .unwrap(),
+        None
+    );
+
+    for gas_price in 1..=3 {
+        produce_block_with_fee(&client, &mut rng, gas_price).await;
+        client.produce_blocks(1, None).await.unwrap();
+    }
+    let balance = client
+        .contract_balance(&contract_id, Some(&AssetId::BASE))
+        .await
+        .unwrap();
+    let produced_blocks = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    assert_eq!(
+        client
+            .blocks_until_coinbase(contract_id, AssetId::BASE, balance)
+            .await
+            .unwrap(),
+        Some(0)
+    );
+    // All produced blocks together credited the `balance`, so doubling it
+    // takes the same number of blocks.
+    assert_eq!(
+        client
+            .blocks_until_coinbase(contract_id, AssetId::BASE, balance * 2)
+            .await
+            .unwrap(),
+        Some(produced_blocks as u64)
+    );
+}
+
+#[tokio::test]
+async fn subscribe_block_headers_receives_produced_blocks() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The subscription is established lazily on the first poll of the stream,
+    // so keep producing blocks in the background until we receive them.
+    let producer = client.clone();
+    let production = tokio::spawn(async move {
+        loop {
+            producer.produce_blocks(1, None).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    let mut headers = client.subscribe_block_headers().await.unwrap();
+    let mut received = vec![];
+    for _ in 0..2 {
+        let header = tokio::time::timeout(Duration::from_secs(5), headers.next())
+            .await
+            .expect("should receive the header before the timeout")
+            .expect("the stream should not end")
+            .unwrap();
+        received.push(header);
+    }
+    production.abort();
+
+    let (first, second) = (&received[0], &received[1]);
+
+    assert!(first.height > 0);
+    assert_eq!(second.height, first.height + 1);
+}
+
+#[tokio::test]
+async fn await_block_height_resolves_when_target_is_reached() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let producer = client.clone();
+    let production = tokio::spawn(async move {
+        for _ in 0..3 {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            producer.produce_blocks(1, None).await.unwrap();
+        }
+    });
+
+    client
+        .await_block_height(3u32.into(), Duration::from_secs(10))
+        .await
+        .expect("should reach the target height before the timeout");
+    production.await.unwrap();
+
+    // The already reached height resolves immediately
+    client
+        .await_block_height(2u32.into(), Duration::from_secs(1))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn await_block_height_fails_on_timeout() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let err = client
+        .await_block_height(10u32.into(), Duration::from_millis(500))
+        .await
+        .expect_err("should time out");
+
+    assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+}
+
 #[tokio::test]
 async fn produce_block_negative() {
     let db = Database::default();
@@ -198,6 +1199,96 @@ async fn produce_block_custom_time() {
     assert_eq!(db.block_time(&5u32.into()).unwrap().0, start_timestamp + 40);
 }
 
+#[tokio::test]
+async fn blocks_by_time_range_returns_only_blocks_within_range() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Interval {
+        block_time: Duration::from_secs(10),
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Blocks 1..=5 have timestamps `start + 0, start + 10, ..., start + 40`
+    let start_timestamp = Tai64::UNIX_EPOCH.0 + 100u64;
+    client
+        .produce_blocks(5, Some(start_timestamp))
+        .await
+        .unwrap();
+    let from_time = start_timestamp + 5;
+    let to_time = start_timestamp + 30;
+
+    let forward = client
+        .blocks_by_time_range(
+            from_time,
+            to_time,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let forward_heights: Vec<_> = forward
+        .results
+        .iter()
+        .map(|block| block.header.height)
+        .collect();
+    assert_eq!(forward_heights, vec![2, 3, 4]);
+
+    let backward = client
+        .blocks_by_time_range(
+            from_time,
+            to_time,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Backward,
+            },
+        )
+        .await
+        .unwrap();
+    let backward_heights: Vec<_> = backward
+        .results
+        .iter()
+        .map(|block| block.header.height)
+        .collect();
+    assert_eq!(backward_heights, vec![4, 3, 2]);
+
+    // The next page continues from the cursor and stays within the range
+    let first_page = client
+        .blocks_by_time_range(
+            from_time,
+            to_time,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .blocks_by_time_range(
+            from_time,
+            to_time,
+            PaginationRequest {
+                cursor: first_page.cursor,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page_heights: Vec<_> = second_page
+        .results
+        .iter()
+        .map(|block| block.header.height)
+        .collect();
+    assert_eq!(second_page_heights, vec![4]);
+    assert!(!second_page.has_next_page);
+}
+
 #[tokio::test]
 async fn produce_block_bad_start_time() {
     let db = Database::default();
@@ -1,8 +1,15 @@
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    chain_config::{
+        AssetConfig,
+        GenesisCommitment,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
 };
 use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::AssetId;
 
 #[tokio::test]
 async fn chain_info() {
@@ -23,4 +30,81 @@ async fn chain_info() {
         node_config.chain_conf.consensus_parameters.gas_costs,
         chain_info.consensus_parameters.gas_costs
     );
+    assert_eq!(
+        node_config.chain_conf.consensus_parameters.base_asset_id,
+        chain_info.base_asset_id
+    );
+    assert_eq!(
+        node_config.chain_conf.block_gas_limit,
+        chain_info.block_gas_limit
+    );
+    assert_eq!(0, chain_info.latest_block_height);
+}
+
+#[tokio::test]
+async fn asset_info_returns_configured_asset() {
+    let asset_id = AssetId::new([1; 32]);
+    let mut node_config = Config::local_node();
+    node_config.chain_conf.assets = vec![AssetConfig {
+        asset_id,
+        name: "Test Token".to_string(),
+        symbol: "TST".to_string(),
+        decimals: 9,
+    }];
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let asset_info = client.asset_info(&asset_id).await.unwrap().unwrap();
+    assert_eq!(asset_info.asset_id, asset_id);
+    assert_eq!(asset_info.name, "Test Token");
+    assert_eq!(asset_info.symbol, "TST");
+    assert_eq!(asset_info.decimals, 9);
+
+    let unknown = client.asset_info(&AssetId::new([2; 32])).await.unwrap();
+    assert!(unknown.is_none());
+}
+
+#[tokio::test]
+async fn chain_config_returns_config_of_the_node() {
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let chain_config = client.chain_config().await.unwrap();
+
+    assert_eq!(chain_config.chain_name, node_config.chain_conf.chain_name);
+    assert_eq!(
+        chain_config.block_gas_limit,
+        node_config.chain_conf.block_gas_limit
+    );
+    assert_eq!(
+        chain_config.consensus_parameters,
+        node_config.chain_conf.consensus_parameters
+    );
+}
+
+#[tokio::test]
+async fn genesis_info_identifies_the_network() {
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let same_srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let same_client = FuelClient::from(same_srv.bound_address);
+    let mut other_config = Config::local_node();
+    other_config.chain_conf.chain_name = "other".to_string();
+    let other_srv = FuelService::new_node(other_config).await.unwrap();
+    let other_client = FuelClient::from(other_srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    let genesis = client.genesis_info().await.unwrap();
+    let same_genesis = same_client.genesis_info().await.unwrap();
+    let other_genesis = other_client.genesis_info().await.unwrap();
+
+    assert_eq!(genesis.header.height, 0);
+    assert_eq!(
+        genesis.chain_config_hash,
+        node_config.chain_conf.root().unwrap().into()
+    );
+    assert_eq!(genesis.chain_config_hash, same_genesis.chain_config_hash);
+    assert_ne!(genesis.chain_config_hash, other_genesis.chain_config_hash);
 }
@@ -23,7 +23,16 @@ use rand::{
 
 mod coin {
     use super::*;
-    use fuel_core_client::client::types::CoinType;
+    use fuel_core_client::client::{
+        pagination::{
+            PageDirection,
+            PaginationRequest,
+        },
+        types::{
+            CoinType,
+            TransactionStatus,
+        },
+    };
     use fuel_core_types::fuel_crypto::SecretKey;
     use rand::Rng;
 
@@ -149,6 +158,67 @@ mod coin {
         assert!(remaining_coins_b.is_err())
     }
 
+    #[tokio::test]
+    async fn inputs_required_matches_successful_spend() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let asset_id_a: AssetId = rng.gen();
+        let asset_id_b: AssetId = rng.gen();
+        let secret_key: SecretKey = SecretKey::random(&mut rng);
+        let owner = Input::owner(&secret_key.public_key());
+        let context = setup(owner, asset_id_a, asset_id_b).await;
+        let amount = 200;
+
+        // The random selection covers the amount with two or all three coins
+        let inputs_required = context
+            .client
+            .inputs_required(&owner, &asset_id_a, amount)
+            .await
+            .unwrap();
+        assert!((2..=3).contains(&inputs_required));
+
+        // Spend the amount with the reported number of the biggest coins
+        let mut coins = context
+            .client
+            .coins(
+                &owner,
+                Some(&asset_id_a),
+                PaginationRequest {
+                    cursor: None,
+                    results: 10,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await
+            .unwrap()
+            .results;
+        coins.sort_by_key(|coin| core::cmp::Reverse(coin.amount));
+
+        let mut script = TransactionBuilder::script(vec![], vec![]);
+        for coin in coins.iter().take(inputs_required as usize) {
+            script.add_unsigned_coin_input(
+                secret_key,
+                coin.utxo_id,
+                coin.amount,
+                coin.asset_id,
+                Default::default(),
+                coin.maturity.into(),
+            );
+        }
+        script.add_output(Output::coin(rng.gen(), amount, asset_id_a));
+        script.add_output(Output::change(owner, 0, asset_id_a));
+        let tx = script.finalize_as_transaction();
+
+        let status = context.client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(matches!(status, TransactionStatus::Success { .. }));
+
+        // Only 100 is left in the unspent coin and the change, so 250 can't be covered
+        let result = context
+            .client
+            .inputs_required(&owner, &asset_id_a, amount + 50)
+            .await;
+        assert!(result.is_err());
+    }
+
     async fn query_target_1(owner: Address, asset_id_a: AssetId, asset_id_b: AssetId) {
         let context = setup(owner, asset_id_a, asset_id_b).await;
 
@@ -2,16 +2,26 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    chain_config::fee_collection_contract::{
+        generate,
+        generate_factory,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
 };
 use fuel_core_client::client::{
     pagination::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        ReceiptFilter,
+        ReceiptType,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_types::{
@@ -20,7 +30,13 @@ use fuel_core_types::{
     fuel_types::canonical::Serialize,
     fuel_vm::*,
 };
+use futures::StreamExt;
+use rand::{
+    Rng,
+    SeedableRng,
+};
 use rstest::rstest;
+use std::time::Duration;
 
 const SEED: u64 = 2322;
 
@@ -112,6 +128,105 @@ fn key(i: u8) -> Bytes32 {
     )
 }
 
+#[tokio::test]
+async fn contract_storage_slots_are_queryable() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], None, None, None);
+    let slots: Vec<(Bytes32, Bytes32)> = (1..=3u8)
+        .map(|i| {
+            (
+                Bytes32::new([i; 32]),
+                Bytes32::new([i.saturating_mul(10); 32]),
+            )
+        })
+        .collect();
+    test_builder.contracts.get_mut(&contract_id).unwrap().state = Some(slots.clone());
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let value = client
+        .contract_storage_slot(&contract_id, &slots[1].0, None)
+        .await
+        .unwrap();
+    assert_eq!(value, Some(slots[1].1));
+    let missing = client
+        .contract_storage_slot(&contract_id, &Bytes32::new([9; 32]), None)
+        .await
+        .unwrap();
+    assert_eq!(missing, None);
+    let at_latest_height = client
+        .contract_storage_slot(&contract_id, &slots[0].0, Some(0u32.into()))
+        .await
+        .unwrap();
+    assert_eq!(at_latest_height, Some(slots[0].1));
+    client
+        .contract_storage_slot(&contract_id, &slots[0].0, Some(1u32.into()))
+        .await
+        .expect_err("The historical state is not available");
+
+    let page = client
+        .contract_storage_slots(
+            &contract_id,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let queried: Vec<_> = page
+        .results
+        .into_iter()
+        .map(|slot| (slot.key, slot.value))
+        .collect();
+    assert_eq!(queried, slots);
+}
+
+#[tokio::test]
+async fn export_contract_balances_streams_nonzero_balances_of_all_contracts() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, first) = test_builder.setup_contract(
+        vec![],
+        Some(vec![
+            (AssetId::new([1u8; 32]), 1000),
+            (AssetId::new([2u8; 32]), 0),
+        ]),
+        None,
+        None,
+    );
+    let (_, second) = test_builder.setup_contract(
+        vec![],
+        Some(vec![(AssetId::new([3u8; 32]), 700)]),
+        None,
+        None,
+    );
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let mut exported: Vec<_> = client
+        .export_contract_balances(0u32.into())
+        .map(|balance| balance.unwrap())
+        .collect()
+        .await;
+    exported.sort();
+
+    let mut expected = vec![
+        (first, AssetId::new([1u8; 32]), 1000),
+        (second, AssetId::new([3u8; 32]), 700),
+    ];
+    expected.sort();
+    assert_eq!(exported, expected);
+}
+
 #[tokio::test]
 async fn can_get_message_proof() {
     let config = Config::local_node();
@@ -294,3 +409,469 @@ async fn can_get_message_proof() {
     assert_eq!(log[1].rb().unwrap(), 1);
     assert_eq!(logd.data().unwrap(), db_data);
 }
+
+#[tokio::test]
+async fn asset_supply_history_tracks_minted_and_burned_amounts() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    // The contract mints `a` and burns `b` of the asset with the zero sub id.
+    let code = vec![
+        op::addi(0x10, RegId::FP, CallFrame::a_offset().try_into().unwrap()),
+        op::lw(0x10, 0x10, 0),
+        op::addi(0x11, RegId::FP, CallFrame::b_offset().try_into().unwrap()),
+        op::lw(0x11, 0x11, 0),
+        // Reserve the zeroed memory for the sub id.
+        op::move_(0x12, RegId::SP),
+        op::cfei(Bytes32::LEN.try_into().unwrap()),
+        op::mint(0x10, 0x12),
+        op::burn(0x11, 0x12),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let (_, contract_id) = test_builder.setup_contract(code, None, None, None);
+    let asset_id = contract_id.asset_id(&Bytes32::zeroed());
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let deltas = [(100, 0), (50, 30), (0, 70)];
+    for (minted, burned) in deltas {
+        let script = [
+            op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect::<Vec<u8>>();
+        let script_data = Call::new(contract_id, minted, burned).to_bytes();
+        let tx: Transaction = Transaction::script(
+            1_000_000,
+            script,
+            script_data,
+            policies::Policies::new().with_gas_price(0),
+            vec![
+                Input::contract(
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    contract_id,
+                ),
+                Input::coin_signed(
+                    rng.gen(),
+                    rng.gen(),
+                    1000,
+                    AssetId::BASE,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ),
+            ],
+            vec![Output::contract(0, Default::default(), Default::default())],
+            vec![vec![].into()],
+        )
+        .into();
+
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(matches!(status, TransactionStatus::Success { .. }));
+    }
+
+    let history = client
+        .asset_supply_history(&asset_id, 0.into(), 3.into())
+        .await
+        .unwrap();
+    let history = history
+        .into_iter()
+        .map(|delta| (delta.height, delta.minted, delta.burned))
+        .collect::<Vec<_>>();
+    assert_eq!(history, vec![(1, 100, 0), (2, 50, 30), (3, 0, 70)]);
+
+    let history = client
+        .asset_supply_history(&asset_id, 2.into(), 2.into())
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].height, 2);
+
+    let balance = client
+        .contract_balance(&contract_id, Some(&asset_id))
+        .await
+        .unwrap();
+    assert_eq!(balance, 50);
+}
+
+#[tokio::test]
+async fn subscribe_contract_balance_changes_receives_minted_amounts() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    // The contract mints `a` of the asset with the zero sub id.
+    let code = vec![
+        op::addi(0x10, RegId::FP, CallFrame::a_offset().try_into().unwrap()),
+        op::lw(0x10, 0x10, 0),
+        // Reserve the zeroed memory for the sub id.
+        op::move_(0x11, RegId::SP),
+        op::cfei(Bytes32::LEN.try_into().unwrap()),
+        op::mint(0x10, 0x11),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let (_, contract_id) = test_builder.setup_contract(code, None, None, None);
+    let asset_id = contract_id.asset_id(&Bytes32::zeroed());
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    // The subscription is established lazily on the first poll of the stream,
+    // so keep minting in the background until we receive the changes.
+    let minter = client.clone();
+    let minting = tokio::spawn(async move {
+        loop {
+            let script = [
+                op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+                op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ]
+            .into_iter()
+            .collect::<Vec<u8>>();
+            let script_data = Call::new(contract_id, 10, 0).to_bytes();
+            let tx: Transaction = Transaction::script(
+                1_000_000,
+                script,
+                script_data,
+                policies::Policies::new().with_gas_price(0),
+                vec![
+                    Input::contract(
+                        Default::default(),
+                        Default::default(),
+                        Default::default(),
+                        Default::default(),
+                        contract_id,
+                    ),
+                    Input::coin_signed(
+                        rng.gen(),
+                        rng.gen(),
+                        1000,
+                        AssetId::BASE,
+                        Default::default(),
+                        Default::default(),
+                        Default::default(),
+                    ),
+                ],
+                vec![Output::contract(0, Default::default(), Default::default())],
+                vec![vec![].into()],
+            )
+            .into();
+            let status = minter.submit_and_await_commit(&tx).await.unwrap();
+            assert!(matches!(status, TransactionStatus::Success { .. }));
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    let mut changes = client
+        .subscribe_contract_balance_changes(Some(&contract_id))
+        .await
+        .unwrap();
+    let mut received = vec![];
+    for _ in 0..2 {
+        let change = tokio::time::timeout(Duration::from_secs(5), changes.next())
+            .await
+            .expect("should receive the change before the timeout")
+            .expect("the stream should not end")
+            .unwrap();
+        received.push(change);
+    }
+    minting.abort();
+
+    let (first, second) = (&received[0], &received[1]);
+
+    assert_eq!(first.contract_id, contract_id);
+    assert_eq!(first.asset_id, asset_id);
+    assert_eq!(first.new, first.old + 10);
+    assert_eq!(second.block_height, first.block_height + 1);
+    assert_eq!(second.old, first.new);
+    assert_eq!(second.new, second.old + 10);
+}
+
+#[tokio::test]
+async fn subscribe_transactions_with_receipt_streams_only_matching_transactions() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    // The contract mints `a` of the asset with the zero sub id.
+    let code = vec![
+        op::addi(0x10, RegId::FP, CallFrame::a_offset().try_into().unwrap()),
+        op::lw(0x10, 0x10, 0),
+        // Reserve the zeroed memory for the sub id.
+        op::move_(0x11, RegId::SP),
+        op::cfei(Bytes32::LEN.try_into().unwrap()),
+        op::mint(0x10, 0x11),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let (_, contract_id) = test_builder.setup_contract(code, None, None, None);
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    // The subscription is established lazily on the first poll of the stream,
+    // so keep submitting the transactions in the background until we receive them.
+    let submitter = client.clone();
+    let submitting = tokio::spawn(async move {
+        loop {
+            // The transaction without receipts of the contract.
+            let unrelated = TransactionBuilder::script(
+                vec![op::ret(RegId::ONE)].into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(10_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+            let status = submitter.submit_and_await_commit(&unrelated).await.unwrap();
+            assert!(matches!(status, TransactionStatus::Success { .. }));
+
+            let script = [
+                op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+                op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ]
+            .into_iter()
+            .collect::<Vec<u8>>();
+            let script_data = Call::new(contract_id, 10, 0).to_bytes();
+            let tx: Transaction = Transaction::script(
+                1_000_000,
+                script,
+                script_data,
+                policies::Policies::new().with_gas_price(0),
+                vec![
+                    Input::contract(
+                        Default::default(),
+                        Default::default(),
+                        Default::default(),
+                        Default::default(),
+                        contract_id,
+                    ),
+                    Input::coin_signed(
+                        rng.gen(),
+                        rng.gen(),
+                        1000,
+                        AssetId::BASE,
+                        Default::default(),
+                        Default::default(),
+                        Default::default(),
+                    ),
+                ],
+                vec![Output::contract(0, Default::default(), Default::default())],
+                vec![vec![].into()],
+            )
+            .into();
+            let status = submitter.submit_and_await_commit(&tx).await.unwrap();
+            assert!(matches!(status, TransactionStatus::Success { .. }));
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    let mut transactions = client
+        .subscribe_transactions_with_receipt(ReceiptFilter {
+            receipt_type: Some(ReceiptType::Mint),
+            contract_id: Some(contract_id),
+        })
+        .await
+        .unwrap();
+    let mut received = vec![];
+    for _ in 0..2 {
+        let tx = tokio::time::timeout(Duration::from_secs(5), transactions.next())
+            .await
+            .expect("should receive the transaction before the timeout")
+            .expect("the stream should not end")
+            .unwrap();
+        received.push(tx);
+    }
+    submitting.abort();
+
+    for tx in received {
+        assert!(matches!(tx.status, TransactionStatus::Success { .. }));
+        assert!(tx.receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Mint { contract_id: id, .. } if id == &contract_id
+        )));
+    }
+}
+
+#[tokio::test]
+async fn contract_balance_series_is_computed_from_balance_changes() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    // The contract mints `a` and burns `b` of the asset with the zero sub id.
+    let code = vec![
+        op::addi(0x10, RegId::FP, CallFrame::a_offset().try_into().unwrap()),
+        op::lw(0x10, 0x10, 0),
+        op::addi(0x11, RegId::FP, CallFrame::b_offset().try_into().unwrap()),
+        op::lw(0x11, 0x11, 0),
+        // Reserve the zeroed memory for the sub id.
+        op::move_(0x12, RegId::SP),
+        op::cfei(Bytes32::LEN.try_into().unwrap()),
+        op::mint(0x10, 0x12),
+        op::burn(0x11, 0x12),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let (_, contract_id) = test_builder.setup_contract(code, None, None, None);
+    let asset_id = contract_id.asset_id(&Bytes32::zeroed());
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    // The second block doesn't change the balance.
+    let deltas = [(100, 0), (20, 20), (50, 30)];
+    for (minted, burned) in deltas {
+        let script = [
+            op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect::<Vec<u8>>();
+        let script_data = Call::new(contract_id, minted, burned).to_bytes();
+        let tx: Transaction = Transaction::script(
+            1_000_000,
+            script,
+            script_data,
+            policies::Policies::new().with_gas_price(0),
+            vec![
+                Input::contract(
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    contract_id,
+                ),
+                Input::coin_signed(
+                    rng.gen(),
+                    rng.gen(),
+                    1000,
+                    AssetId::BASE,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ),
+            ],
+            vec![Output::contract(0, Default::default(), Default::default())],
+            vec![vec![].into()],
+        )
+        .into();
+
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(matches!(status, TransactionStatus::Success { .. }));
+    }
+
+    // The heights above the latest block are skipped.
+    let series = client
+        .contract_balance_series(&contract_id, &asset_id, 0.into()..10.into())
+        .await
+        .unwrap();
+    assert_eq!(
+        series,
+        vec![
+            (0.into(), 0),
+            (1.into(), 100),
+            (2.into(), 100),
+            (3.into(), 120),
+        ]
+    );
+
+    let series = client
+        .contract_balance_series(&contract_id, &asset_id, 2.into()..3.into())
+        .await
+        .unwrap();
+    assert_eq!(series, vec![(2.into(), 100)]);
+
+    // The balance before the first change is taken from the change after the range.
+    let series = client
+        .contract_balance_series(&contract_id, &asset_id, 0.into()..1.into())
+        .await
+        .unwrap();
+    assert_eq!(series, vec![(0.into(), 0)]);
+
+    let series = client
+        .contract_balance_series(&contract_id, &asset_id, 3.into()..3.into())
+        .await
+        .unwrap();
+    assert!(series.is_empty());
+}
+
+#[tokio::test]
+async fn fee_collection_contracts_returns_all_instances_of_recipient() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(SEED);
+    let recipient: Address = rng.gen();
+    let factory = generate_factory(recipient);
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, first) = test_builder.setup_contract(factory.bytecode(), None, None, None);
+    let (_, second) = test_builder.setup_contract(factory.bytecode(), None, None, None);
+    // The contracts of the other recipients and the other contracts are skipped.
+    test_builder.setup_contract(generate(rng.gen()), None, None, None);
+    test_builder.setup_contract(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        None,
+        None,
+        None,
+    );
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let contracts = client.fee_collection_contracts(&recipient).await.unwrap();
+
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(contracts, expected);
+    assert!(client
+        .fee_collection_contracts(&rng.gen())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn verify_contract_code_compares_code_root() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let code: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let (_, contract_id) = test_builder.setup_contract(code.clone(), None, None, None);
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    assert!(client
+        .verify_contract_code(&contract_id, &code)
+        .await
+        .unwrap());
+    let other_code: Vec<u8> = vec![op::ret(RegId::ZERO)].into_iter().collect();
+    assert!(!client
+        .verify_contract_code(&contract_id, &other_code)
+        .await
+        .unwrap());
+    assert!(!client
+        .verify_contract_code(&ContractId::from([1; 32]), &code)
+        .await
+        .unwrap());
+}
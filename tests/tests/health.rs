@@ -3,9 +3,13 @@ use fuel_core::{
     service::{
         Config,
         FuelService,
+        ServiceTrait,
     },
 };
 use fuel_core_client::client::FuelClient;
+use fuel_core_poa::Trigger;
+use fuel_core_types::fuel_tx::Transaction;
+use std::time::Duration;
 
 #[tokio::test]
 async fn health() {
@@ -18,6 +22,75 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn node_health() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let health = client.node_health().await.unwrap();
+    assert!(health.up);
+    assert!(health.txpool_accepting);
+    assert!(health.producer_running);
+    assert_eq!(health.p2p_connected_peers, 0);
+    assert_eq!(health.db_height, 0);
+}
+
+#[tokio::test]
+async fn node_health_of_read_only_node() {
+    let mut config = Config::local_node();
+    config.read_only = true;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let health = client.node_health().await.unwrap();
+    assert!(health.up);
+    assert!(!health.txpool_accepting);
+    assert!(!health.producer_running);
+}
+
+#[tokio::test]
+async fn shutdown_gracefully_commits_accepted_transactions() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default_test_tx();
+    client.submit(&tx).await.unwrap();
+
+    let drained = srv
+        .shutdown_gracefully(Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert!(drained);
+    assert_eq!(srv.shared.txpool.pending_number(), 0);
+    assert!(srv.state().stopped());
+}
+
+#[tokio::test]
+async fn shutdown_gracefully_times_out_if_transactions_are_not_committed() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default_test_tx();
+    client.submit(&tx).await.unwrap();
+
+    let drained = srv
+        .shutdown_gracefully(Duration::from_millis(100))
+        .await
+        .unwrap();
+
+    assert!(!drained);
+    assert!(srv.state().stopped());
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
@@ -26,7 +99,6 @@ async fn can_restart_node() {
 
     // start node once
     {
-        use fuel_core::service::ServiceTrait;
         let database = Database::open(tmp_dir.path(), None).unwrap();
         let first_startup = FuelService::from_database(database, Config::local_node())
             .await
@@ -3,9 +3,16 @@ use fuel_core::service::{
     FuelService,
 };
 use fuel_core_client::client::{
-    types::NodeInfo,
+    types::{
+        NodeInfo,
+        ProductionConfig,
+    },
     FuelClient,
 };
+use fuel_core_poa::Trigger;
+use fuel_core_types::fuel_types::ContractId;
+use rstest::rstest;
+use std::time::Duration;
 
 #[tokio::test]
 async fn node_info() {
@@ -28,3 +35,92 @@ async fn node_info() {
     assert_eq!(max_depth, node_config.txpool.max_depth as u64);
     assert_eq!(max_tx, node_config.txpool.max_tx as u64);
 }
+
+#[tokio::test]
+async fn service_config_reflects_overrides() {
+    let mut node_config = Config::local_node();
+    node_config.txpool.max_tx = 123;
+    node_config.txpool.min_gas_price = 7;
+    node_config.txpool.transaction_ttl = Duration::from_secs(42);
+    // The node resolves the inconsistent `utxo_validation` of the `TxPool`.
+    node_config.txpool.utxo_validation = !node_config.utxo_validation;
+    let coinbase_recipient = ContractId::from([1; 32]);
+    node_config.block_producer.coinbase_recipient = Some(coinbase_recipient);
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let service_config = client.service_config().await.unwrap();
+
+    assert_eq!(service_config.txpool.max_tx, 123);
+    assert_eq!(service_config.txpool.min_gas_price, 7);
+    assert_eq!(
+        service_config.txpool.transaction_ttl,
+        Duration::from_secs(42)
+    );
+    assert_eq!(
+        service_config.txpool.utxo_validation,
+        node_config.utxo_validation
+    );
+    assert_eq!(
+        service_config.block_producer.coinbase_recipient,
+        Some(coinbase_recipient)
+    );
+    assert!(service_config.relayer.is_none());
+}
+
+#[rstest]
+#[case::instant(Trigger::Instant, ProductionConfig::Instant)]
+#[case::interval(
+    Trigger::Interval { block_time: Duration::from_secs(2) },
+    ProductionConfig::Interval { block_time: Duration::from_secs(2) },
+)]
+#[case::manual(Trigger::Never, ProductionConfig::Manual)]
+#[tokio::test]
+async fn block_production_config_reflects_trigger(
+    #[case] trigger: Trigger,
+    #[case] expected: ProductionConfig,
+) {
+    let mut node_config = Config::local_node();
+    node_config.block_production = trigger;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let config = client.block_production_config().await.unwrap();
+
+    assert_eq!(config, expected);
+}
+
+#[tokio::test]
+async fn producer_metrics_count_produced_blocks() {
+    let mut config = Config::local_node();
+    config.block_producer.metrics = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    // The metrics are global for the process, so other tests may produce blocks too.
+    let before = client
+        .producer_metrics()
+        .await
+        .unwrap()
+        .expect("Metrics are enabled");
+
+    client.produce_blocks(2, None).await.unwrap();
+
+    let after = client
+        .producer_metrics()
+        .await
+        .unwrap()
+        .expect("Metrics are enabled");
+    assert!(after.blocks_produced >= before.blocks_produced + 2);
+    assert!(after.last_production_time.is_some());
+    assert!(after.last_production_time >= before.last_production_time);
+}
+
+#[tokio::test]
+async fn producer_metrics_are_none_when_metrics_are_disabled() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(1, None).await.unwrap();
+
+    assert_eq!(client.producer_metrics().await.unwrap(), None);
+}
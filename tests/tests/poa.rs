@@ -9,6 +9,7 @@ use fuel_core_client::client::{
     types::TransactionStatus,
     FuelClient,
 };
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
     blockchain::{
         consensus::Consensus,
@@ -22,7 +23,10 @@ use rand::{
     rngs::StdRng,
     SeedableRng,
 };
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    time::Duration,
+};
 
 #[tokio::test]
 async fn can_get_sealed_block_from_poa_produced_block() {
@@ -85,6 +89,35 @@ async fn can_get_sealed_block_from_poa_produced_block() {
         .expect("failed to verify signature");
 }
 
+#[tokio::test]
+async fn read_only_node_stops_producing_blocks() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Interval {
+        block_time: Duration::from_millis(100),
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    srv.shared
+        .block_importer
+        .block_importer
+        .enter_read_only_mode();
+    // The block committed while the mode was switched is not counted.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let height = client.chain_info().await.unwrap().latest_block_height;
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    assert_eq!(
+        client.chain_info().await.unwrap().latest_block_height,
+        height
+    );
+    client
+        .produce_blocks(1, None)
+        .await
+        .expect_err("The read-only node should not produce blocks");
+}
+
 #[cfg(feature = "p2p")]
 mod p2p {
     use super::*;
@@ -1,5 +1,7 @@
 use fuel_core::{
     chain_config::{
+        fee_collection_contract,
+        ChainConfig,
         CoinConfig,
         ContractConfig,
         MessageConfig,
@@ -9,10 +11,24 @@ use fuel_core::{
     service::{
         Config,
         FuelService,
+        GenesisState,
     },
 };
+use fuel_core_client::client::{
+    types::TransactionStatus,
+    FuelClient,
+};
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        Contract,
+        Output,
+        TransactionBuilder,
+    },
     fuel_types::{
         BlockHeight,
         Nonce,
@@ -116,3 +132,91 @@ async fn snapshot_state_config() {
 
     assert_eq!(state_conf.messages, starting_state.messages)
 }
+
+#[tokio::test]
+async fn node_from_snapshot_restores_balances_and_tip() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let owner: Address = rng.gen();
+    let node = FuelService::new_node(Config::local_node_in_memory())
+        .await
+        .unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // Given
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(10_000)
+    .add_random_fee_input()
+    .add_output(Output::coin(owner, 100, AssetId::BASE))
+    .finalize_as_transaction();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let tip = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    // When
+    let snapshot = node.snapshot_state().unwrap();
+    let restored = FuelService::new_node(Config::from_snapshot(snapshot))
+        .await
+        .unwrap();
+    let restored_client = FuelClient::from(restored.bound_address);
+
+    // Then
+    let restored_tip = restored_client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    assert_eq!(restored_tip, tip);
+    assert_eq!(restored_client.balance(&owner, None).await.unwrap(), 100);
+}
+
+#[tokio::test]
+async fn node_from_exported_genesis_has_fee_collection_contract_deployed() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let recipient: Address = rng.gen();
+    let salt: Salt = rng.gen();
+    let bytecode = fee_collection_contract::generate(recipient);
+    let contract = Contract::from(bytecode.clone());
+    let contract_id =
+        contract.id(&salt, &contract.root(), &Contract::default_state_root());
+
+    // Given
+    let mut config = Config::local_node_in_memory();
+    config.chain_conf =
+        ChainConfig::local_testnet().with_contract(bytecode, salt, vec![]);
+    config.block_producer.coinbase_recipient = Some(contract_id);
+    let json = serde_json::to_string(&config.export_genesis()).unwrap();
+
+    // When
+    let genesis: GenesisState = serde_json::from_str(&json).unwrap();
+    assert_eq!(genesis, config.export_genesis());
+    let node = FuelService::new_node(Config::from_genesis(genesis))
+        .await
+        .unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // Then
+    assert!(client.contract(&contract_id).await.unwrap().is_some());
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_price(1)
+    .script_gas_limit(10_000)
+    .add_random_fee_input()
+    .finalize_as_transaction();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let balance = client.contract_balance(&contract_id, None).await.unwrap();
+    assert!(balance > 0);
+}
@@ -14,9 +14,16 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        CoinOutputType,
+        FeeBreakdown,
+        IdempotentSubmission,
+        ScriptResult,
+        TransactionStatus,
+    },
     FuelClient,
 };
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
     blockchain::{
         block::PartialFuelBlock,
@@ -26,8 +33,17 @@ use fuel_core_types::{
         },
     },
     fuel_asm::*,
-    fuel_tx::*,
-    fuel_types::ChainId,
+    fuel_crypto::SecretKey,
+    fuel_merkle,
+    fuel_tx::{
+        field::ScriptGasLimit,
+        *,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+        ChainId,
+    },
     services::executor::ExecutionBlock,
     tai64::Tai64,
 };
@@ -110,6 +126,40 @@ async fn dry_run_script() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_fails_if_gas_limit_exceeds_max_dry_run_gas() {
+    let mut config = Config::local_node();
+    config.block_producer.max_dry_run_gas = Some(10_000);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The script loops forever, so it would consume all the gas of its limit.
+    let script = [op::jmp(RegId::ZERO)];
+    let heavy_tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let err = client.dry_run(&heavy_tx).await.unwrap_err();
+    assert!(
+        err.to_string().contains("exceeds the maximum dry run gas"),
+        "unexpected err {err:?}"
+    );
+
+    // The same script within the cap runs out of gas instead.
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let receipts = client.dry_run(&tx).await.unwrap();
+    assert!(receipts.iter().any(|receipt| matches!(
+        receipt,
+        Receipt::Panic {
+            reason, ..
+        } if *reason.reason() == PanicReason::OutOfGas
+    )));
+}
+
 #[tokio::test]
 async fn dry_run_create() {
     let mut rng = StdRng::seed_from_u64(2322);
@@ -139,6 +189,405 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_with_fees_matches_the_fee_paid_by_the_transaction() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let amount = 1_000_000;
+    let script = [op::noop(), op::noop(), op::ret(RegId::ONE)];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(10_000)
+        .gas_price(1)
+        .add_unsigned_coin_input(
+            secret,
+            rng.gen(),
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .finalize_as_transaction();
+
+    let (receipts, fees) = client.dry_run_with_fees(&tx).await.unwrap();
+    assert!(!receipts.is_empty());
+    assert!(fees.gas_used > 0);
+    assert!(fees.min_gas <= fees.max_gas);
+    assert!(fees.total_fee > 0);
+
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // The change output receives everything that wasn't paid as the fee.
+    let coins = client
+        .coins(
+            &owner,
+            Some(&AssetId::BASE),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results;
+    assert_eq!(coins.len(), 1);
+    assert_eq!(coins[0].amount, amount - fees.total_fee);
+}
+
+#[tokio::test]
+async fn estimate_transaction_cost_bounds_the_fee_of_the_transaction() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let script = [op::noop(), op::noop(), op::ret(RegId::ONE)];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(10_000)
+        .gas_price(1)
+        .add_unsigned_coin_input(
+            secret,
+            rng.gen(),
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .finalize_as_transaction();
+
+    let cost = client.estimate_transaction_cost(&tx).await.unwrap();
+    let (_, fees) = client.dry_run_with_fees(&tx).await.unwrap();
+
+    assert_eq!(cost.gas_used, fees.gas_used);
+    assert_eq!(cost.max_gas, fees.max_gas);
+    assert!(cost.min_fee <= fees.total_fee);
+    assert!(fees.total_fee <= cost.max_fee);
+}
+
+#[tokio::test]
+async fn estimate_gas_limit_is_enough_to_execute_the_script() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::addi(0x11, RegId::ZERO, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let mut tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1)
+        .add_random_fee_input()
+        .finalize();
+
+    let gas_limit = client.estimate_gas_limit(&tx.clone().into()).await.unwrap();
+    assert!(gas_limit > 1);
+
+    *tx.script_gas_limit_mut() = gas_limit;
+    let status = client.submit_and_await_commit(&tx.into()).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn estimate_gas_limit_is_clamped_to_max_dry_run_gas() {
+    const MAX_DRY_RUN_GAS: u64 = 10_000;
+    let mut config = Config::local_node();
+    config.block_producer.max_dry_run_gas = Some(MAX_DRY_RUN_GAS);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let node_info = client.node_info().await.unwrap();
+    assert_eq!(node_info.max_dry_run_gas, Some(MAX_DRY_RUN_GAS));
+
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::addi(0x11, RegId::ZERO, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let gas_limit = client.estimate_gas_limit(&tx).await.unwrap();
+    assert!(gas_limit > 1);
+    assert!(gas_limit <= MAX_DRY_RUN_GAS);
+}
+
+#[tokio::test]
+async fn success_status_contains_assigned_variable_outputs() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let recipient = Address::from([7u8; 32]);
+    let amount: u32 = 1234;
+    let script = [
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, Address::LEN as u16),
+        op::movi(0x12, amount),
+        op::tro(0x10, RegId::ZERO, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let script_data: Vec<u8> = recipient
+        .iter()
+        .chain(AssetId::BASE.iter())
+        .copied()
+        .collect();
+    let tx = TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .add_output(Output::variable(Default::default(), 0, Default::default()))
+        .finalize();
+
+    let status = client.submit_and_await_commit(&tx.into()).await.unwrap();
+
+    match status {
+        TransactionStatus::Success {
+            variable_outputs, ..
+        } => assert_eq!(
+            variable_outputs,
+            vec![Output::variable(
+                recipient,
+                u64::from(amount),
+                AssetId::BASE
+            )]
+        ),
+        status => panic!("Expected success status, got: {status:?}"),
+    }
+}
+
+#[tokio::test]
+async fn execution_status_contains_gas_used_and_total_fee() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let params = client.chain_info().await.unwrap().consensus_parameters;
+
+    let successful: Transaction =
+        TransactionBuilder::script([op::ret(RegId::ONE)].into_iter().collect(), vec![])
+            .gas_price(1)
+            .script_gas_limit(10_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+    let failed: Transaction =
+        TransactionBuilder::script([op::rvrt(RegId::ONE)].into_iter().collect(), vec![])
+            .gas_price(1)
+            .script_gas_limit(10_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+
+    for tx in [successful, failed] {
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        let receipts = client
+            .receipts(&tx.id(&params.chain_id))
+            .await
+            .unwrap()
+            .unwrap();
+        let expected = FeeBreakdown::new(&tx, &receipts, &params).unwrap();
+
+        let (gas_used, total_fee) = match status {
+            TransactionStatus::Success {
+                gas_used,
+                total_fee,
+                ..
+            }
+            | TransactionStatus::Failure {
+                gas_used,
+                total_fee,
+                ..
+            } => (gas_used, total_fee),
+            status => panic!("Expected the execution status, got: {status:?}"),
+        };
+        assert!(gas_used > 0);
+        assert_eq!(gas_used, expected.gas_used);
+        assert!(total_fee > 0);
+        assert_eq!(total_fee, expected.total_fee);
+    }
+}
+
+#[tokio::test]
+async fn transaction_outputs_contain_resolved_variable_outputs() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let recipient = Address::from([7u8; 32]);
+    let amount: u32 = 1234;
+    let script = [
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, Address::LEN as u16),
+        op::movi(0x12, amount),
+        op::tro(0x10, RegId::ZERO, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let script_data: Vec<u8> = recipient
+        .iter()
+        .chain(AssetId::BASE.iter())
+        .copied()
+        .collect();
+    let tx = TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .add_output(Output::variable(Default::default(), 0, Default::default()))
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let outputs = client
+        .transaction_outputs(&tx.id(&ChainId::default()))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        outputs,
+        vec![Output::variable(
+            recipient,
+            u64::from(amount),
+            AssetId::BASE
+        )]
+    );
+}
+
+#[tokio::test]
+async fn transaction_location_returns_height_and_index_in_block() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let first = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_random_fee_input()
+    .finalize_as_transaction();
+    let second = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_random_fee_input()
+    .finalize_as_transaction();
+    let first_id = first.id(&ChainId::default());
+    let second_id = second.id(&ChainId::default());
+
+    // Both transactions are included into the same block.
+    client.submit(&first).await.unwrap();
+    client.submit(&second).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    let index_in_block = |tx_id: &TxId| {
+        block
+            .transactions
+            .iter()
+            .position(|id| id == tx_id)
+            .unwrap() as u32
+    };
+
+    let first_location = client.transaction_location(&first_id).await.unwrap();
+    let second_location = client.transaction_location(&second_id).await.unwrap();
+    let unknown_location = client.transaction_location(&TxId::zeroed()).await.unwrap();
+
+    assert_eq!(
+        first_location,
+        Some((BlockHeight::new(1), index_in_block(&first_id)))
+    );
+    assert_eq!(
+        second_location,
+        Some((BlockHeight::new(1), index_in_block(&second_id)))
+    );
+    let mut indexes = vec![first_location.unwrap().1, second_location.unwrap().1];
+    indexes.sort();
+    assert_eq!(indexes, vec![0, 1]);
+    assert_eq!(unknown_location, None);
+}
+
+#[tokio::test]
+async fn dry_run_with_diagnostics_reports_missing_variable_output() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, Address::LEN as u16),
+        op::movi(0x12, 1234),
+        op::tro(0x10, RegId::ZERO, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let script_data: Vec<u8> = Address::from([7u8; 32])
+        .iter()
+        .chain(AssetId::BASE.iter())
+        .copied()
+        .collect();
+    let tx = TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let (_, diagnostics) = client.dry_run_with_diagnostics(&tx).await.unwrap();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].reason, PanicReason::OutputNotFound);
+    assert!(
+        diagnostics[0]
+            .to_string()
+            .ends_with("add a variable output"),
+        "{}",
+        diagnostics[0]
+    );
+}
+
+#[tokio::test]
+async fn transaction_receipts_returns_receipts_of_committed_tx() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let receipts = client
+        .transaction_receipts(&tx.id(&ChainId::default()))
+        .await
+        .unwrap()
+        .expect("The transaction is committed");
+
+    assert!(matches!(receipts[0], Receipt::Log { ra: 0xca, .. }));
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn transaction_receipts_returns_none_for_unknown_tx() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let receipts = client
+        .transaction_receipts(&TxId::from([1u8; 32]))
+        .await
+        .unwrap();
+
+    assert_eq!(receipts, None);
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -177,6 +626,372 @@ async fn submit() {
     assert_eq!(tx.id(&ChainId::default()), ret_tx.id(&ChainId::default()));
 }
 
+#[tokio::test]
+async fn submit_with_idempotency_rejects_duplicate_submissions() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let original = create_mock_tx(1);
+    let original_id = original.id(&ChainId::default());
+    let retry = create_mock_tx(2);
+    let retry_id = retry.id(&ChainId::default());
+
+    let submission = client
+        .submit_with_idempotency(&original, [1; 32])
+        .await
+        .unwrap();
+    assert_eq!(submission, IdempotentSubmission::Submitted(original_id));
+
+    // The retry of the same logical submission is rejected and isn't inserted.
+    let submission = client
+        .submit_with_idempotency(&retry, [1; 32])
+        .await
+        .unwrap();
+    assert_eq!(
+        submission,
+        IdempotentSubmission::DuplicateSubmission {
+            original_tx_id: original_id
+        }
+    );
+    let status = client.transaction_status(&retry_id).await;
+    assert!(status.is_err());
+
+    // The key of the failed submission can be reused.
+    client
+        .submit_with_idempotency(&original, [2; 32])
+        .await
+        .expect_err("The transaction is already in the `TxPool`");
+    let submission = client
+        .submit_with_idempotency(&retry, [2; 32])
+        .await
+        .unwrap();
+    assert_eq!(submission, IdempotentSubmission::Submitted(retry_id));
+}
+
+#[tokio::test]
+async fn await_commits_returns_statuses_in_order_of_ids() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut tx_ids = vec![];
+    for i in 0..10 {
+        let tx = create_mock_tx(i);
+        tx_ids.push(client.submit(&tx).await.unwrap());
+    }
+
+    let statuses = client
+        .await_commits(&tx_ids, std::time::Duration::from_secs(10))
+        .await
+        .unwrap();
+
+    assert_eq!(statuses.len(), tx_ids.len());
+    for (tx_id, status) in tx_ids.iter().zip(statuses) {
+        let expected = client.transaction_status(tx_id).await.unwrap();
+        match (status, expected) {
+            (
+                TransactionStatus::Success { block_id, .. },
+                TransactionStatus::Success {
+                    block_id: expected_block_id,
+                    ..
+                },
+            ) => assert_eq!(block_id, expected_block_id),
+            (status, _) => panic!("Unexpected status {status:?}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn await_commits_fails_if_transactions_are_not_committed_in_time() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx_id = client.submit(&create_mock_tx(1)).await.unwrap();
+
+    let err = client
+        .await_commits(&[tx_id], std::time::Duration::from_millis(500))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+}
+
+#[tokio::test]
+async fn submit_is_rejected_by_read_only_node() {
+    let mut config = Config::local_node();
+    config.read_only = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = create_mock_tx(1);
+    let err = client.submit(&tx).await.unwrap_err();
+    assert!(err.to_string().contains("ReadOnlyNode"), "{err}");
+    let err = client.submit_and_await_commit(&tx).await.unwrap_err();
+    assert!(err.to_string().contains("ReadOnlyNode"), "{err}");
+
+    // The manual block production is disabled, but the queries still work.
+    let err = client.produce_blocks(1, None).await.unwrap_err();
+    assert!(err.to_string().contains("ReadOnlyNode"), "{err}");
+    let chain_info = client.chain_info().await.unwrap();
+    assert_eq!(chain_info.latest_block.header.height, 0);
+}
+
+#[tokio::test]
+async fn success_status_exposes_script_return_value() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    for (register, expected) in [(RegId::ONE, 1), (RegId::ZERO, 0)] {
+        let tx =
+            TransactionBuilder::script(op::ret(register).to_bytes().to_vec(), vec![])
+                .script_gas_limit(1_000_000)
+                .add_random_fee_input()
+                .finalize_as_transaction();
+
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+
+        match status {
+            TransactionStatus::Success { script_result, .. } => assert_eq!(
+                script_result,
+                ScriptResult {
+                    return_value: Some(expected),
+                    reverted: false,
+                    transferred: false,
+                }
+            ),
+            status => panic!("Unexpected status {status:?}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn transaction_inclusion_proof_verifies_against_transactions_root() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = op::ret(RegId::ONE).to_bytes().to_vec();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+
+    // the transaction is unknown before the submission
+    assert!(client
+        .transaction_inclusion_proof(&tx_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let proof = client
+        .transaction_inclusion_proof(&tx_id)
+        .await
+        .unwrap()
+        .expect("The transaction is included in the block");
+    // the root is calculated over the transactions as they are stored in the block
+    let included_tx = client
+        .transaction(&tx_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .transaction;
+    let root: [u8; 32] = proof.block_header.transactions_root.into();
+    let proof_set: Vec<[u8; 32]> =
+        proof.proof.proof_set.into_iter().map(Into::into).collect();
+
+    assert!(fuel_merkle::binary::verify(
+        &root,
+        &included_tx.to_bytes(),
+        &proof_set,
+        proof.proof.proof_index,
+        proof.block_header.transactions_count,
+    ));
+}
+
+#[tokio::test]
+async fn transaction_created_utxos_match_coins() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let owner: Address = rng.gen();
+    let recipient: Address = rng.gen();
+    let tx: Transaction = Transaction::script(
+        1_000_000,
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+        vec![],
+        policies::Policies::new().with_gas_price(0),
+        vec![Input::coin_signed(
+            rng.gen(),
+            owner,
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )],
+        vec![
+            Output::coin(recipient, 100, AssetId::BASE),
+            Output::change(owner, 0, AssetId::BASE),
+        ],
+        vec![vec![].into()],
+    )
+    .into();
+    let tx_id = tx.id(&ChainId::default());
+
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let utxos = client
+        .transaction_created_utxos(&tx_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(utxos.len(), 2);
+    assert_eq!(utxos[0].output_type, CoinOutputType::Coin);
+    assert_eq!(utxos[0].owner, recipient);
+    assert_eq!(utxos[0].amount, 100);
+    assert_eq!(utxos[1].output_type, CoinOutputType::Change);
+    assert_eq!(utxos[1].owner, owner);
+    assert_eq!(utxos[1].amount, 900);
+
+    for utxo in utxos {
+        let coin = client.coin(&utxo.utxo_id).await.unwrap().unwrap();
+        assert_eq!(coin.owner, utxo.owner);
+        assert_eq!(coin.amount, utxo.amount);
+        assert_eq!(coin.asset_id, utxo.asset_id);
+    }
+
+    // Unknown transactions don't have created UTXOs.
+    let unknown = client.transaction_created_utxos(&rng.gen()).await.unwrap();
+    assert!(unknown.is_none());
+}
+
+#[tokio::test]
+async fn outputs_as_coins_skips_spent_and_zero_amount_outputs() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let owner: Address = rng.gen();
+    let recipient: Address = rng.gen();
+    let tx: Transaction = Transaction::script(
+        1_000_000,
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+        vec![],
+        policies::Policies::new().with_gas_price(0),
+        vec![Input::coin_signed(
+            rng.gen(),
+            owner,
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )],
+        vec![
+            Output::coin(recipient, 0, AssetId::BASE),
+            Output::coin(recipient, 100, AssetId::BASE),
+            Output::change(owner, 0, AssetId::BASE),
+        ],
+        vec![vec![].into()],
+    )
+    .into();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // The zero amount output doesn't create a coin.
+    let coins = client.outputs_as_coins(&tx_id).await.unwrap();
+    let utxo_ids = coins.iter().map(|coin| coin.utxo_id).collect_vec();
+    assert_eq!(utxo_ids, vec![UtxoId::new(tx_id, 1), UtxoId::new(tx_id, 2)]);
+    assert_eq!(coins[0].amount, 100);
+    assert_eq!(coins[1].amount, 900);
+
+    // Spend the coin of the recipient.
+    let spend: Transaction = Transaction::script(
+        1_000_000,
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+        vec![],
+        policies::Policies::new().with_gas_price(0),
+        vec![Input::coin_signed(
+            UtxoId::new(tx_id, 1),
+            recipient,
+            100,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )],
+        vec![Output::change(recipient, 0, AssetId::BASE)],
+        vec![vec![].into()],
+    )
+    .into();
+    client.submit_and_await_commit(&spend).await.unwrap();
+
+    // The spent coin is not returned anymore.
+    let coins = client.outputs_as_coins(&tx_id).await.unwrap();
+    assert_eq!(coins.len(), 1);
+    assert_eq!(coins[0].utxo_id, UtxoId::new(tx_id, 2));
+    assert_eq!(coins[0].amount, 900);
+
+    // Unknown transactions are reported as not found.
+    let err = client.outputs_as_coins(&rng.gen()).await.unwrap_err();
+    assert_eq!(err.kind(), NotFound);
+}
+
+#[tokio::test]
+async fn transaction_fee_payer_is_owner_of_base_asset_input() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let other: Address = rng.gen();
+    let payer: Address = rng.gen();
+    let tx: Transaction = Transaction::script(
+        1_000_000,
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+        vec![],
+        policies::Policies::new().with_gas_price(0),
+        vec![
+            // The first input is not the base asset, so it doesn't pay the fee.
+            Input::coin_signed(
+                rng.gen(),
+                other,
+                1000,
+                rng.gen(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+            Input::coin_signed(
+                rng.gen(),
+                payer,
+                1000,
+                AssetId::BASE,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+        ],
+        vec![],
+        vec![vec![].into()],
+    )
+    .into();
+    let tx_id = tx.id(&ChainId::default());
+
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let fee_payer = client.transaction_fee_payer(&tx_id).await.unwrap();
+    assert_eq!(fee_payer, Some(payer));
+
+    // Unknown transactions don't have the fee payer.
+    let unknown = client.transaction_fee_payer(&rng.gen()).await.unwrap();
+    assert!(unknown.is_none());
+}
+
 #[ignore]
 #[tokio::test]
 async fn transaction_status_submitted() {
@@ -418,6 +1233,63 @@ async fn get_transactions_by_owner_returns_correct_number_of_results(
     assert_eq!(transactions_forward.len(), 5);
 }
 
+#[tokio::test]
+async fn get_transactions_by_owner_paged_walks_all_transactions_once() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let mut expected = vec![];
+    for amount in 1..=5 {
+        expected.push(context.transfer(alice, bob, amount).await.unwrap());
+    }
+
+    let client = context.client.clone();
+    let mut walked = vec![];
+    let mut cursor = None;
+    loop {
+        let page = client
+            .transactions_by_owner_paged(&bob, cursor.clone(), 2)
+            .await
+            .unwrap();
+        walked.extend(
+            page.results
+                .iter()
+                .map(|tx| tx.transaction.id(&ChainId::default())),
+        );
+        cursor = page.cursor;
+
+        // A new block arrives in the middle of the walk.
+        if walked.len() == 2 {
+            expected.push(context.transfer(alice, bob, 6).await.unwrap());
+        }
+
+        if !page.has_next_page {
+            break
+        }
+    }
+    assert_eq!(walked, expected);
+
+    // The empty page keeps the cursor, so the walk continues from the same place.
+    let page = client
+        .transactions_by_owner_paged(&bob, cursor.clone(), 2)
+        .await
+        .unwrap();
+    assert!(page.results.is_empty());
+    assert_eq!(page.cursor, cursor);
+    let tx_id = context.transfer(alice, bob, 7).await.unwrap();
+    let page = client
+        .transactions_by_owner_paged(&bob, page.cursor, 2)
+        .await
+        .unwrap();
+    let ids = page
+        .results
+        .iter()
+        .map(|tx| tx.transaction.id(&ChainId::default()))
+        .collect_vec();
+    assert_eq!(ids, vec![tx_id]);
+}
+
 #[test_case::test_case(PageDirection::Forward; "forward")]
 #[test_case::test_case(PageDirection::Backward; "backward")]
 #[tokio::test]
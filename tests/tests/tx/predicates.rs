@@ -1,6 +1,24 @@
 // Tests related to the predicate execution feature
 
 use crate::helpers::TestSetupBuilder;
+use fuel_core::{
+    chain_config::{
+        CoinConfig,
+        StateConfig,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    types::{
+        ResourcesExhausted,
+        SpendQuery,
+        TransactionStatus,
+    },
+    FuelClient,
+};
 use fuel_core_types::{
     fuel_asm::*,
     fuel_tx::{
@@ -164,3 +182,90 @@ async fn transaction_with_predicates_that_exhaust_gas_limit_are_rejected() {
         "got unexpected error {err}"
     )
 }
+
+async fn predicate_owned_coins_node(owner: Address, amounts: &[Word]) -> FuelService {
+    let mut config = Config::local_node();
+    config.utxo_validation = true;
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(
+            amounts
+                .iter()
+                .map(|amount| CoinConfig {
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                    maturity: None,
+                    owner,
+                    amount: *amount,
+                    asset_id: AssetId::BASE,
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    });
+    FuelService::new_node(config).await.unwrap()
+}
+
+#[tokio::test]
+async fn transaction_funded_by_coin_inputs_to_spend_of_predicate_is_executed() {
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let srv = predicate_owned_coins_node(owner, &[100, 200, 300]).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    let inputs = client
+        .coin_inputs_to_spend(&owner, SpendQuery::new(vec![(AssetId::BASE, 250)]))
+        .await
+        .unwrap();
+    assert!(inputs.iter().map(|input| input.amount()).sum::<Word>() >= 250);
+
+    let mut builder = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+        Default::default(),
+    );
+    builder.script_gas_limit(10_000);
+    for input in inputs {
+        builder.add_input(input.into_predicate_input(predicate.clone(), vec![]));
+    }
+    builder.add_output(Output::change(owner, 0, AssetId::BASE));
+    let mut tx = builder.finalize_as_transaction();
+    tx.estimate_predicates(&CheckPredicateParams::default())
+        .expect("Predicate check failed");
+
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "{status:?}"
+    );
+}
+
+#[tokio::test]
+async fn coin_inputs_to_spend_returns_resources_exhausted_error() {
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let srv = predicate_owned_coins_node(owner, &[100, 200, 300]).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    let not_enough_coins = client
+        .coin_inputs_to_spend(&owner, SpendQuery::new(vec![(AssetId::BASE, 601)]))
+        .await
+        .unwrap_err();
+    let max_inputs_reached = client
+        .coin_inputs_to_spend(
+            &owner,
+            SpendQuery::new(vec![(AssetId::BASE, 600)]).with_max_inputs(2),
+        )
+        .await
+        .unwrap_err();
+
+    for err in [not_enough_coins, max_inputs_reached] {
+        assert!(
+            err.get_ref()
+                .map(|inner| inner.is::<ResourcesExhausted>())
+                .unwrap_or(false),
+            "{err:?}"
+        );
+    }
+}
@@ -228,3 +228,60 @@ fn submit_and_await_model(tx: &Transaction) -> bool {
         _ => true,
     }
 }
+
+#[tokio::test]
+async fn subscribe_txn_status_with_keepalive() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client =
+        FuelClient::from(srv.bound_address).with_keepalive(Duration::from_secs(30));
+
+    let tx = Transaction::default_test_tx();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+
+    assert!(
+        matches!(
+            status,
+            fuel_core_client::client::types::TransactionStatus::Success { .. }
+        ),
+        "{status:?}"
+    );
+}
+
+#[tokio::test]
+async fn subscription_with_keepalive_reconnects_after_idle_period() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    // The node sends the keep-alive messages only every 15 seconds, so the connection
+    // is idle for longer than the timeout and the client has to reconnect.
+    let keepalive = Duration::from_millis(500);
+    let client = FuelClient::from(srv.bound_address).with_keepalive(keepalive);
+
+    let tx = Transaction::default_test_tx();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit(&tx).await.unwrap();
+    let mut statuses = client.subscribe_transaction_status(&tx_id).await.unwrap();
+
+    tokio::time::sleep(keepalive * 4).await;
+    client.produce_blocks(1, None).await.unwrap();
+
+    // The errors signal the reconnections, after which the subscription goes on.
+    let mut reconnections = 0;
+    loop {
+        let status = tokio::time::timeout(Duration::from_secs(30), statuses.next())
+            .await
+            .expect("The subscription should not hang")
+            .expect("The subscription should not end before the commit");
+        match status {
+            Ok(fuel_core_client::client::types::TransactionStatus::Success {
+                ..
+            }) => break,
+            Ok(_) => {}
+            Err(_) => reconnections += 1,
+        }
+    }
+    assert!(
+        reconnections > 0,
+        "The idle connection should be reconnected"
+    );
+}
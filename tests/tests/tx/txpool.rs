@@ -4,11 +4,25 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::{
+        ResubmitPolicy,
+        SqueezedOutKind,
+        TransactionStatus,
+    },
+    FuelClient,
+};
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
     fuel_tx::*,
+    fuel_types::ChainId,
 };
 use itertools::Itertools;
 use rand::{
@@ -75,3 +89,465 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn transaction_dependencies_returns_all_pending_ancestors() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let amount = 1000;
+
+    // Builds a transaction that spends `utxo_id` and sends the same amount back to `owner`.
+    let transfer = |utxo_id: UtxoId| {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_unsigned_coin_input(
+                secret,
+                utxo_id,
+                amount,
+                AssetId::BASE,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::coin(owner, amount, AssetId::BASE))
+            .finalize_as_transaction()
+    };
+
+    // Given
+    let root = transfer(rng.gen());
+    let root_id = root.id(&ChainId::default());
+    let parent = transfer(UtxoId::new(root_id, 0));
+    let parent_id = parent.id(&ChainId::default());
+    let leaf = transfer(UtxoId::new(parent_id, 0));
+    let leaf_id = leaf.id(&ChainId::default());
+    for tx in [&root, &parent, &leaf] {
+        client.submit(tx).await.unwrap();
+    }
+
+    // When
+    let dependencies = client.transaction_dependencies(&leaf_id).await.unwrap();
+
+    // Then
+    let dependencies = dependencies
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .sorted()
+        .collect_vec();
+    let expected = [root_id, parent_id].into_iter().sorted().collect_vec();
+    assert_eq!(dependencies, expected);
+    assert!(client
+        .transaction_dependencies(&root_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn pending_spenders_of_returns_the_transaction_that_won_the_coin() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let amount = 1_000_000;
+    let utxo_id: UtxoId = rng.gen();
+
+    // Builds a transaction that spends the contested coin with the `gas_price`.
+    let spend = |gas_price: Word| {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .script_gas_limit(10_000)
+            .gas_price(gas_price)
+            .add_unsigned_coin_input(
+                secret,
+                utxo_id,
+                amount,
+                AssetId::BASE,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::change(owner, 0, AssetId::BASE))
+            .finalize_as_transaction()
+    };
+
+    // Given
+    let cheap = spend(1);
+    let expensive = spend(2);
+    let expensive_id = expensive.id(&ChainId::default());
+    client.submit(&cheap).await.unwrap();
+    // The more expensive transaction squeezes out the cheap one.
+    client.submit(&expensive).await.unwrap();
+
+    // When
+    let spenders = client.pending_spenders_of(&utxo_id).await.unwrap();
+
+    // Then
+    let spenders = spenders
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect_vec();
+    assert_eq!(spenders, vec![expensive_id]);
+    assert!(client
+        .pending_spenders_of(&rng.gen())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn txpool_stats_counts_pending_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let empty = client.txpool_stats().await.unwrap();
+    assert_eq!(empty.tx_count, 0);
+    assert_eq!(empty.total_gas, 0);
+
+    const SCRIPT_GAS_LIMIT: u64 = 10_000;
+    for _ in 0..2 {
+        let tx = TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .gas_price(1)
+        .script_gas_limit(SCRIPT_GAS_LIMIT)
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize_as_transaction();
+        client.submit(&tx).await.unwrap();
+    }
+
+    let stats = client.txpool_stats().await.unwrap();
+    assert_eq!(stats.tx_count, 2);
+    assert!(stats.total_gas >= 2 * SCRIPT_GAS_LIMIT);
+    assert_eq!(
+        stats.estimated_coinbase,
+        Some(client.pending_coinbase().await.unwrap())
+    );
+}
+
+#[tokio::test]
+async fn txpool_stats_without_debug_has_no_estimated_coinbase() {
+    let config = Config {
+        debug: false,
+        ..Config::local_node()
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let stats = client.txpool_stats().await.unwrap();
+    assert_eq!(stats.tx_count, 0);
+    assert_eq!(stats.estimated_coinbase, None);
+}
+
+#[tokio::test]
+async fn produce_until_empty_includes_all_pending_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Nothing to include, so no blocks are produced.
+    assert_eq!(client.produce_until_empty(10).await.unwrap(), 0u32.into());
+
+    let mut tx_ids = vec![];
+    for _ in 0..3 {
+        let tx = TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize_as_transaction();
+        tx_ids.push(client.submit(&tx).await.unwrap());
+    }
+
+    let height = client.produce_until_empty(10).await.unwrap();
+
+    assert_eq!(height, 1u32.into());
+    assert_eq!(client.txpool_stats().await.unwrap().tx_count, 0);
+    for tx_id in tx_ids {
+        let status = client.transaction_status(&tx_id).await.unwrap();
+        assert!(
+            matches!(status, TransactionStatus::Success { .. }),
+            "{status:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn remove_from_txpool_evicts_pending_transaction() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_price(1)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    let tx_id = client.submit(&tx).await.unwrap();
+    assert_eq!(client.txpool_stats().await.unwrap().tx_count, 1);
+
+    assert!(client.remove_from_txpool(&tx_id).await.unwrap());
+    assert_eq!(client.txpool_stats().await.unwrap().tx_count, 0);
+    assert!(!client.remove_from_txpool(&tx_id).await.unwrap());
+
+    // The removed transaction can be submitted again.
+    client.submit(&tx).await.unwrap();
+    assert_eq!(client.txpool_stats().await.unwrap().tx_count, 1);
+}
+
+#[tokio::test]
+async fn submit_with_expiry_drops_tx_not_included_by_expiry_height() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The transaction doesn't fit into the produced blocks, so it stays in the pool.
+    client.set_block_gas_limit(100_000).await.unwrap();
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_random_fee_input()
+    .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+
+    // The expiry height is already reached.
+    client
+        .submit_with_expiry(&tx, 0.into())
+        .await
+        .expect_err("The expired transaction should be rejected");
+
+    let submission = tokio::spawn({
+        let client = client.clone();
+        let tx = tx.clone();
+        async move { client.submit_with_expiry(&tx, 2.into()).await }
+    });
+    while client.transaction(&tx_id).await.unwrap().is_none() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    client.produce_blocks(2, None).await.unwrap();
+
+    let status = tokio::time::timeout(Duration::from_secs(5), submission)
+        .await
+        .expect("The status should be received before the timeout")
+        .unwrap()
+        .unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Expired { expire_at } if expire_at == 2.into()),
+        "{status:?}"
+    );
+    assert!(client.transaction(&tx_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn submit_with_resubmit_bumps_gas_price_of_squeezed_out_tx() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.txpool.max_tx = 1;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret_key = SecretKey::random(&mut rng);
+    let utxo_id: UtxoId = rng.gen();
+    let build = move |gas_price| {
+        TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .gas_price(gas_price)
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret_key,
+            utxo_id,
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize_as_transaction()
+    };
+    let policy = ResubmitPolicy {
+        gas_price_step: 2,
+        max_gas_price: 5,
+    };
+    let first_id = build(1).id(&ChainId::default());
+    let resubmitted_id = build(3).id(&ChainId::default());
+
+    let submission = tokio::spawn({
+        let client = client.clone();
+        async move {
+            client
+                .submit_and_await_commit_with_resubmit(build, 1, &policy)
+                .await
+        }
+    });
+    while client.transaction(&first_id).await.unwrap().is_none() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // The pool is full, so the transaction with the higher gas price squeezes out
+    // the submitted one, and is squeezed out by its resubmission in turn.
+    let competitor = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_price(2)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    let competitor_id = client.submit(&competitor).await.unwrap();
+    while client.transaction(&resubmitted_id).await.unwrap().is_none() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    client.produce_blocks(1, None).await.unwrap();
+
+    let status = tokio::time::timeout(Duration::from_secs(5), submission)
+        .await
+        .expect("The status should be received before the timeout")
+        .unwrap()
+        .unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "{status:?}"
+    );
+    assert!(matches!(
+        client.transaction_status(&resubmitted_id).await.unwrap(),
+        TransactionStatus::Success { .. }
+    ));
+    assert!(client.transaction(&competitor_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn submit_with_resubmit_returns_squeezed_out_tx_replaced_by_colliding_tx() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret_key = SecretKey::random(&mut rng);
+    let utxo_id: UtxoId = rng.gen();
+    let build = move |gas_price| {
+        TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .gas_price(gas_price)
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret_key,
+            utxo_id,
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize_as_transaction()
+    };
+    let policy = ResubmitPolicy {
+        gas_price_step: 2,
+        max_gas_price: 5,
+    };
+    let first_id = build(1).id(&ChainId::default());
+    let resubmitted_id = build(3).id(&ChainId::default());
+
+    let submission = tokio::spawn({
+        let client = client.clone();
+        async move {
+            client
+                .submit_and_await_commit_with_resubmit(build, 1, &policy)
+                .await
+        }
+    });
+    while client.transaction(&first_id).await.unwrap().is_none() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // The transaction spending the same coin with the higher gas price replaces
+    // the submitted one, so resubmitting it would only squeeze out the replacement.
+    let competitor = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_price(2)
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        secret_key,
+        utxo_id,
+        1_000_000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    let competitor_id = client.submit(&competitor).await.unwrap();
+
+    let status = tokio::time::timeout(Duration::from_secs(5), submission)
+        .await
+        .expect("The status should be received before the timeout")
+        .unwrap()
+        .unwrap();
+    assert!(
+        matches!(
+            &status,
+            TransactionStatus::SqueezedOut {
+                kind: SqueezedOutKind::Removed,
+                ..
+            }
+        ),
+        "{status:?}"
+    );
+    assert!(client.transaction(&resubmitted_id).await.unwrap().is_none());
+    assert!(client.transaction(&competitor_id).await.unwrap().is_some());
+}
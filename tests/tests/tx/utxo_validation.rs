@@ -9,7 +9,13 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        CoinInfo,
+        FinalizeSigned,
+        SecretKeySigner,
+        Signer,
+        TransactionStatus,
+    },
 };
 use fuel_core_types::{
     fuel_asm::*,
@@ -26,6 +32,111 @@ use rand::{
 };
 use std::collections::BTreeSet;
 
+#[tokio::test]
+async fn submit_utxo_verified_tx_signed_by_signer() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let signer = SecretKeySigner::new(SecretKey::random(&mut rng));
+    let owner = Input::owner(&signer.public_key());
+
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(10000)
+    .gas_price(1)
+    .add_input(Input::coin_signed(
+        rng.gen(),
+        owner,
+        1000,
+        AssetId::BASE,
+        Default::default(),
+        0,
+        Default::default(),
+    ))
+    .add_output(Output::change(owner, 0, AssetId::BASE))
+    .finalize_signed(&signer, &ChainId::default())
+    .await;
+
+    test_builder.config_coin_inputs_from_transactions(&[&tx]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let status = client.submit_and_await_commit(&tx.into()).await.unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "{status:?}"
+    );
+}
+
+#[tokio::test]
+async fn send_script_funds_signs_and_submits_tx() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.min_gas_price = 1;
+    let signer = SecretKeySigner::new(SecretKey::random(&mut rng));
+    let owner = Input::owner(&signer.public_key());
+    let coin = CoinInfo {
+        utxo_id: rng.gen(),
+        asset_id: AssetId::BASE,
+        amount: 1_000_000,
+        maturity: Default::default(),
+    };
+
+    // The transaction only defines the coin owned by the `signer` in the genesis.
+    let genesis_tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_signed(
+            coin.utxo_id,
+            owner,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+            0,
+            coin.maturity,
+        ))
+        .finalize();
+    test_builder.config_coin_inputs_from_transactions(&[&genesis_tx]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let status = client
+        .send_script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+            &signer,
+            &[coin],
+        )
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "{status:?}"
+    );
+    let coins = client
+        .coins(
+            &owner,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results;
+    // The spent coin is replaced by the change.
+    assert_eq!(coins.len(), 1);
+    assert_ne!(coins[0].utxo_id, coin.utxo_id);
+}
+
 #[tokio::test]
 async fn submit_utxo_verified_tx_with_min_gas_price() {
     let mut rng = StdRng::seed_from_u64(2322);
@@ -1,16 +1,30 @@
-use fuel_core::p2p_test_helpers::{
-    make_nodes,
-    BootstrapSetup,
-    BootstrapType,
-    Nodes,
-    ProducerSetup,
-    ValidatorSetup,
+use fuel_core::{
+    chain_config::ChainConfig,
+    p2p_test_helpers::{
+        make_config,
+        make_node,
+        make_nodes,
+        BootstrapSetup,
+        BootstrapType,
+        Node,
+        Nodes,
+        ProducerSetup,
+        ValidatorSetup,
+    },
 };
 use fuel_core_client::client::{
+    schema::Bytes,
     types::TransactionStatus,
     FuelClient,
 };
-use fuel_core_poa::ports::BlockImporter;
+use fuel_core_p2p::{
+    Multiaddr,
+    PeerId,
+};
+use fuel_core_poa::{
+    ports::BlockImporter,
+    Trigger,
+};
 use fuel_core_types::{
     fuel_tx::*,
     fuel_vm::*,
@@ -27,7 +41,10 @@ use std::{
         Hasher,
     },
     io,
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 #[tokio::test(flavor = "multi_thread")]
@@ -222,3 +239,195 @@ async fn test_tx_gossiping_non_reserved_nodes_invalid_txs() {
 
     assert!(status.is_err());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_gossiping_is_throttled_under_flood() {
+    const NUMBER_OF_TXS: usize = 20;
+    const MAX_TXS_PER_INTERVAL: usize = 5;
+    const GOSSIP_INTERVAL: Duration = Duration::from_secs(1);
+
+    // Create a random seed based on the test parameters.
+    let mut hasher = DefaultHasher::new();
+    (NUMBER_OF_TXS, MAX_TXS_PER_INTERVAL, line!()).hash(&mut hasher);
+    let mut rng = StdRng::seed_from_u64(hasher.finish());
+
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+
+    let Nodes {
+        producers,
+        validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret)
+                .with_txs(NUMBER_OF_TXS)
+                .with_name("producer"),
+        )],
+        [Some(
+            ValidatorSetup::new(pub_key)
+                .with_name("flooded")
+                .with_tx_gossip_throttle(GOSSIP_INTERVAL, MAX_TXS_PER_INTERVAL),
+        )],
+    )
+    .await;
+
+    let producer = &producers[0];
+    let flooded = &validators[0];
+
+    // Time for nodes to connect to each other.
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let mut gossiped_txs = producer
+        .node
+        .shared
+        .network
+        .as_ref()
+        .expect("P2P is enabled")
+        .subscribe_tx();
+
+    // Flood the validator with all transactions at once.
+    for tx in producer.test_transactions() {
+        flooded
+            .node
+            .submit(tx.clone())
+            .await
+            .expect("Transaction is valid");
+    }
+
+    // All transactions should propagate within the time required
+    // to gossip all batches with some margin for the network.
+    let number_of_batches = NUMBER_OF_TXS / MAX_TXS_PER_INTERVAL;
+    let max_delay = GOSSIP_INTERVAL * (number_of_batches as u32 + 5);
+    let arrivals = tokio::time::timeout(max_delay, async {
+        let mut arrivals = Vec::with_capacity(NUMBER_OF_TXS);
+        while arrivals.len() < NUMBER_OF_TXS {
+            gossiped_txs.recv().await.expect("The sender is alive");
+            arrivals.push(Instant::now());
+        }
+        arrivals
+    })
+    .await
+    .expect("All transactions should be gossiped in time");
+
+    // The transactions arrive in batches, one batch per interval.
+    let first_arrival = arrivals[0];
+    let first_batch = arrivals
+        .iter()
+        .filter(|arrival| arrival.duration_since(first_arrival) < GOSSIP_INTERVAL / 2)
+        .count();
+    assert!(first_batch <= MAX_TXS_PER_INTERVAL);
+
+    let gossip_duration = arrivals[NUMBER_OF_TXS - 1].duration_since(first_arrival);
+    assert!(gossip_duration >= GOSSIP_INTERVAL * (number_of_batches as u32 - 2));
+}
+
+/// Makes the sender and the receiver nodes connected directly to each other,
+/// so the transactions are not relayed by other nodes.
+async fn make_sender_and_receiver() -> (Node, Node, PeerId) {
+    let chain_config = ChainConfig::local_testnet();
+
+    // The sender listens on a known port, so the receiver connects to it directly.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("Should find a free port")
+        .port();
+    let mut sender_config = make_config("sender".to_string(), chain_config.clone());
+    sender_config.block_production = Trigger::Never;
+    sender_config.utxo_validation = false;
+    let sender_p2p = sender_config.p2p.as_mut().unwrap();
+    sender_p2p.tcp_port = port;
+    let sender_peer_id = PeerId::from(sender_p2p.keypair.public());
+    let sender_address: Multiaddr =
+        format!("/ip4/127.0.0.1/tcp/{port}/p2p/{sender_peer_id}")
+            .parse()
+            .unwrap();
+
+    let mut receiver_config = make_config("receiver".to_string(), chain_config);
+    receiver_config.block_production = Trigger::Never;
+    receiver_config.utxo_validation = false;
+    receiver_config.p2p.as_mut().unwrap().reserved_nodes = vec![sender_address];
+
+    let sender = make_node(sender_config, vec![]).await;
+    let receiver = make_node(receiver_config, vec![]).await;
+
+    // Time for nodes to connect to each other.
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    (sender, receiver, sender_peer_id)
+}
+
+fn random_unverified_tx(rng: &mut StdRng) -> Transaction {
+    use rand::Rng;
+
+    TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+        )
+        .finalize()
+        .into()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_gossiping_records_source_peer() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+    let (sender, receiver, sender_peer_id) = make_sender_and_receiver().await;
+
+    let mut receiver_txs = receiver.node.shared.txpool.new_tx_notification_subscribe();
+    let tx = random_unverified_tx(&mut rng);
+    let tx_id = tx.id(&Default::default());
+    sender
+        .node
+        .submit(tx)
+        .await
+        .expect("Should accept the transaction because `utxo_validation = false`.");
+
+    let gossiped_id = tokio::time::timeout(Duration::from_secs(5), receiver_txs.recv())
+        .await
+        .expect("The receiver should get the transaction in time")
+        .expect("The sender is alive");
+    assert_eq!(gossiped_id, tx_id);
+
+    let sender_client = FuelClient::from(sender.node.bound_address);
+    let receiver_client = FuelClient::from(receiver.node.bound_address);
+    assert_eq!(
+        sender_client.transaction_source(&tx_id).await.unwrap(),
+        Some("local".to_string())
+    );
+    assert_eq!(
+        receiver_client.transaction_source(&tx_id).await.unwrap(),
+        Some(Bytes(sender_peer_id.to_bytes()).to_string())
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_gossiping_is_ignored_by_read_only_node() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+    let (sender, receiver, _) = make_sender_and_receiver().await;
+    receiver
+        .node
+        .shared
+        .block_importer
+        .block_importer
+        .enter_read_only_mode();
+
+    let mut receiver_txs = receiver.node.shared.txpool.new_tx_notification_subscribe();
+    let tx = random_unverified_tx(&mut rng);
+    let tx_id = tx.id(&Default::default());
+    sender
+        .node
+        .submit(tx)
+        .await
+        .expect("Should accept the transaction because `utxo_validation = false`.");
+
+    tokio::time::timeout(Duration::from_secs(5), receiver_txs.recv())
+        .await
+        .expect_err("The read-only receiver should not insert the transaction");
+    assert!(receiver.node.shared.txpool.find_one(tx_id).is_none());
+}